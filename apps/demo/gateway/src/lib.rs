@@ -0,0 +1,92 @@
+#![expect(clippy::unused_async)]
+
+use candid::Principal;
+use canic::{
+    Error,
+    api::{call::Call, canister::index::AppIndexApi},
+    prelude::*,
+};
+use futures::future::join_all;
+use ic_cdk::api::{msg_caller, time};
+use std::{cell::RefCell, collections::BTreeMap};
+
+const USER_HUB_ROLE: &str = "user_hub";
+const RATE_LIMIT_WINDOW_SECS: u64 = 60;
+const RATE_LIMIT_MAX_REQUESTS: u32 = 30;
+const FAN_OUT_MAX_KEYS: usize = 10;
+
+thread_local! {
+    // Per-caller fixed rate-limit windows: (window start, requests admitted).
+    static RATE_LIMITS: RefCell<BTreeMap<Principal, (u64, u32)>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+canic::start!();
+
+async fn canic_setup() {}
+async fn canic_install(_: Option<Vec<u8>>) {}
+async fn canic_upgrade() {}
+
+// Route one partition key through user_hub to its shard.
+#[canic_update(public)]
+async fn demo_gateway_describe(partition_key: String) -> Result<String, Error> {
+    admit(msg_caller(), 1)?;
+
+    describe(partition_key).await
+}
+
+// Fan one request out to every shard that owns one of the keys.
+#[canic_update(public)]
+async fn demo_gateway_describe_many(partition_keys: Vec<String>) -> Result<Vec<String>, Error> {
+    if partition_keys.len() > FAN_OUT_MAX_KEYS {
+        return Err(Error::invalid(format!(
+            "at most {FAN_OUT_MAX_KEYS} partition keys per request"
+        )));
+    }
+    let weight = u32::try_from(partition_keys.len()).unwrap_or(u32::MAX);
+    admit(msg_caller(), weight)?;
+
+    join_all(partition_keys.into_iter().map(describe))
+        .await
+        .into_iter()
+        .collect()
+}
+
+async fn describe(partition_key: String) -> Result<String, Error> {
+    let user_hub = AppIndexApi::get(CanisterRole::new(USER_HUB_ROLE))
+        .ok_or_else(|| Error::unavailable("user_hub is not registered in the app index"))?;
+
+    let shard: Result<Principal, Error> = Call::bounded_wait(user_hub, "demo_user_hub_route")
+        .with_arg(&partition_key)?
+        .execute_candid()
+        .await?;
+
+    let described: Result<String, Error> = Call::bounded_wait(shard?, "demo_user_shard_describe")
+        .with_arg(&partition_key)?
+        .execute_candid()
+        .await?;
+
+    described
+}
+
+// Admit `weight` requests for `caller` within the current fixed window.
+fn admit(caller: Principal, weight: u32) -> Result<(), Error> {
+    let now_secs = time() / 1_000_000_000;
+
+    RATE_LIMITS.with_borrow_mut(|limits| {
+        limits.retain(|_, (start, _)| now_secs < start.saturating_add(RATE_LIMIT_WINDOW_SECS));
+
+        let (_, used) = limits.entry(caller).or_insert((now_secs, 0));
+        let next = used.saturating_add(weight);
+        if next > RATE_LIMIT_MAX_REQUESTS {
+            return Err(Error::exhausted(format!(
+                "rate limit of {RATE_LIMIT_MAX_REQUESTS} requests per {RATE_LIMIT_WINDOW_SECS}s exceeded"
+            )));
+        }
+
+        *used = next;
+        Ok(())
+    })
+}
+
+canic::finish!();
@@ -1,5 +1,6 @@
 #![expect(clippy::unused_async)]
 
+use candid::Principal;
 use canic::{Error, api::canister::placement::ShardingApi, prelude::*};
 use ic_cdk::api::{canister_self, msg_caller};
 
@@ -48,6 +49,12 @@ async fn demo_user_hub_plan(partition_key: String) -> Result<String, Error> {
     ))
 }
 
+#[canic_query(public)]
+async fn demo_user_hub_route(partition_key: String) -> Result<Principal, Error> {
+    ShardingApi::lookup_partition_key(POOL_NAME, &partition_key)
+        .ok_or_else(|| Error::not_found(format!("partition key {partition_key} is unassigned")))
+}
+
 #[canic_update(public)]
 async fn demo_user_hub_assign(partition_key: String) -> Result<String, Error> {
     canic::access::require_local()?;
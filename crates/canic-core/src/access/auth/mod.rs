@@ -140,6 +140,16 @@ pub async fn is_whitelisted(caller: Principal) -> Result<(), AccessError> {
     predicates::is_whitelisted(caller).await
 }
 
+/// Require that the authenticated subject holds an ACL role.
+pub async fn has_role(subject: Principal, role: &str) -> Result<(), AccessError> {
+    predicates::has_role(subject, role).await
+}
+
+/// Require that the authenticated subject's session has not idled out.
+pub async fn session_active(subject: Principal, idle_timeout_secs: u64) -> Result<(), AccessError> {
+    predicates::session_active(subject, idle_timeout_secs).await
+}
+
 /// Require that the caller is a direct child of the current canister.
 pub async fn is_child(caller: Principal) -> Result<(), AccessError> {
     predicates::is_child(caller).await
@@ -241,13 +251,14 @@ mod tests {
     #[test]
     fn required_scope_allows_when_scope_present() {
         let scopes = vec![cap::READ.to_string(), cap::VERIFY.to_string()];
-        assert!(enforce_required_scope(Some(cap::VERIFY), &scopes).is_ok());
+        assert!(enforce_required_scope(Some(cap::VERIFY.as_str()), &scopes).is_ok());
     }
 
     #[test]
     fn required_scope_rejects_when_scope_missing() {
         let scopes = vec![cap::READ.to_string()];
-        let err = enforce_required_scope(Some(cap::VERIFY), &scopes).expect_err("expected denial");
+        let err = enforce_required_scope(Some(cap::VERIFY.as_str()), &scopes)
+            .expect_err("expected denial");
         assert!(matches!(err, AccessError::Denied(_)));
     }
 
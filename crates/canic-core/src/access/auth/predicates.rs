@@ -10,10 +10,15 @@ use super::{
 use crate::{
     access::AccessError,
     cdk::types::Principal,
+    domain::policy::pure::session as session_policy,
     ops::{
         config::ConfigOps,
+        ic::IcOps,
         runtime::env::EnvOps,
-        storage::{children::CanisterChildrenOps, registry::subnet::SubnetRegistryOps},
+        storage::{
+            acl::AclStoreOps, children::CanisterChildrenOps, registry::subnet::SubnetRegistryOps,
+            session::SessionStoreOps,
+        },
     },
 };
 use ic_cdk::api::{canister_self, is_controller as caller_is_controller};
@@ -46,6 +51,53 @@ pub(super) async fn is_whitelisted(caller: Principal) -> Result<(), AccessError>
     Ok(())
 }
 
+/// Require that the authenticated subject holds an ACL role.
+#[expect(clippy::unused_async)]
+pub(super) async fn has_role(subject: Principal, role: &str) -> Result<(), AccessError> {
+    if AclStoreOps::has_role(subject, role) {
+        Ok(())
+    } else {
+        Err(AccessError::Denied(format!(
+            "caller '{subject}' does not hold role '{role}'"
+        )))
+    }
+}
+
+/// Require that the authenticated subject has an open session that has not
+/// been idle longer than `idle_timeout_secs`.
+/// An idle session is closed; only a newer delegated token reopens it.
+#[expect(clippy::unused_async)]
+pub(super) async fn session_active(
+    subject: Principal,
+    idle_timeout_secs: u64,
+) -> Result<(), AccessError> {
+    let now_ns = IcOps::now_nanos();
+    let Some(session) = SessionStoreOps::get(subject)
+        .filter(|session| !session_policy::is_expired(session.expires_at_ns, now_ns))
+    else {
+        return Err(AccessError::Denied(format!(
+            "caller '{subject}' has no active session"
+        )));
+    };
+    if session.idle_closed {
+        return Err(AccessError::Denied(format!(
+            "session of caller '{subject}' was closed after idling"
+        )));
+    }
+
+    let seen_ns =
+        session_policy::seen_before_call_ns(session.last_seen_ns, session.previous_seen_ns, now_ns);
+    if session_policy::is_idle(seen_ns, now_ns, idle_timeout_secs) {
+        SessionStoreOps::close_idle(subject);
+        return Err(AccessError::Denied(format!(
+            "session of caller '{subject}' idled longer than {idle_timeout_secs}s"
+        )));
+    }
+
+    SessionStoreOps::touch(subject, now_ns);
+    Ok(())
+}
+
 /// Require that the caller is a direct child of the current canister.
 #[expect(clippy::unused_async)]
 pub(super) async fn is_child(caller: Principal) -> Result<(), AccessError> {
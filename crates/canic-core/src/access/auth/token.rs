@@ -17,6 +17,7 @@ use crate::{
         auth::{AuthOps, VerifyDelegatedTokenRuntimeInput},
         config::ConfigOps,
         ic::IcOps,
+        storage::session::{SessionStoreOps, VerifiedSession},
     },
 };
 use ic_cdk::api::msg_arg_data;
@@ -62,6 +63,17 @@ fn verify_token(
     enforce_subject_binding(verified.subject, caller)?;
     enforce_required_scope(required_scope, &verified.scopes)?;
 
+    SessionStoreOps::observe(
+        VerifiedSession {
+            subject: verified.subject,
+            issuer_pid: verified.issuer_pid,
+            scopes: verified.scopes,
+            token_issued_at_ns: token.claims.issued_at_ns,
+            expires_at_ns: token.claims.expires_at_ns,
+        },
+        now_ns,
+    );
+
     Ok(verified.issuer_pid)
 }
 
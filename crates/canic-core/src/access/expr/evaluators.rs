@@ -9,7 +9,7 @@ use super::{
 };
 use crate::{
     access::{self, AccessError, metrics::DelegatedAuthMetrics},
-    ids::AccessMetricKind,
+    ids::{AccessMetricKind, cap::Capability},
 };
 
 pub(super) const fn name(pred: &BuiltinPredicate) -> &'static str {
@@ -34,15 +34,18 @@ pub(super) const fn name(pred: &BuiltinPredicate) -> &'static str {
         BuiltinPredicate::Environment(EnvironmentPredicate::BuildIcOnly) => "build_ic_only",
         BuiltinPredicate::Environment(EnvironmentPredicate::BuildLocalOnly) => "build_local_only",
         BuiltinPredicate::Authenticated { .. } => "authenticated",
+        BuiltinPredicate::HasRole { .. } => "has_role",
+        BuiltinPredicate::SessionActive { .. } => "session_active",
     }
 }
 
 pub(super) const fn metric_kind(pred: &BuiltinPredicate) -> AccessMetricKind {
     match pred {
         BuiltinPredicate::Fleet(_) => AccessMetricKind::Guard,
-        BuiltinPredicate::Caller(_) | BuiltinPredicate::Authenticated { .. } => {
-            AccessMetricKind::Auth
-        }
+        BuiltinPredicate::Caller(_)
+        | BuiltinPredicate::Authenticated { .. }
+        | BuiltinPredicate::HasRole { .. }
+        | BuiltinPredicate::SessionActive { .. } => AccessMetricKind::Auth,
         BuiltinPredicate::Environment(
             EnvironmentPredicate::SelfIsPrimeSubnet | EnvironmentPredicate::SelfIsPrimeRoot,
         ) => AccessMetricKind::Env,
@@ -95,10 +98,18 @@ pub(super) async fn evaluate(
             access::env::build_network_local()
         }
         BuiltinPredicate::Authenticated { required_scope } => {
-            let issuer_pid =
-                access::auth::delegated_token_verified(ctx.authenticated_caller, *required_scope)?;
+            let issuer_pid = access::auth::delegated_token_verified(
+                ctx.authenticated_caller,
+                required_scope.map(Capability::as_str),
+            )?;
             DelegatedAuthMetrics::record_authority(issuer_pid);
             Ok(())
         }
+        BuiltinPredicate::HasRole { role } => {
+            access::auth::has_role(ctx.authenticated_caller, role).await
+        }
+        BuiltinPredicate::SessionActive { idle_timeout_secs } => {
+            access::auth::session_active(ctx.authenticated_caller, *idle_timeout_secs).await
+        }
     }
 }
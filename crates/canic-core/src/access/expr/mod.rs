@@ -9,7 +9,7 @@ mod evaluators;
 use crate::{
     access::{self, AccessError, metrics::AccessMetrics},
     cdk::types::Principal,
-    ids::{AccessMetricKind, EndpointCall},
+    ids::{AccessMetricKind, EndpointCall, cap::Capability},
     log,
     log::Topic,
 };
@@ -98,9 +98,9 @@ pub enum BuiltinPredicate {
     Fleet(FleetPredicate),
     Caller(CallerPredicate),
     Environment(EnvironmentPredicate),
-    Authenticated {
-        required_scope: Option<&'static str>,
-    },
+    Authenticated { required_scope: Option<Capability> },
+    HasRole { role: &'static str },
+    SessionActive { idle_timeout_secs: u64 },
 }
 
 impl BuiltinPredicate {
@@ -303,17 +303,27 @@ pub mod env {
 }
 
 pub mod auth {
-    use super::{AccessExpr, BuiltinPredicate, builtin};
+    use super::{AccessExpr, BuiltinPredicate, Capability, builtin};
 
     #[must_use]
-    pub const fn authenticated(required_scope: Option<&'static str>) -> AccessExpr {
+    pub const fn authenticated(required_scope: Option<Capability>) -> AccessExpr {
         builtin(BuiltinPredicate::Authenticated { required_scope })
     }
 
     #[must_use]
-    pub const fn authenticated_with_scope(required_scope: &'static str) -> AccessExpr {
+    pub const fn authenticated_with_scope(required_scope: Capability) -> AccessExpr {
         authenticated(Some(required_scope))
     }
+
+    #[must_use]
+    pub const fn has_role(role: &'static str) -> AccessExpr {
+        builtin(BuiltinPredicate::HasRole { role })
+    }
+
+    #[must_use]
+    pub const fn session_active(idle_timeout_secs: u64) -> AccessExpr {
+        builtin(BuiltinPredicate::SessionActive { idle_timeout_secs })
+    }
 }
 
 /// eval_access
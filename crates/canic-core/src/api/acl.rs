@@ -0,0 +1,59 @@
+//! Module: api::acl
+//!
+//! Responsibility: public facade for application roles in the access control list.
+//! Does not own: stable schemas, role-name rules, or endpoint access evaluation.
+//! Boundary: maps ACL workflow errors into public API errors.
+
+use crate::{
+    cdk::types::Principal,
+    dto::{
+        acl::{AclEntry, AclRoleRequest},
+        error::Error,
+        page::{Page, PageRequest},
+    },
+    workflow::acl::AclWorkflow,
+};
+
+///
+/// AclApi
+///
+/// Application roles held by principals, checked by `auth::has_role("...")`.
+///
+/// Controllers manage grants through `canic_acl_grant`, `canic_acl_revoke`,
+/// and `canic_acl_list`; canister code may grant and check roles directly.
+///
+
+pub struct AclApi;
+
+impl AclApi {
+    pub fn grant(principal: Principal, role: &str) -> Result<(), Error> {
+        AclWorkflow::grant(&AclRoleRequest {
+            principal,
+            role: role.to_string(),
+        })
+        .map_err(Error::from)
+    }
+
+    pub fn revoke(principal: Principal, role: &str) -> Result<(), Error> {
+        AclWorkflow::revoke(&AclRoleRequest {
+            principal,
+            role: role.to_string(),
+        })
+        .map_err(Error::from)
+    }
+
+    #[must_use]
+    pub fn has_role(principal: Principal, role: &str) -> bool {
+        AclWorkflow::has_role(principal, role)
+    }
+
+    #[must_use]
+    pub fn roles(principal: Principal) -> Vec<String> {
+        AclWorkflow::roles(principal)
+    }
+
+    #[must_use]
+    pub fn list(page: PageRequest) -> Page<AclEntry> {
+        AclWorkflow::list(page)
+    }
+}
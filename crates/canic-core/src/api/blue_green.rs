@@ -0,0 +1,37 @@
+//! Module: api::blue_green
+//!
+//! Responsibility: public facade for blue/green replacement of root children.
+//! Does not own: provisioning, smoke checks, routing, or stable schemas.
+//! Boundary: maps blue/green workflow errors into public API errors.
+
+use crate::{
+    dto::{
+        blue_green::{BlueGreenDeployRequest, BlueGreenStatus},
+        error::Error,
+    },
+    workflow::canister_lifecycle::blue_green::BlueGreenWorkflow,
+};
+
+///
+/// BlueGreenApi
+///
+/// Root-only deployments that provision a new instance of a role beside the
+/// old one, flip the role's index entry once a smoke-check call succeeds,
+/// and delete the old instance after a grace period.
+///
+/// Controllers drive deployments through `canic_blue_green_deploy` and
+/// `canic_blue_green_status`; progress also shows in `canic_workflow_status`.
+///
+
+pub struct BlueGreenApi;
+
+impl BlueGreenApi {
+    pub fn deploy(request: BlueGreenDeployRequest) -> Result<BlueGreenStatus, Error> {
+        BlueGreenWorkflow::deploy(request).map_err(Error::from)
+    }
+
+    #[must_use]
+    pub fn statuses() -> Vec<BlueGreenStatus> {
+        BlueGreenWorkflow::statuses()
+    }
+}
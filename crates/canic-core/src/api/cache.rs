@@ -0,0 +1,45 @@
+//! Module: api::cache
+//!
+//! Responsibility: public facade for filling and purging cached query responses.
+//! Does not own: cache tiers, freshness policy, or endpoint registration.
+//! Boundary: maps query-cache workflow errors into public API errors.
+
+use crate::{
+    cdk::candid::{encode_args, utils::ArgumentEncoder},
+    dto::error::Error,
+    workflow::runtime::query_cache::QueryCacheWorkflow,
+};
+
+///
+/// CacheApi
+///
+/// Response cache for `#[canic_query(cache(...))]` endpoints.
+///
+/// Query calls cannot persist state, so a cached query only ever reads the
+/// cache. Fill it with `warm` from an update or timer, which runs the query
+/// handler and stores its response for the endpoint's TTL, and call
+/// `invalidate` from the update paths that change the data behind it.
+///
+
+pub struct CacheApi;
+
+impl CacheApi {
+    /// Run a cached query endpoint with `args` and store its response.
+    #[expect(
+        clippy::future_not_send,
+        reason = "cached query handlers run on the single-threaded IC executor"
+    )]
+    pub async fn warm<A: ArgumentEncoder>(endpoint: &str, args: A) -> Result<(), Error> {
+        let args = encode_args(args)
+            .map_err(|err| Error::invalid(format!("failed to encode cached query args: {err}")))?;
+
+        QueryCacheWorkflow::warm(endpoint, args)
+            .await
+            .map_err(Error::from)
+    }
+
+    /// Drop every cached response of one endpoint.
+    pub fn invalidate(endpoint: &str) {
+        QueryCacheWorkflow::invalidate(endpoint);
+    }
+}
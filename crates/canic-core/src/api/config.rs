@@ -1,10 +1,13 @@
 //! Module: api::config
 //!
-//! Responsibility: public config export facade for endpoint callers.
+//! Responsibility: public config export and runtime patch facade for endpoint callers.
 //! Does not own: config storage, parsing policy, or serialization format rules.
 //! Boundary: maps config workflow errors into public API errors.
 
-use crate::{dto::error::Error, workflow::config::ConfigWorkflow};
+use crate::{
+    dto::{config::ConfigPatch, error::Error},
+    workflow::config::ConfigWorkflow,
+};
 
 ///
 /// ConfigApi
@@ -19,4 +22,11 @@ impl ConfigApi {
     pub fn export_toml() -> Result<String, Error> {
         ConfigWorkflow::export_toml().map_err(Error::from)
     }
+
+    /// Layer runtime-tunable overrides onto the embedded config and cascade them.
+    pub async fn apply_patch(patch: ConfigPatch) -> Result<(), Error> {
+        ConfigWorkflow::apply_patch(patch)
+            .await
+            .map_err(Error::from)
+    }
 }
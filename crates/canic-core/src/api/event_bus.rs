@@ -0,0 +1,106 @@
+//! Module: api::event_bus
+//!
+//! Responsibility: public event-bus publish/subscribe facade and root broker endpoint adapters.
+//! Does not own: queue storage, delivery retries, or topic validation rules.
+//! Boundary: maps event-bus workflow errors into public API errors.
+
+use crate::{
+    cdk::candid::{CandidType, decode_one, encode_one},
+    dto::{
+        error::Error,
+        event_bus::{
+            EventBusAdminCommand, EventDeadLetter, EventEnvelope, EventPublishRequest,
+            EventPublishResponse, EventSubscribeRequest, EventUnsubscribeRequest,
+        },
+        page::{Page, PageRequest},
+    },
+    ops::ic::IcOps,
+    workflow::event_bus::EventBusWorkflow,
+};
+use serde::de::DeserializeOwned;
+
+///
+/// EventBus
+///
+/// Canister-side facade for publishing to and subscribing at the root broker.
+/// Subscriber callbacks receive an `EventEnvelope` and acknowledge by returning `Ok(())`.
+///
+
+pub struct EventBus;
+
+impl EventBus {
+    /// Candid-encode `payload` and publish it on `topic`.
+    pub async fn publish<T: CandidType + Sync>(
+        topic: &str,
+        payload: &T,
+    ) -> Result<EventPublishResponse, Error> {
+        let payload = encode_one(payload)
+            .map_err(|err| Error::invalid(format!("event payload encode failed: {err}")))?;
+        Self::publish_raw(topic, payload).await
+    }
+
+    /// Publish pre-encoded payload bytes on `topic`.
+    pub async fn publish_raw(topic: &str, payload: Vec<u8>) -> Result<EventPublishResponse, Error> {
+        EventBusWorkflow::publish(EventPublishRequest {
+            topic: topic.to_string(),
+            payload,
+        })
+        .await
+        .map_err(Error::from)
+    }
+
+    /// Register this canister's `method` as the callback for `topic`.
+    pub async fn subscribe(topic: &str, method: &str) -> Result<(), Error> {
+        EventBusWorkflow::subscribe(EventSubscribeRequest {
+            topic: topic.to_string(),
+            method: method.to_string(),
+        })
+        .await
+        .map_err(Error::from)
+    }
+
+    pub async fn unsubscribe(topic: &str) -> Result<(), Error> {
+        EventBusWorkflow::unsubscribe(EventUnsubscribeRequest {
+            topic: topic.to_string(),
+        })
+        .await
+        .map_err(Error::from)
+    }
+
+    /// Decode the Candid payload carried by a delivered envelope.
+    pub fn decode<T: CandidType + DeserializeOwned>(envelope: &EventEnvelope) -> Result<T, Error> {
+        decode_one(&envelope.payload)
+            .map_err(|err| Error::invalid(format!("event payload decode failed: {err}")))
+    }
+}
+
+///
+/// EventBusApi
+///
+/// Root broker endpoint adapters.
+///
+
+pub struct EventBusApi;
+
+impl EventBusApi {
+    pub fn publish_root(request: EventPublishRequest) -> Result<EventPublishResponse, Error> {
+        EventBusWorkflow::publish_root(IcOps::msg_caller(), request).map_err(Error::from)
+    }
+
+    pub fn subscribe_root(request: EventSubscribeRequest) -> Result<(), Error> {
+        EventBusWorkflow::subscribe_root(IcOps::msg_caller(), request).map_err(Error::from)
+    }
+
+    pub fn unsubscribe_root(request: EventUnsubscribeRequest) -> Result<(), Error> {
+        EventBusWorkflow::unsubscribe_root(IcOps::msg_caller(), request).map_err(Error::from)
+    }
+
+    #[must_use]
+    pub fn dead_letters(page: PageRequest) -> Page<EventDeadLetter> {
+        EventBusWorkflow::dead_letters(page)
+    }
+
+    pub fn admin(command: EventBusAdminCommand) -> Result<(), Error> {
+        EventBusWorkflow::admin(command).map_err(Error::from)
+    }
+}
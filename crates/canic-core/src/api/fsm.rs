@@ -0,0 +1,42 @@
+//! Module: api::fsm
+//!
+//! Responsibility: public facade for declarative, persisted workflow state machines.
+//! Does not own: step scheduling, retry policy, or stable schemas.
+//! Boundary: maps fsm workflow errors into public API errors.
+
+use crate::{
+    dto::{error::Error, fsm::WorkflowStatusResponse},
+    workflow::fsm::FsmWorkflow,
+};
+
+pub use crate::workflow::fsm::{StateMachine, Transition};
+
+///
+/// Fsm
+///
+/// Multi-step workflows that survive upgrades.
+///
+/// A machine declares its states and allowed transitions; each step's result
+/// is persisted in stable memory before the next step runs, and running
+/// instances resume on their own after an upgrade once their machine is
+/// registered again. Progress is visible through `canic_workflow_status`.
+///
+
+pub struct Fsm;
+
+impl Fsm {
+    /// Make a machine runnable; call during setup so instances resume after upgrade.
+    pub fn register<M: StateMachine>() {
+        FsmWorkflow::register::<M>();
+    }
+
+    /// Persist a new instance in `state` and return its id.
+    pub fn start<M: StateMachine>(state: &M::State) -> Result<u64, Error> {
+        FsmWorkflow::start_instance::<M>(state).map_err(Error::from)
+    }
+
+    #[must_use]
+    pub fn status(instance_id: u64) -> Option<WorkflowStatusResponse> {
+        FsmWorkflow::status(instance_id)
+    }
+}
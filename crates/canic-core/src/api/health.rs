@@ -0,0 +1,57 @@
+//! Module: api::health
+//!
+//! Responsibility: public facade for application health gauges and root fleet health.
+//! Does not own: report scheduling, report transport, or staleness policy.
+//! Boundary: maps health workflow errors into public API errors.
+
+use crate::{
+    dto::{
+        error::Error,
+        health::{FleetHealthResponse, HealthReport},
+    },
+    ops::ic::IcOps,
+    workflow::runtime::health::HealthWorkflow,
+};
+
+///
+/// Health
+///
+/// Health reporting without heartbeats.
+///
+/// Every non-root canister pushes a compact report (cycles, memory pages,
+/// last upgrade, application gauges) to root on a jittered timer; root keeps
+/// the latest report per child and serves them through `canic_fleet_health`.
+///
+
+pub struct Health;
+
+impl Health {
+    /// Set an application gauge carried in this canister's next reports.
+    pub fn set_gauge(name: &str, value: u64) -> Result<(), Error> {
+        HealthWorkflow::set_gauge(name, value).map_err(Error::from)
+    }
+
+    /// Stop reporting one application gauge; returns whether it was set.
+    #[must_use]
+    pub fn remove_gauge(name: &str) -> bool {
+        HealthWorkflow::remove_gauge(name)
+    }
+}
+
+///
+/// HealthApi
+///
+/// Root fleet-health endpoint adapters.
+///
+
+pub struct HealthApi;
+
+impl HealthApi {
+    pub fn report_root(report: HealthReport) -> Result<(), Error> {
+        HealthWorkflow::receive_root(IcOps::msg_caller(), report).map_err(Error::from)
+    }
+
+    pub fn fleet_health() -> Result<FleetHealthResponse, Error> {
+        HealthWorkflow::fleet_health().map_err(Error::from)
+    }
+}
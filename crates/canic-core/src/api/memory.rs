@@ -5,14 +5,18 @@
 //! Boundary: maps memory workflow errors into public API errors.
 
 use crate::{
-    dto::{error::Error, memory::MemoryLedgerResponse},
-    workflow::memory::query::MemoryQuery as MemoryQueryWorkflow,
+    cdk::structures::Storable,
+    dto::{
+        error::Error,
+        memory::{EagerInitReport, MapMigrationStatus, MemoryLedgerResponse, StoreCatalogResponse},
+    },
+    workflow::memory::{migrate::MapMigrationWorkflow, query::MemoryQuery as MemoryQueryWorkflow},
 };
 
 ///
 /// MemoryQuery
 ///
-/// Thin endpoint-facing facade for memory ledger queries.
+/// Thin endpoint-facing facade for memory ledger, store catalog, and eager-init queries.
 ///
 
 pub struct MemoryQuery;
@@ -22,4 +26,45 @@ impl MemoryQuery {
     pub fn ledger() -> Result<MemoryLedgerResponse, Error> {
         MemoryQueryWorkflow::ledger().map_err(Error::from)
     }
+
+    /// Return the catalog of registered stable stores.
+    pub fn stores() -> Result<StoreCatalogResponse, Error> {
+        MemoryQueryWorkflow::stores().map_err(Error::from)
+    }
+
+    /// Return fallible eager statics that fell back during canister start.
+    #[must_use]
+    pub fn eager_init_report() -> EagerInitReport {
+        MemoryQueryWorkflow::eager_init_report()
+    }
+}
+
+///
+/// MapMigrationApi
+///
+/// Timer-driven migration of one stable BTreeMap's value layout.
+///
+
+pub struct MapMigrationApi;
+
+impl MapMigrationApi {
+    /// Start or resume migrating the map behind `memory_id` from `VOld` to `VNew`.
+    ///
+    /// Entries are rewritten in instruction-bounded batches, one per timer run,
+    /// with a durable cursor so the migration resumes when called again after an
+    /// upgrade. The map must not be open elsewhere until `status` reports completion.
+    pub fn migrate_map<K, VOld, VNew>(memory_id: u8, f: fn(VOld) -> VNew) -> Result<(), Error>
+    where
+        K: Storable + Ord + Clone + 'static,
+        VOld: Storable + 'static,
+        VNew: Storable + 'static,
+    {
+        MapMigrationWorkflow::start::<K, VOld, VNew>(memory_id, f).map_err(Error::from)
+    }
+
+    /// Return the recorded progress for one map migration.
+    #[must_use]
+    pub fn status(memory_id: u8) -> Option<MapMigrationStatus> {
+        MapMigrationWorkflow::status(memory_id)
+    }
 }
@@ -4,25 +4,34 @@
 //! Does not own: orchestration, business logic, policy, or storage invariants.
 //! Boundary: maps endpoint calls into workflow calls and public errors.
 
+pub mod acl;
 pub mod auth;
 #[cfg(feature = "blob-storage")]
 pub mod blob_storage;
+pub mod blue_green;
+pub mod cache;
 pub mod call;
 pub mod cascade;
 pub mod config;
 pub mod error;
+pub mod event_bus;
 pub mod fleet_activation;
+pub mod fsm;
+pub mod health;
 pub mod ic;
 pub mod icp_refill;
 pub mod intent;
 pub mod lifecycle;
 pub mod memory;
 pub mod metadata;
+pub mod model;
+pub mod outbox;
 pub mod placement;
 pub mod pool;
 pub mod ready;
 pub mod rpc;
 pub mod runtime;
+pub mod session;
 pub mod state;
 pub mod timer;
 pub mod topology;
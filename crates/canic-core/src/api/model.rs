@@ -0,0 +1,136 @@
+//! Module: api::model
+//!
+//! Responsibility: read-only facade over application maps declared with `canic_model!`.
+//! Does not own: map declaration, memory allocation, or endpoint access control.
+//! Boundary: generated model endpoints project stable map rows into page DTOs here.
+
+use crate::{
+    cdk::structures::{BTreeMap, Memory, Storable},
+    dto::{
+        model::ModelEntry,
+        page::{Page, PageRequest},
+    },
+    workflow::view::paginate::clamp_page_request,
+};
+
+///
+/// ModelQuery
+///
+/// Generic reads backing the `<name>_get`, `<name>_list`, and `<name>_count`
+/// endpoints generated by `canic_model!`.
+///
+
+pub struct ModelQuery;
+
+impl ModelQuery {
+    /// Return the value stored under `key`, if any.
+    #[must_use]
+    pub fn get<K, V, M>(map: &BTreeMap<K, V, M>, key: &K) -> Option<V>
+    where
+        K: Storable + Ord + Clone,
+        V: Storable,
+        M: Memory,
+    {
+        map.get(key)
+    }
+
+    /// Return one page of rows in key order.
+    ///
+    /// The limit is clamped to the shared page maximum; rows before `offset`
+    /// are skipped without being decoded into the response.
+    #[must_use]
+    pub fn list<K, V, M>(map: &BTreeMap<K, V, M>, page: PageRequest) -> Page<ModelEntry<K, V>>
+    where
+        K: Storable + Ord + Clone,
+        V: Storable,
+        M: Memory,
+    {
+        let page = clamp_page_request(page);
+        let skip = usize::try_from(page.offset).unwrap_or(usize::MAX);
+        let take = usize::try_from(page.limit).unwrap_or(usize::MAX);
+
+        let entries = map
+            .iter()
+            .skip(skip)
+            .take(take)
+            .map(|entry| ModelEntry {
+                key: entry.key().clone(),
+                value: entry.value(),
+            })
+            .collect();
+
+        Page {
+            entries,
+            total: map.len(),
+        }
+    }
+
+    /// Return the number of rows in the map.
+    #[must_use]
+    pub fn count<K, V, M>(map: &BTreeMap<K, V, M>) -> u64
+    where
+        K: Storable + Ord + Clone,
+        V: Storable,
+        M: Memory,
+    {
+        map.len()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdk::structures::DefaultMemoryImpl;
+
+    fn map_with(rows: u64) -> BTreeMap<u64, u64, DefaultMemoryImpl> {
+        let mut map = BTreeMap::init(DefaultMemoryImpl::default());
+        for key in 0..rows {
+            map.insert(key, key * 10);
+        }
+        map
+    }
+
+    #[test]
+    fn list_pages_rows_in_key_order() {
+        let map = map_with(5);
+
+        let page = ModelQuery::list(
+            &map,
+            PageRequest {
+                limit: 2,
+                offset: 1,
+            },
+        );
+
+        assert_eq!(page.total, 5);
+        assert_eq!(
+            page.entries,
+            vec![
+                ModelEntry { key: 1, value: 10 },
+                ModelEntry { key: 2, value: 20 },
+            ]
+        );
+        assert_eq!(ModelQuery::get(&map, &4), Some(40));
+        assert_eq!(ModelQuery::count(&map), 5);
+    }
+
+    #[test]
+    fn list_past_the_end_returns_an_empty_page() {
+        let map = map_with(2);
+
+        let page = ModelQuery::list(
+            &map,
+            PageRequest {
+                limit: 10,
+                offset: u64::MAX,
+            },
+        );
+
+        assert!(page.entries.is_empty());
+        assert_eq!(page.total, 2);
+    }
+}
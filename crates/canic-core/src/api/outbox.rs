@@ -0,0 +1,71 @@
+//! Module: api::outbox
+//!
+//! Responsibility: public facade for enqueueing reliable side-effect calls.
+//! Does not own: outbox storage, dispatch retries, or descriptor limits.
+//! Boundary: maps outbox workflow errors into public API errors.
+
+use crate::{
+    cdk::{
+        candid::{encode_args, utils::ArgumentEncoder},
+        types::Principal,
+    },
+    dto::{
+        error::Error,
+        outbox::{OutboxAdminCommand, OutboxFailedEntry},
+        page::{Page, PageRequest},
+    },
+    workflow::runtime::outbox::OutboxWorkflow,
+};
+
+///
+/// Outbox
+///
+/// Durable queue of inter-canister calls issued after the current message commits.
+///
+/// Enqueue inside the same update that changes local state: if the update
+/// traps, the entry is rolled back with it; once it commits, a timer worker
+/// dispatches the call with exponential backoff until it succeeds or exhausts
+/// its attempt budget. Delivery is at-least-once, so targets should be
+/// idempotent for repeated calls.
+///
+
+pub struct Outbox;
+
+impl Outbox {
+    /// Candid-encode `args` and enqueue a call to `target::method`.
+    pub fn enqueue<A: ArgumentEncoder>(
+        target: Principal,
+        method: &str,
+        args: A,
+    ) -> Result<u64, Error> {
+        let args = encode_args(args)
+            .map_err(|err| Error::invalid(format!("outbox args encode failed: {err}")))?;
+        Self::enqueue_raw(target, method, args, 0)
+    }
+
+    /// Enqueue a call with pre-encoded Candid arguments and attached cycles.
+    pub fn enqueue_raw(
+        target: Principal,
+        method: &str,
+        args: Vec<u8>,
+        cycles: u128,
+    ) -> Result<u64, Error> {
+        OutboxWorkflow::enqueue(target, method, args, cycles).map_err(Error::from)
+    }
+
+    /// Number of calls still waiting to dispatch or retry.
+    #[must_use]
+    pub fn pending_count() -> u64 {
+        OutboxWorkflow::pending_count()
+    }
+
+    /// Calls that exhausted their retry budget.
+    #[must_use]
+    pub fn failed(page: PageRequest) -> Page<OutboxFailedEntry> {
+        OutboxWorkflow::failed(page)
+    }
+
+    pub fn admin(command: OutboxAdminCommand) -> Result<(), Error> {
+        OutboxWorkflow::admin(command).map_err(Error::from)
+    }
+}
@@ -3,12 +3,15 @@ use crate::{
     dto::{
         error::Error,
         placement::sharding::{
-            ShardingPartitionKeysResponse, ShardingPlanStateResponse, ShardingRegistryResponse,
+            ShardingPartitionKeysResponse, ShardingPlanResponse, ShardingRegistryResponse,
         },
     },
+    ops::placement::sharding::strategy::PlacementStrategyOps,
     workflow::placement::sharding::{ShardingWorkflow, query::ShardingQuery},
 };
 
+pub use crate::domain::policy::pure::placement::strategy::{PlacementCandidate, PlacementInput};
+
 ///
 /// ShardingApi
 ///
@@ -52,14 +55,29 @@ impl ShardingApi {
             .map_err(Error::from)
     }
 
-    /// Perform a dry-run shard assignment and return the resulting plan.
+    /// Perform a dry-run shard assignment and return the resulting plan,
+    /// including the placement strategy that made the decision.
     pub fn plan_assign_to_pool(
         pool: &str,
         partition_key: impl AsRef<str>,
-    ) -> Result<ShardingPlanStateResponse, Error> {
+    ) -> Result<ShardingPlanResponse, Error> {
         ShardingWorkflow::plan_assign_to_pool(pool, partition_key).map_err(Error::from)
     }
 
+    /// Register the custom placement strategy named by a pool's
+    /// `strategy = { custom = "<name>" }` config.
+    ///
+    /// Call during init/startup; the registry is heap-only and must be
+    /// repopulated after upgrade. The selector must be deterministic and may
+    /// only return one of the offered candidates; returning `None` requests a
+    /// new shard when the pool has capacity.
+    pub fn register_placement_strategy<F>(name: &str, select: F)
+    where
+        F: Fn(&PlacementInput<'_>) -> Option<Principal> + 'static,
+    {
+        PlacementStrategyOps::register(name, select);
+    }
+
     /// Release (unassign) a partition_key from its shard, freeing shard
     /// capacity and decrementing the shard's load counter. Returns the shard it
     /// was assigned to, or `None` if it was unassigned. Inverse of
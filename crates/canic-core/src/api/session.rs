@@ -0,0 +1,42 @@
+//! Module: api::session
+//!
+//! Responsibility: public facade for sessions opened by verified delegated tokens.
+//! Does not own: token verification, idle-timeout enforcement, or stable schemas.
+//! Boundary: resolves the calling subject and reads sessions through the session workflow.
+
+use crate::{
+    access::auth::resolve_authenticated_identity,
+    dto::{
+        page::{Page, PageRequest},
+        session::SessionEntry,
+    },
+    ops::ic::IcOps,
+    workflow::session::SessionWorkflow,
+};
+
+///
+/// SessionApi
+///
+/// Sessions opened when `auth::authenticated(...)` verifies a delegated token
+/// and kept alive by `auth::session_active("...")`.
+///
+/// Sessions are written only by update calls; a query may verify a token but
+/// its session changes are discarded with the rest of its state.
+///
+
+pub struct SessionApi;
+
+impl SessionApi {
+    /// Return the unexpired session of the calling subject, if any.
+    #[must_use]
+    pub fn current() -> Option<SessionEntry> {
+        let subject = resolve_authenticated_identity(IcOps::msg_caller()).authenticated_subject;
+
+        SessionWorkflow::current(subject, IcOps::now_nanos())
+    }
+
+    #[must_use]
+    pub fn list(page: PageRequest) -> Page<SessionEntry> {
+        SessionWorkflow::list(page, IcOps::now_nanos())
+    }
+}
@@ -4,7 +4,11 @@
 //! Does not own: timer state, recurrence, arbitration, or domain scheduling policy.
 //! Boundary: macro-expanded downstream code delegates to TimerWorkflow.
 
-use crate::workflow::runtime::timer::{ApplicationTimerId, TimerWorkflow};
+use crate::{
+    domain::cron::CronSchedule,
+    dto::error::Error,
+    workflow::runtime::timer::{ApplicationTimerId, TimerWorkflow},
+};
 use std::{future::Future, time::Duration};
 
 /// Opaque, single-owner handle for a cancellable application timer.
@@ -48,6 +52,26 @@ impl TimerApi {
         ))
     }
 
+    /// Schedule a cancellable task at each occurrence of a five-field UTC cron expression.
+    ///
+    /// Timers do not survive upgrades; call this from `canic_setup` so the
+    /// schedule is re-armed from the current time after every install and upgrade.
+    pub fn set_schedule<F, Fut>(
+        expr: &str,
+        label: impl Into<String>,
+        task: F,
+    ) -> Result<TimerHandle, Error>
+    where
+        F: FnMut() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let schedule = CronSchedule::parse(expr).map_err(|err| Error::invalid(err.to_string()))?;
+
+        TimerWorkflow::set_application_schedule(schedule, label, task)
+            .map(TimerHandle)
+            .ok_or_else(|| Error::invalid(format!("cron expression '{expr}' never fires")))
+    }
+
     /// Consume a timer handle and suppress any future invocation.
     #[must_use]
     pub fn cancel(handle: TimerHandle) -> bool {
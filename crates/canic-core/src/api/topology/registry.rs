@@ -1,5 +1,9 @@
 use crate::{
-    dto::topology::SubnetRegistryResponse, workflow::topology::registry::query::SubnetRegistryQuery,
+    dto::{
+        page::{Page, PageRequest},
+        topology::{CanisterFilter, CanisterSummary, SubnetRegistryResponse},
+    },
+    workflow::topology::registry::query::SubnetRegistryQuery,
 };
 
 ///
@@ -14,3 +18,17 @@ impl SubnetRegistryApi {
         SubnetRegistryQuery::registry()
     }
 }
+
+///
+/// CanisterRegistryApi
+///
+
+pub struct CanisterRegistryApi;
+
+impl CanisterRegistryApi {
+    /// List registered canisters matching `filter`, ordered by canister id.
+    #[must_use]
+    pub fn list(filter: CanisterFilter, page: PageRequest) -> Page<CanisterSummary> {
+        SubnetRegistryQuery::list(&filter, page)
+    }
+}
@@ -19,7 +19,7 @@ use crate::ids::BuildNetwork;
 use std::sync::Arc;
 
 #[doc(hidden)]
-pub use crate::config::{ConfigError, ConfigTomlIssue};
+pub use crate::config::{ConfigError, ConfigSourceLocation, ConfigTomlIssue};
 
 #[doc(hidden)]
 pub mod compiled {
@@ -32,9 +32,9 @@ pub mod compiled {
             DiagnosticsCanisterConfig, FleetInitMode, FleetServicesConfig, IcpRefillPolicy,
             LogConfig, MetricsCanisterConfig, MetricsProfile, NAME_MAX_BYTES, PoolImport,
             RoleAttestationConfig, RoleDeclaration, RoleDeclarationKind, ScalePool,
-            ScalePoolPolicy, ScalingConfig, ServicesConfig, ShardPool, ShardPoolPolicy,
-            ShardingConfig, Standards, StandardsCanisterConfig, SubnetConfig, TopupPolicy,
-            Whitelist, validate_canister_role_name,
+            ScalePoolPolicy, ScalingConfig, ServicesConfig, ShardPlacementStrategy, ShardPool,
+            ShardPoolPolicy, ShardingConfig, Standards, StandardsCanisterConfig, SubnetConfig,
+            TopupPolicy, Whitelist, validate_canister_role_name,
         },
         ids::{AppId, BuildNetwork, CanisterRole, SubnetSlotId},
     };
@@ -204,6 +204,21 @@ kind = "root"
         ));
     }
 
+    #[test]
+    fn parse_errors_carry_source_location() {
+        let source = format!("{MINIMAL_CONFIG}\n[subnets.default.canisters.root]\nkind = 7\n");
+        let error = parse_config_model(&source).expect_err("duplicate table must reject");
+
+        let ConfigError::CannotParseToml {
+            location: Some(location),
+            ..
+        } = error
+        else {
+            panic!("expected located TOML parse error, got {error:?}");
+        };
+        assert_eq!(location.line, 12);
+    }
+
     #[test]
     fn runtime_root_key_injection_sets_local_missing_key() {
         let mut config = ConfigModel::test_default();
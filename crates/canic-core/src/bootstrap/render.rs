@@ -12,8 +12,9 @@ use crate::{
         CyclesFundingPolicyConfig, DelegatedTokenConfig, DiagnosticsCanisterConfig, FleetInitMode,
         FleetServicesConfig, IcpRefillPolicy, LogConfig, MetricsCanisterConfig, MetricsProfile,
         PoolImport, RoleAttestationConfig, RoleDeclaration, RoleDeclarationKind, ScalePool,
-        ScalePoolPolicy, ScalingConfig, ServicesConfig, ShardPool, ShardPoolPolicy, ShardingConfig,
-        Standards, StandardsCanisterConfig, SubnetConfig, TopupPolicy, Whitelist,
+        ScalePoolPolicy, ScalingConfig, ServicesConfig, ShardPlacementStrategy, ShardPool,
+        ShardPoolPolicy, ShardingConfig, Standards, StandardsCanisterConfig, SubnetConfig,
+        TopupPolicy, Whitelist,
     },
     ids::{AppId, BuildNetwork, CanisterRole, SubnetSlotId},
 };
@@ -508,10 +509,12 @@ fn render_cycles_funding_policy(policy: &CyclesFundingPolicyConfig) -> TokenStre
 // Render the per-canister diagnostics config.
 fn render_diagnostics_canister_config(config: DiagnosticsCanisterConfig) -> TokenStream {
     let memory_ledger = config.memory_ledger;
+    let store_catalog = config.store_catalog;
 
     quote! {
         ::canic::__internal::core::bootstrap::compiled::DiagnosticsCanisterConfig {
             memory_ledger: #memory_ledger,
+            store_catalog: #store_catalog,
         }
     }
 }
@@ -776,12 +779,37 @@ fn render_shard_pool_policy(policy: &ShardPoolPolicy) -> TokenStream {
     let capacity = policy.capacity;
     let initial_shards = policy.initial_shards;
     let max_shards = policy.max_shards;
+    let strategy = render_shard_placement_strategy(&policy.strategy);
 
     quote! {
         ::canic::__internal::core::bootstrap::compiled::ShardPoolPolicy {
             capacity: #capacity,
             initial_shards: #initial_shards,
             max_shards: #max_shards,
+            strategy: #strategy,
+        }
+    }
+}
+
+// Render one shard placement strategy selector.
+fn render_shard_placement_strategy(strategy: &ShardPlacementStrategy) -> TokenStream {
+    match strategy {
+        ShardPlacementStrategy::ConsistentHash => {
+            quote!(::canic::__internal::core::bootstrap::compiled::ShardPlacementStrategy::ConsistentHash)
+        }
+        ShardPlacementStrategy::LeastLoaded => {
+            quote!(
+                ::canic::__internal::core::bootstrap::compiled::ShardPlacementStrategy::LeastLoaded
+            )
+        }
+        ShardPlacementStrategy::RoundRobin => {
+            quote!(
+                ::canic::__internal::core::bootstrap::compiled::ShardPlacementStrategy::RoundRobin
+            )
+        }
+        ShardPlacementStrategy::Custom(name) => {
+            let name = render_owned_string(name);
+            quote!(::canic::__internal::core::bootstrap::compiled::ShardPlacementStrategy::Custom(#name))
         }
     }
 }
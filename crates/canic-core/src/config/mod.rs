@@ -4,11 +4,13 @@
 //! Does not own: schema field definitions, validation rules, or endpoint DTOs.
 //! Boundary: bootstrap installs validated config here before ops/workflow reads it.
 
+pub mod overrides;
 pub mod schema;
 #[cfg(any(not(target_arch = "wasm32"), test))]
 mod validation;
 
 use crate::{InternalError, InternalErrorOrigin};
+use overrides::ConfigOverrides;
 use schema::ConfigSchemaError;
 use std::{cell::RefCell, sync::Arc};
 use thiserror::Error as ThisError;
@@ -20,10 +22,23 @@ use schema::Validate;
 use serde_path_to_error::{Path as SerdePath, Segment as SerdePathSegment};
 
 struct InstalledConfig {
+    base: Arc<ConfigModel>,
     model: Arc<ConfigModel>,
+    overrides: ConfigOverrides,
     source_toml: Arc<str>,
 }
 
+impl InstalledConfig {
+    fn new(model: Arc<ConfigModel>, source_toml: &str) -> Self {
+        Self {
+            base: model.clone(),
+            model,
+            overrides: ConfigOverrides::default(),
+            source_toml: Arc::<str>::from(source_toml),
+        }
+    }
+}
+
 thread_local! {
     static CONFIG: RefCell<Option<InstalledConfig>> = const { RefCell::new(None) };
 }
@@ -44,9 +59,10 @@ pub enum ConfigError {
     NotInitialized,
 
     /// TOML could not be parsed into the expected structure.
-    #[error("toml {issue}: {detail}")]
+    #[error("toml {issue}{}: {detail}", location_suffix(location.as_ref()))]
     CannotParseToml {
         issue: ConfigTomlIssue,
+        location: Option<ConfigSourceLocation>,
         detail: String,
     },
 
@@ -75,6 +91,42 @@ pub enum ConfigTomlIssue {
     },
 }
 
+///
+/// ConfigSourceLocation
+///
+/// One-based line and column of a TOML parse failure in the source document.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConfigSourceLocation {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ConfigSourceLocation {
+    /// Resolve a byte offset into `source` to a line and character column.
+    #[must_use]
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let prefix = source.get(..offset).unwrap_or(source);
+        let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: prefix.matches('\n').count() + 1,
+            column: prefix[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigSourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+fn location_suffix(location: Option<&ConfigSourceLocation>) -> String {
+    location.map_or_else(String::new, |location| format!(" at {location}"))
+}
+
 impl From<ConfigError> for InternalError {
     fn from(err: ConfigError) -> Self {
         Self::domain(InternalErrorOrigin::Config, err.to_string())
@@ -136,15 +188,23 @@ impl Config {
         let deserializer = toml::Deserializer::parse(config_str).map_err(|source| {
             ConfigError::CannotParseToml {
                 issue: ConfigTomlIssue::InvalidDocument,
-                detail: source.to_string(),
+                location: source
+                    .span()
+                    .map(|span| ConfigSourceLocation::from_offset(config_str, span.start)),
+                detail: source.message().to_string(),
             }
         })?;
         let config: ConfigModel =
             serde_path_to_error::deserialize(deserializer).map_err(|error| {
                 let issue = classify_toml_issue(config_str, error.path(), error.inner());
+                let location = error
+                    .inner()
+                    .span()
+                    .map(|span| ConfigSourceLocation::from_offset(config_str, span.start));
                 ConfigError::CannotParseToml {
                     issue,
-                    detail: error.into_inner().to_string(),
+                    location,
+                    detail: error.into_inner().message().to_string(),
                 }
             })?;
 
@@ -164,10 +224,7 @@ impl Config {
             }
 
             let model = Arc::new(config);
-            *borrow = Some(InstalledConfig {
-                model: model.clone(),
-                source_toml: Arc::<str>::from(source_toml),
-            });
+            *borrow = Some(InstalledConfig::new(model.clone(), source_toml));
 
             Ok(model)
         })
@@ -181,12 +238,45 @@ impl Config {
         let source_toml =
             toml::to_string_pretty(&config).map_err(|source| ConfigError::CannotParseToml {
                 issue: ConfigTomlIssue::InvalidDocument,
+                location: None,
                 detail: source.to_string(),
             })?;
 
         Self::init_from_model(config, &source_toml)
     }
 
+    /// Layer runtime overrides onto the embedded model and install the result.
+    ///
+    /// Overrides accumulate across calls and live on the heap only: an upgrade
+    /// reinstalls the embedded model without them.
+    pub(crate) fn apply_overrides(
+        overrides: &ConfigOverrides,
+    ) -> Result<Arc<ConfigModel>, ConfigError> {
+        CONFIG.with(|cfg| {
+            let mut borrow = cfg.borrow_mut();
+            let installed = borrow.as_mut().ok_or(ConfigError::NotInitialized)?;
+
+            let merged = installed.overrides.merged(overrides);
+            let mut model = (*installed.base).clone();
+            merged.apply(&mut model)?;
+
+            installed.model = Arc::new(model);
+            installed.overrides = merged;
+
+            Ok(installed.model.clone())
+        })
+    }
+
+    /// Return the runtime overrides currently layered onto the embedded model.
+    pub(crate) fn overrides() -> Result<ConfigOverrides, ConfigError> {
+        CONFIG.with(|cfg| {
+            cfg.borrow()
+                .as_ref()
+                .map(|config| config.overrides.clone())
+                .ok_or(ConfigError::NotInitialized)
+        })
+    }
+
     /// Return the canonical TOML source embedded for the current configuration.
     pub(crate) fn to_toml() -> Result<String, InternalError> {
         CONFIG.with(|cfg| {
@@ -219,10 +309,7 @@ impl Config {
             config.validate().expect("test config must validate");
 
             let model = Arc::new(config);
-            *borrow = Some(InstalledConfig {
-                model: model.clone(),
-                source_toml: Arc::<str>::from(""),
-            });
+            *borrow = Some(InstalledConfig::new(model.clone(), ""));
 
             model
         })
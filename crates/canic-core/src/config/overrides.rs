@@ -0,0 +1,256 @@
+//! Module: config::overrides
+//!
+//! Responsibility: describe and apply runtime overrides for tunable config keys.
+//! Does not own: override transport, authorization, or cascade orchestration.
+//! Boundary: `Config` re-applies the accumulated overrides onto the embedded model.
+
+use super::schema::{ConfigModel, ConfigSchemaError, MAX_LOG_ENTRIES};
+use crate::ids::{CanisterRole, SubnetSlotId};
+use std::collections::BTreeMap;
+
+///
+/// ConfigOverrides
+///
+/// Accumulated runtime overrides. Only keys that are safe to change without
+/// reinstalling (retention limits, worker bounds) are representable here.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConfigOverrides {
+    pub log: LogOverrides,
+    pub scaling_pools: BTreeMap<ScalePoolKey, ScalePoolOverrides>,
+}
+
+///
+/// LogOverrides
+///
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LogOverrides {
+    pub max_entries: Option<u64>,
+    pub max_entry_bytes: Option<u32>,
+    pub max_age: Option<LogMaxAgeOverride>,
+}
+
+///
+/// LogMaxAgeOverride
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogMaxAgeOverride {
+    Unbounded,
+    Secs(u64),
+}
+
+impl LogMaxAgeOverride {
+    #[must_use]
+    pub const fn max_age_secs(self) -> Option<u64> {
+        match self {
+            Self::Unbounded => None,
+            Self::Secs(secs) => Some(secs),
+        }
+    }
+}
+
+///
+/// ScalePoolKey
+///
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ScalePoolKey {
+    pub subnet: SubnetSlotId,
+    pub canister_role: CanisterRole,
+    pub pool: String,
+}
+
+///
+/// ScalePoolOverrides
+///
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ScalePoolOverrides {
+    pub initial_workers: Option<u32>,
+    pub min_workers: Option<u32>,
+    pub max_workers: Option<u32>,
+}
+
+impl ConfigOverrides {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Layer `newer` on top of `self`; keys set in `newer` win.
+    #[must_use]
+    pub fn merged(&self, newer: &Self) -> Self {
+        let mut merged = self.clone();
+        merged.log = LogOverrides {
+            max_entries: newer.log.max_entries.or(self.log.max_entries),
+            max_entry_bytes: newer.log.max_entry_bytes.or(self.log.max_entry_bytes),
+            max_age: newer.log.max_age.or(self.log.max_age),
+        };
+        for (key, newer_pool) in &newer.scaling_pools {
+            let pool = merged.scaling_pools.entry(key.clone()).or_default();
+            *pool = ScalePoolOverrides {
+                initial_workers: newer_pool.initial_workers.or(pool.initial_workers),
+                min_workers: newer_pool.min_workers.or(pool.min_workers),
+                max_workers: newer_pool.max_workers.or(pool.max_workers),
+            };
+        }
+
+        merged
+    }
+
+    /// Apply these overrides to `model`, re-checking the schema rules for every touched section.
+    pub fn apply(&self, model: &mut ConfigModel) -> Result<(), ConfigSchemaError> {
+        let log = &mut model.log;
+        if let Some(max_entries) = self.log.max_entries {
+            log.max_entries = max_entries;
+        }
+        if let Some(max_entry_bytes) = self.log.max_entry_bytes {
+            log.max_entry_bytes = max_entry_bytes;
+        }
+        if let Some(max_age) = self.log.max_age {
+            log.max_age_secs = max_age.max_age_secs();
+        }
+        if log.max_entries > MAX_LOG_ENTRIES {
+            return Err(ConfigSchemaError::ValidationError(format!(
+                "log.max_entries {} exceeds max {MAX_LOG_ENTRIES}",
+                log.max_entries
+            )));
+        }
+
+        for (key, overrides) in &self.scaling_pools {
+            let not_found = || {
+                ConfigSchemaError::ValidationError(format!(
+                    "scaling pool '{}' for canister '{}' in subnet '{}' is not configured",
+                    key.pool, key.canister_role, key.subnet
+                ))
+            };
+            let policy = &mut model
+                .subnets
+                .get_mut(&key.subnet)
+                .and_then(|subnet| subnet.canisters.get_mut(&key.canister_role))
+                .and_then(|canister| canister.scaling.as_mut())
+                .and_then(|scaling| scaling.pools.get_mut(&key.pool))
+                .ok_or_else(not_found)?
+                .policy;
+
+            if let Some(initial_workers) = overrides.initial_workers {
+                policy.initial_workers = initial_workers;
+            }
+            if let Some(min_workers) = overrides.min_workers {
+                policy.min_workers = min_workers;
+            }
+            if let Some(max_workers) = overrides.max_workers {
+                policy.max_workers = max_workers;
+            }
+            if policy.max_workers != 0
+                && (policy.max_workers < policy.min_workers
+                    || policy.max_workers < policy.initial_workers)
+            {
+                return Err(ConfigSchemaError::ValidationError(format!(
+                    "canister '{}' scaling pool '{}' has max_workers below min_workers or initial_workers",
+                    key.canister_role, key.pool
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ---- Tests ----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool_key() -> ScalePoolKey {
+        ScalePoolKey {
+            subnet: SubnetSlotId::DEFAULT,
+            canister_role: CanisterRole::new("worker"),
+            pool: "default".to_string(),
+        }
+    }
+
+    #[test]
+    fn merged_keeps_older_keys_unless_newer_sets_them() {
+        let older = ConfigOverrides {
+            log: LogOverrides {
+                max_entries: Some(10),
+                max_entry_bytes: Some(256),
+                max_age: None,
+            },
+            ..ConfigOverrides::default()
+        };
+        let newer = ConfigOverrides {
+            log: LogOverrides {
+                max_entries: Some(20),
+                max_entry_bytes: None,
+                max_age: Some(LogMaxAgeOverride::Unbounded),
+            },
+            ..ConfigOverrides::default()
+        };
+
+        let merged = older.merged(&newer);
+
+        assert_eq!(merged.log.max_entries, Some(20));
+        assert_eq!(merged.log.max_entry_bytes, Some(256));
+        assert_eq!(merged.log.max_age, Some(LogMaxAgeOverride::Unbounded));
+    }
+
+    #[test]
+    fn apply_updates_log_retention() {
+        let mut model = ConfigModel::test_default();
+        let overrides = ConfigOverrides {
+            log: LogOverrides {
+                max_entries: Some(42),
+                max_entry_bytes: None,
+                max_age: Some(LogMaxAgeOverride::Secs(60)),
+            },
+            ..ConfigOverrides::default()
+        };
+
+        overrides.apply(&mut model).expect("log overrides apply");
+
+        assert_eq!(model.log.max_entries, 42);
+        assert_eq!(model.log.max_age_secs, Some(60));
+    }
+
+    #[test]
+    fn apply_rejects_log_entries_above_max() {
+        let mut model = ConfigModel::test_default();
+        let overrides = ConfigOverrides {
+            log: LogOverrides {
+                max_entries: Some(MAX_LOG_ENTRIES + 1),
+                ..LogOverrides::default()
+            },
+            ..ConfigOverrides::default()
+        };
+
+        overrides
+            .apply(&mut model)
+            .expect_err("max_entries above the cap must be rejected");
+    }
+
+    #[test]
+    fn apply_rejects_unknown_scaling_pool() {
+        let mut model = ConfigModel::test_default();
+        let overrides = ConfigOverrides {
+            scaling_pools: BTreeMap::from([(
+                pool_key(),
+                ScalePoolOverrides {
+                    max_workers: Some(4),
+                    ..ScalePoolOverrides::default()
+                },
+            )]),
+            ..ConfigOverrides::default()
+        };
+
+        let err = overrides
+            .apply(&mut model)
+            .expect_err("unknown pool must be rejected");
+        assert!(err.to_string().contains("is not configured"));
+    }
+}
@@ -19,7 +19,6 @@ mod defaults {
     }
 }
 
-#[cfg(any(not(target_arch = "wasm32"), test))]
 pub const MAX_LOG_ENTRIES: u64 = 100_000;
 
 ///
@@ -200,6 +200,9 @@ pub struct StandardsCanisterConfig {
 pub struct DiagnosticsCanisterConfig {
     #[serde(default)]
     pub memory_ledger: bool,
+
+    #[serde(default)]
+    pub store_catalog: bool,
 }
 
 ///
@@ -587,6 +590,9 @@ pub struct ShardPoolPolicy {
     pub capacity: u32,
     pub initial_shards: u32,
     pub max_shards: u32,
+
+    /// Strategy used to place new partition keys on existing shards
+    pub strategy: ShardPlacementStrategy,
 }
 
 impl Default for ShardPoolPolicy {
@@ -595,10 +601,30 @@ impl Default for ShardPoolPolicy {
             capacity: 1_000,
             initial_shards: 1,
             max_shards: 4,
+            strategy: ShardPlacementStrategy::default(),
         }
     }
 }
 
+///
+/// ShardPlacementStrategy
+///
+/// Per-pool selection strategy for existing shards with spare capacity.
+/// Owned by config schema and resolved by sharding placement workflows.
+///
+/// `custom` names a strategy registered at runtime through the sharding API.
+///
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShardPlacementStrategy {
+    #[default]
+    ConsistentHash,
+    LeastLoaded,
+    RoundRobin,
+    Custom(String),
+}
+
 // -----------------------------------------------------------------------------
 // Tests
 // -----------------------------------------------------------------------------
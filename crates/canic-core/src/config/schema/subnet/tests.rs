@@ -253,6 +253,7 @@ kind = "singleton"
     .expect("minimal canister config should parse");
 
     assert!(!cfg.diagnostics.memory_ledger);
+    assert!(!cfg.diagnostics.store_catalog);
 }
 
 #[test]
@@ -270,6 +271,22 @@ memory_ledger = true
     assert!(cfg.diagnostics.memory_ledger);
 }
 
+#[test]
+fn diagnostics_store_catalog_parses_explicit_opt_in() {
+    let cfg: CanisterConfig = toml::from_str(
+        r#"
+kind = "singleton"
+
+[diagnostics]
+store_catalog = true
+"#,
+    )
+    .expect("diagnostics store catalog config should parse");
+
+    assert!(cfg.diagnostics.store_catalog);
+    assert!(!cfg.diagnostics.memory_ledger);
+}
+
 #[test]
 fn root_canister_rejects_configured_auth_roles() {
     let mut cfg = base_canister_config(CanisterKind::Root);
@@ -358,6 +375,7 @@ fn sharding_pool_policy_requires_positive_capacity_and_shards() {
                 capacity: 0,
                 initial_shards: 1,
                 max_shards: 0,
+                strategy: ShardPlacementStrategy::default(),
             },
         },
     );
@@ -404,6 +422,7 @@ fn sharding_pool_policy_rejects_initial_shards_above_max() {
                 capacity: 10,
                 initial_shards: 3,
                 max_shards: 2,
+                strategy: ShardPlacementStrategy::default(),
             },
         },
     );
@@ -1115,3 +1134,20 @@ kind = "instance"
         .expect("instance role config should exist");
     assert_eq!(cfg.kind, CanisterKind::Instance);
 }
+
+#[test]
+fn sharding_pool_policy_parses_placement_strategies() {
+    let policy: ShardPoolPolicy =
+        toml::from_str("strategy = \"least_loaded\"").expect("policy should parse");
+    assert_eq!(policy.strategy, ShardPlacementStrategy::LeastLoaded);
+
+    let policy: ShardPoolPolicy =
+        toml::from_str("strategy = { custom = \"geo\" }").expect("policy should parse");
+    assert_eq!(
+        policy.strategy,
+        ShardPlacementStrategy::Custom("geo".to_string())
+    );
+
+    let policy: ShardPoolPolicy = toml::from_str("").expect("policy should parse");
+    assert_eq!(policy.strategy, ShardPlacementStrategy::ConsistentHash);
+}
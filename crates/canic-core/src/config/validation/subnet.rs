@@ -7,7 +7,7 @@
 use crate::{
     config::schema::{
         CanisterConfig, CanisterKind, ConfigSchemaError, CyclesFundingPolicyConfig,
-        IcpRefillPolicy, NAME_MAX_BYTES, SubnetConfig, Validate,
+        IcpRefillPolicy, NAME_MAX_BYTES, ShardPlacementStrategy, SubnetConfig, Validate,
     },
     config::validation::validate_canister_role,
     ids::CanisterRole,
@@ -201,6 +201,14 @@ fn validate_sharding(
                 "canister '{role}' sharding pool '{pool_name}' has initial_shards > max_shards",
             )));
         }
+
+        if let ShardPlacementStrategy::Custom(name) = &pool.policy.strategy
+            && (name.is_empty() || name.len() > NAME_MAX_BYTES)
+        {
+            return Err(ConfigSchemaError::ValidationError(format!(
+                "canister '{role}' sharding pool '{pool_name}' custom strategy name must be 1..={NAME_MAX_BYTES} bytes",
+            )));
+        }
     }
 
     Ok(())
@@ -0,0 +1,165 @@
+//! Idempotent update dispatch.
+//!
+//! Wrappers generated for `#[canic_update(idempotent(ttl = "..."))]` route
+//! through here. A call is identified by (caller, method, explicit key or raw
+//! Candid arguments); the first successful response is Candid-encoded into a
+//! shared replay receipt and returned verbatim to retries within the TTL.
+//!
+//! Failed handlers release their reservation instead of caching the error, so
+//! a retry after a transient failure runs the handler again.
+
+use super::{enter_endpoint, perf};
+use crate::{
+    dto::error::Error,
+    ids::EndpointCall,
+    model::idempotency::IdempotentCall,
+    ops::replay::receipt::ReplayReceiptToken,
+    workflow::runtime::idempotency::{IdempotencyWorkflow, IdempotentAdmission},
+};
+use candid::CandidType;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+
+/// Dispatch a synchronous idempotent update endpoint.
+pub fn dispatch_idempotent_update<T, E>(
+    call: EndpointCall,
+    ttl_secs: u64,
+    key: Option<&[u8]>,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E>
+where
+    T: CandidType + DeserializeOwned,
+    E: From<Error>,
+{
+    enter_endpoint();
+    let res = match admit(call, ttl_secs, key) {
+        Ok(IdempotentAdmission::Execute(token)) => complete(&token, f()),
+        Ok(IdempotentAdmission::Replay(bytes)) => decode(&bytes),
+        Err(err) => Err(err.into()),
+    };
+    perf::exit_endpoint(call);
+
+    res
+}
+
+/// Dispatch an asynchronous idempotent update endpoint.
+pub async fn dispatch_idempotent_update_async<T, E, F>(
+    call: EndpointCall,
+    ttl_secs: u64,
+    key: Option<&[u8]>,
+    f: impl FnOnce() -> F,
+) -> Result<T, E>
+where
+    T: CandidType + DeserializeOwned,
+    E: From<Error>,
+    F: Future<Output = Result<T, E>>,
+{
+    enter_endpoint();
+    let res = match admit(call, ttl_secs, key) {
+        Ok(IdempotentAdmission::Execute(token)) => complete(&token, f().await),
+        Ok(IdempotentAdmission::Replay(bytes)) => decode(&bytes),
+        Err(err) => Err(err.into()),
+    };
+    perf::exit_endpoint(call);
+
+    res
+}
+
+fn admit(
+    call: EndpointCall,
+    ttl_secs: u64,
+    key: Option<&[u8]>,
+) -> Result<IdempotentAdmission, Error> {
+    let args = ic_cdk::api::msg_arg_data();
+    IdempotencyWorkflow::admit(
+        IdempotentCall {
+            method: call.endpoint.name,
+            caller: ic_cdk::api::msg_caller(),
+            key,
+            args: &args,
+        },
+        ttl_secs,
+    )
+    .map_err(Error::from)
+}
+
+// Commit a successful response; a failed handler releases its reservation.
+fn complete<T, E>(token: &ReplayReceiptToken, res: Result<T, E>) -> Result<T, E>
+where
+    T: CandidType,
+{
+    let committed = res.as_ref().is_ok_and(|value| {
+        candid::encode_one(value)
+            .is_ok_and(|bytes| IdempotencyWorkflow::commit(token, bytes).is_ok())
+    });
+    if !committed {
+        IdempotencyWorkflow::abort(token);
+    }
+
+    res
+}
+
+fn decode<T, E>(bytes: &[u8]) -> Result<T, E>
+where
+    T: DeserializeOwned + CandidType,
+    E: From<Error>,
+{
+    candid::decode_one(bytes).map_err(|err| {
+        Error::internal(format!(
+            "failed to decode idempotent replay response: {err}"
+        ))
+        .into()
+    })
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cdk::types::Principal, ops::storage::replay::ReplayReceiptOps};
+
+    fn admit_test(args: &[u8]) -> IdempotentAdmission {
+        IdempotencyWorkflow::admit(
+            IdempotentCall {
+                method: "transfer",
+                caller: Principal::from_slice(&[5; 29]),
+                key: None,
+                args,
+            },
+            60,
+        )
+        .expect("admit")
+    }
+
+    #[test]
+    fn successful_response_round_trips_through_the_receipt() {
+        ReplayReceiptOps::reset_for_tests();
+        let IdempotentAdmission::Execute(token) = admit_test(b"ok") else {
+            panic!("expected a fresh admission");
+        };
+        let res: Result<String, Error> = complete(&token, Ok("done".to_string()));
+        assert_eq!(res, Ok("done".to_string()));
+
+        let IdempotentAdmission::Replay(bytes) = admit_test(b"ok") else {
+            panic!("expected a replay");
+        };
+        assert_eq!(decode::<String, Error>(&bytes), Ok("done".to_string()));
+    }
+
+    #[test]
+    fn failed_response_is_not_cached() {
+        ReplayReceiptOps::reset_for_tests();
+        let IdempotentAdmission::Execute(token) = admit_test(b"err") else {
+            panic!("expected a fresh admission");
+        };
+        let _: Result<String, Error> = complete(&token, Err(Error::unavailable("busy")));
+
+        assert!(matches!(
+            admit_test(b"err"),
+            IdempotentAdmission::Execute(_)
+        ));
+    }
+}
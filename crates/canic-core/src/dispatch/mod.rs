@@ -26,6 +26,8 @@
 //! All application behavior belongs in `api` or `workflow`, not here.
 
 pub mod icrc21;
+pub mod idempotency;
+pub mod query_cache;
 
 use crate::{ids::EndpointCall, perf};
 use std::future::Future;
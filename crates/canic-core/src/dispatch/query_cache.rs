@@ -0,0 +1,125 @@
+//! Cached query dispatch.
+//!
+//! Wrappers generated for `#[canic_query(cache(ttl = "..."))]` route through
+//! here. A call is identified by (method, canonical Candid re-encoding of the
+//! keyed arguments); a fresh cached response is decoded and returned without
+//! running the handler.
+//!
+//! Queries cannot persist state, so a miss runs the handler and caches
+//! nothing. Entries are filled by `CacheApi::warm` from update or timer
+//! context through the compute function each wrapper registers.
+
+use super::{enter_endpoint, perf};
+use crate::{
+    dto::error::Error, ids::EndpointCall, ops::runtime::query_cache::CachedQueryResponse,
+    workflow::runtime::query_cache::QueryCacheWorkflow,
+};
+use candid::{
+    CandidType,
+    utils::{ArgumentDecoder, ArgumentEncoder},
+};
+use serde::de::DeserializeOwned;
+use std::future::Future;
+
+pub use crate::ops::runtime::query_cache::{CachedQuery, CachedQueryFuture};
+
+/// Register one cached query endpoint; called from macro-generated constructors.
+pub fn register_cached_query(query: CachedQuery) {
+    QueryCacheWorkflow::register(query);
+}
+
+/// Canonical cache key for the keyed arguments of one call.
+///
+/// Returns `None` when the arguments cannot be encoded, which bypasses the cache.
+#[must_use]
+pub fn cache_key<A: ArgumentEncoder>(args: A) -> Option<Vec<u8>> {
+    candid::encode_args(args).ok()
+}
+
+/// Decode the Candid arguments passed to `CacheApi::warm`.
+pub fn decode_warm_args<'a, A: ArgumentDecoder<'a>>(bytes: &'a [u8]) -> Result<A, Error> {
+    candid::decode_args(bytes)
+        .map_err(|err| Error::invalid(format!("failed to decode cached query arguments: {err}")))
+}
+
+/// Encode a computed response for the cache.
+pub fn cached_response<R: CandidType>(
+    key: Option<Vec<u8>>,
+    response: &R,
+) -> Result<CachedQueryResponse, Error> {
+    let key = key.ok_or_else(|| Error::invalid("failed to encode cached query key"))?;
+    let response = candid::encode_one(response)
+        .map_err(|err| Error::internal(format!("failed to encode cached query response: {err}")))?;
+
+    Ok(CachedQueryResponse { key, response })
+}
+
+/// Dispatch a synchronous cached query endpoint.
+pub fn dispatch_cached_query<R>(call: EndpointCall, key: Option<&[u8]>, f: impl FnOnce() -> R) -> R
+where
+    R: CandidType + DeserializeOwned,
+{
+    enter_endpoint();
+    let res = lookup(call, key).unwrap_or_else(f);
+    perf::exit_endpoint(call);
+
+    res
+}
+
+/// Dispatch an asynchronous cached query endpoint.
+pub async fn dispatch_cached_query_async<R, F>(
+    call: EndpointCall,
+    key: Option<&[u8]>,
+    f: impl FnOnce() -> F,
+) -> R
+where
+    R: CandidType + DeserializeOwned,
+    F: Future<Output = R>,
+{
+    enter_endpoint();
+    let res = match lookup(call, key) {
+        Some(res) => res,
+        None => f().await,
+    };
+    perf::exit_endpoint(call);
+
+    res
+}
+
+// An undecodable entry is treated as a miss so the handler answers instead.
+fn lookup<R>(call: EndpointCall, key: Option<&[u8]>) -> Option<R>
+where
+    R: DeserializeOwned + CandidType,
+{
+    let bytes = QueryCacheWorkflow::lookup(call.endpoint.name, key?)?;
+
+    candid::decode_one(&bytes).ok()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key_is_independent_of_argument_ownership() {
+        let owned = cache_key(("dashboard".to_string(), 7_u64));
+        let borrowed = cache_key((&"dashboard".to_string(), &7_u64));
+
+        assert!(owned.is_some());
+        assert_eq!(owned, borrowed);
+        assert_ne!(owned, cache_key(("dashboard".to_string(), 8_u64)));
+    }
+
+    #[test]
+    fn warm_args_round_trip_through_the_cache_key() {
+        let key = cache_key(("dashboard".to_string(), 7_u64)).expect("key");
+        let (name, page): (String, u64) = decode_warm_args(&key).expect("decode");
+
+        assert_eq!((name.as_str(), page), ("dashboard", 7));
+        assert!(decode_warm_args::<(String,)>(&[0xff]).is_err());
+    }
+}
@@ -0,0 +1,297 @@
+//! Module: domain::cron
+//!
+//! Responsibility: parse five-field cron expressions and compute their next UTC occurrence.
+//! Does not own: timer arming, recurrence bookkeeping, or task execution.
+//! Boundary: the timer workflow asks for the next deadline after each completed run.
+
+use std::{fmt, str::FromStr};
+use thiserror::Error as ThisError;
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_DAY: u64 = 86_400;
+
+// Leap days make some day-of-month/month pairs rare; eight years covers every
+// satisfiable combination (including Feb 29 across a skipped century leap).
+const MAX_SEARCH_DAYS: u64 = 8 * 366;
+
+///
+/// CronSchedule
+///
+/// Parsed `minute hour day-of-month month day-of-week` expression, evaluated in UTC.
+/// Fields accept `*`, values, ranges (`a-b`), lists (`a,b`), and steps (`*/n`, `a-b/n`).
+/// Day-of-week uses 0-6 from Sunday; 7 is accepted as Sunday.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    dom_restricted: bool,
+    dow_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parse a five-field cron expression.
+    pub fn parse(expr: &str) -> Result<Self, CronParseError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, dom, month, dow] = fields.as_slice() else {
+            return Err(CronParseError::FieldCount(fields.len()));
+        };
+
+        let mut days_of_week = parse_field(dow, CronField::DayOfWeek)?;
+        // Fold 7 (Sunday) onto 0.
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes: parse_field(minute, CronField::Minute)?,
+            hours: parse_field(hour, CronField::Hour)?,
+            days_of_month: parse_field(dom, CronField::DayOfMonth)?,
+            months: parse_field(month, CronField::Month)?,
+            days_of_week,
+            dom_restricted: *dom != "*",
+            dow_restricted: *dow != "*",
+        })
+    }
+
+    /// First matching minute strictly after `after_secs` (Unix seconds), if any.
+    #[must_use]
+    pub fn next_after_secs(&self, after_secs: u64) -> Option<u64> {
+        let start = (after_secs / SECS_PER_MINUTE).checked_add(1)? * SECS_PER_MINUTE;
+        let first_day = start / SECS_PER_DAY;
+        let start_minute_of_day = (start % SECS_PER_DAY) / SECS_PER_MINUTE;
+
+        for offset in 0..MAX_SEARCH_DAYS {
+            let day = first_day.checked_add(offset)?;
+            if !self.matches_day(day) {
+                continue;
+            }
+
+            let from = if offset == 0 { start_minute_of_day } else { 0 };
+            if let Some(minute_of_day) = self.first_time_of_day(from) {
+                return day
+                    .checked_mul(SECS_PER_DAY)?
+                    .checked_add(minute_of_day * SECS_PER_MINUTE);
+            }
+        }
+
+        None
+    }
+
+    const fn matches_day(&self, day: u64) -> bool {
+        let (_, month, day_of_month) = civil_from_days(day);
+        if self.months & (1 << month) == 0 {
+            return false;
+        }
+
+        // Unix day 0 was a Thursday.
+        let weekday = (day + 4) % 7;
+        let month_day_match = self.days_of_month & (1 << day_of_month) != 0;
+        let weekday_match = self.days_of_week & (1 << weekday) != 0;
+
+        // Standard cron: when both day fields are restricted, either may match.
+        if self.dom_restricted && self.dow_restricted {
+            month_day_match || weekday_match
+        } else {
+            month_day_match && weekday_match
+        }
+    }
+
+    fn first_time_of_day(&self, from_minute_of_day: u64) -> Option<u64> {
+        (from_minute_of_day..24 * 60).find(|minute_of_day| {
+            self.hours & (1 << (minute_of_day / 60)) != 0
+                && self.minutes & (1 << (minute_of_day % 60)) != 0
+        })
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = CronParseError;
+
+    fn from_str(expr: &str) -> Result<Self, Self::Err> {
+        Self::parse(expr)
+    }
+}
+
+///
+/// CronField
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CronField {
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+
+impl CronField {
+    const fn bounds(self) -> (u64, u64) {
+        match self {
+            Self::Minute => (0, 59),
+            Self::Hour => (0, 23),
+            Self::DayOfMonth => (1, 31),
+            Self::Month => (1, 12),
+            Self::DayOfWeek => (0, 7),
+        }
+    }
+}
+
+impl fmt::Display for CronField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::DayOfMonth => "day-of-month",
+            Self::Month => "month",
+            Self::DayOfWeek => "day-of-week",
+        };
+
+        f.write_str(label)
+    }
+}
+
+///
+/// CronParseError
+///
+
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum CronParseError {
+    #[error("cron expression must have 5 fields, found {0}")]
+    FieldCount(usize),
+
+    #[error("invalid cron {field} field '{value}'")]
+    InvalidField { field: CronField, value: String },
+}
+
+fn parse_field(field_expr: &str, field: CronField) -> Result<u64, CronParseError> {
+    let invalid = || CronParseError::InvalidField {
+        field,
+        value: field_expr.to_string(),
+    };
+    let (min, max) = field.bounds();
+    let mut mask = 0u64;
+
+    for part in field_expr.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u64>().map_err(|_| invalid())?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err(invalid());
+        }
+
+        let (lo, hi) = if range == "*" {
+            (min, max)
+        } else if let Some((lo, hi)) = range.split_once('-') {
+            (
+                lo.parse().map_err(|_| invalid())?,
+                hi.parse().map_err(|_| invalid())?,
+            )
+        } else {
+            let value = range.parse().map_err(|_| invalid())?;
+            // `a/n` means "from a to the end of the field, every n".
+            (value, if part.contains('/') { max } else { value })
+        };
+        if lo < min || hi > max || lo > hi {
+            return Err(invalid());
+        }
+
+        for value in (lo..=hi).step_by(usize::try_from(step).map_err(|_| invalid())?) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+// Howard Hinnant's days-to-civil conversion for non-negative Unix days.
+const fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2026-10-15T12:34:56Z, a Thursday.
+    const NOW: u64 = 1_792_067_696;
+
+    fn next(expr: &str, after: u64) -> u64 {
+        CronSchedule::parse(expr)
+            .expect("valid expression")
+            .next_after_secs(after)
+            .expect("expression has a next occurrence")
+    }
+
+    #[test]
+    fn daily_expression_rolls_to_the_next_day_once_passed() {
+        // 2026-10-16T03:00:00Z
+        assert_eq!(next("0 3 * * *", NOW), 1_792_119_600);
+    }
+
+    #[test]
+    fn next_occurrence_is_strictly_after_the_reference_time() {
+        let at = next("*/15 * * * *", NOW);
+
+        assert_eq!(at, 1_792_068_300);
+        assert_eq!(next("*/15 * * * *", at), at + 15 * 60);
+    }
+
+    #[test]
+    fn restricted_day_fields_match_either_day_of_month_or_weekday() {
+        // Sunday 2026-10-18 comes before the 1st of November.
+        assert_eq!(next("0 0 1 * 0", NOW), 1_792_281_600);
+        // Weekday 7 is Sunday as well.
+        assert_eq!(next("0 0 * * 7", NOW), 1_792_281_600);
+    }
+
+    #[test]
+    fn leap_day_expressions_find_the_next_leap_year() {
+        // 2028-02-29T00:00:00Z
+        assert_eq!(next("0 0 29 2 *", NOW), 1_835_395_200);
+    }
+
+    #[test]
+    fn unsatisfiable_expression_has_no_next_occurrence() {
+        let schedule = CronSchedule::parse("0 0 31 2 *").expect("valid fields");
+
+        assert_eq!(schedule.next_after_secs(NOW), None);
+    }
+
+    #[test]
+    fn malformed_expressions_are_rejected_with_the_field() {
+        assert_eq!(
+            CronSchedule::parse("0 3 * *"),
+            Err(CronParseError::FieldCount(4))
+        );
+        assert_eq!(
+            CronSchedule::parse("0 24 * * *"),
+            Err(CronParseError::InvalidField {
+                field: CronField::Hour,
+                value: "24".to_string(),
+            })
+        );
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+    }
+}
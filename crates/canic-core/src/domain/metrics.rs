@@ -531,3 +531,29 @@ impl PlatformCallMetricReason {
         }
     }
 }
+
+///
+/// CallConcurrencyMetricEvent
+///
+/// Admission event dimension for concurrency-limited call classes.
+///
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[remain::sorted]
+pub enum CallConcurrencyMetricEvent {
+    Admitted,
+    Queued,
+    Rejected,
+}
+
+impl CallConcurrencyMetricEvent {
+    /// Return the stable public metrics label for this event.
+    #[must_use]
+    pub const fn metric_label(self) -> &'static str {
+        match self {
+            Self::Admitted => "admitted",
+            Self::Queued => "queued",
+            Self::Rejected => "rejected",
+        }
+    }
+}
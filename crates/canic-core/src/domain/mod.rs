@@ -6,6 +6,7 @@
 pub mod auth;
 pub mod blob_storage;
 pub mod canister;
+pub mod cron;
 pub mod cycles;
 pub mod icp_refill;
 pub mod icrc;
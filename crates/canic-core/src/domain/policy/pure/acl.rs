@@ -0,0 +1,104 @@
+use thiserror::Error as ThisError;
+
+/// Maximum bytes in one ACL role name.
+pub const ACL_ROLE_MAX_BYTES: usize = 64;
+
+/// Maximum roles granted to one principal.
+pub const ACL_MAX_ROLES_PER_PRINCIPAL: usize = 32;
+
+///
+/// AclPolicyViolation
+///
+
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum AclPolicyViolation {
+    #[error("acl role must not be empty")]
+    EmptyRole,
+
+    #[error("acl role is {len} bytes; maximum is {max}")]
+    RoleTooLong { len: usize, max: usize },
+
+    #[error(
+        "acl role '{role}' may contain only lowercase ascii letters, digits, '_', '-', '.', or ':'"
+    )]
+    InvalidRoleCharacter { role: String },
+
+    #[error("principal already holds {count} acl roles; maximum is {max}")]
+    TooManyRoles { count: usize, max: usize },
+}
+
+/// Validate one role name before it is granted or checked.
+pub fn validate_role(role: &str) -> Result<(), AclPolicyViolation> {
+    if role.is_empty() {
+        return Err(AclPolicyViolation::EmptyRole);
+    }
+    if role.len() > ACL_ROLE_MAX_BYTES {
+        return Err(AclPolicyViolation::RoleTooLong {
+            len: role.len(),
+            max: ACL_ROLE_MAX_BYTES,
+        });
+    }
+    if !role.bytes().all(is_role_byte) {
+        return Err(AclPolicyViolation::InvalidRoleCharacter {
+            role: role.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate that a principal holding `count` roles may be granted another.
+pub const fn validate_grant_capacity(count: usize) -> Result<(), AclPolicyViolation> {
+    if count >= ACL_MAX_ROLES_PER_PRINCIPAL {
+        return Err(AclPolicyViolation::TooManyRoles {
+            count,
+            max: ACL_MAX_ROLES_PER_PRINCIPAL,
+        });
+    }
+
+    Ok(())
+}
+
+const fn is_role_byte(byte: u8) -> bool {
+    matches!(byte, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'.' | b':')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_names_are_short_lowercase_tokens() {
+        assert_eq!(validate_role("admin"), Ok(()));
+        assert_eq!(validate_role("billing:read"), Ok(()));
+        assert_eq!(validate_role(""), Err(AclPolicyViolation::EmptyRole));
+        assert_eq!(
+            validate_role("Admin"),
+            Err(AclPolicyViolation::InvalidRoleCharacter {
+                role: "Admin".to_string(),
+            })
+        );
+        assert_eq!(
+            validate_role(&"a".repeat(ACL_ROLE_MAX_BYTES + 1)),
+            Err(AclPolicyViolation::RoleTooLong {
+                len: ACL_ROLE_MAX_BYTES + 1,
+                max: ACL_ROLE_MAX_BYTES,
+            })
+        );
+    }
+
+    #[test]
+    fn grants_stop_at_the_per_principal_limit() {
+        assert_eq!(
+            validate_grant_capacity(ACL_MAX_ROLES_PER_PRINCIPAL - 1),
+            Ok(())
+        );
+        assert_eq!(
+            validate_grant_capacity(ACL_MAX_ROLES_PER_PRINCIPAL),
+            Err(AclPolicyViolation::TooManyRoles {
+                count: ACL_MAX_ROLES_PER_PRINCIPAL,
+                max: ACL_MAX_ROLES_PER_PRINCIPAL,
+            })
+        );
+    }
+}
@@ -134,10 +134,10 @@ mod tests {
         Principal::from_slice(&[id; 29])
     }
 
-    fn grant(role: &str, scopes: &[&str]) -> DelegatedRoleGrantPolicy {
+    fn grant(role: &str, scopes: &[cap::Capability]) -> DelegatedRoleGrantPolicy {
         DelegatedRoleGrantPolicy {
             target: CanisterRole::owned(role.to_string()),
-            scopes: scopes.iter().map(|scope| (*scope).to_string()).collect(),
+            scopes: scopes.iter().map(ToString::to_string).collect(),
         }
     }
 
@@ -168,7 +168,12 @@ mod tests {
 
     #[test]
     fn public_prepare_rejects_privileged_or_custom_scopes() {
-        for denied in [cap::READ, cap::WRITE, cap::ADMIN, "toko.admin"] {
+        for denied in [
+            cap::READ,
+            cap::WRITE,
+            cap::ADMIN,
+            cap::Capability::new("toko.admin"),
+        ] {
             let err = validate_public_delegated_token_prepare(
                 p(7),
                 p(7),
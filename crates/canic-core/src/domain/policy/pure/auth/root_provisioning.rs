@@ -223,10 +223,10 @@ mod tests {
         }
     }
 
-    fn root_grant(role: &str, scopes: &[&str]) -> RootDelegatedRoleGrantPolicy {
+    fn root_grant(role: &str, scopes: &[cap::Capability]) -> RootDelegatedRoleGrantPolicy {
         RootDelegatedRoleGrantPolicy {
             target: CanisterRole::owned(role.to_string()),
-            scopes: scopes.iter().map(|scope| (*scope).to_string()).collect(),
+            scopes: scopes.iter().map(ToString::to_string).collect(),
         }
     }
 
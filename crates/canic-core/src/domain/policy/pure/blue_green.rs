@@ -0,0 +1,84 @@
+use thiserror::Error as ThisError;
+
+/// Maximum bytes in a smoke-check method name.
+pub const BLUE_GREEN_SMOKE_CHECK_METHOD_MAX_BYTES: usize = 128;
+
+/// Longest grace period the old instance may be kept after the routing flip.
+pub const BLUE_GREEN_MAX_GRACE_PERIOD_SECS: u64 = 7 * 24 * 60 * 60;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+///
+/// BlueGreenPolicyViolation
+///
+
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum BlueGreenPolicyViolation {
+    #[error("smoke-check method must not be empty")]
+    EmptySmokeCheckMethod,
+
+    #[error("smoke-check method is {len} bytes; maximum is {max}")]
+    SmokeCheckMethodTooLong { len: usize, max: usize },
+
+    #[error("grace period is {secs}s; maximum is {max}s")]
+    GracePeriodTooLong { secs: u64, max: u64 },
+}
+
+/// Validate a blue/green deployment request before anything is provisioned.
+pub const fn validate_request(
+    smoke_check_method: &str,
+    grace_period_secs: u64,
+) -> Result<(), BlueGreenPolicyViolation> {
+    if smoke_check_method.is_empty() {
+        return Err(BlueGreenPolicyViolation::EmptySmokeCheckMethod);
+    }
+    if smoke_check_method.len() > BLUE_GREEN_SMOKE_CHECK_METHOD_MAX_BYTES {
+        return Err(BlueGreenPolicyViolation::SmokeCheckMethodTooLong {
+            len: smoke_check_method.len(),
+            max: BLUE_GREEN_SMOKE_CHECK_METHOD_MAX_BYTES,
+        });
+    }
+    if grace_period_secs > BLUE_GREEN_MAX_GRACE_PERIOD_SECS {
+        return Err(BlueGreenPolicyViolation::GracePeriodTooLong {
+            secs: grace_period_secs,
+            max: BLUE_GREEN_MAX_GRACE_PERIOD_SECS,
+        });
+    }
+
+    Ok(())
+}
+
+/// IC time at which the old instance of a role flipped at `flipped_at_ns` is retired.
+#[must_use]
+pub const fn retire_at_ns(flipped_at_ns: u64, grace_period_secs: u64) -> u64 {
+    flipped_at_ns.saturating_add(grace_period_secs.saturating_mul(NANOS_PER_SECOND))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_need_a_bounded_method_and_grace_period() {
+        assert_eq!(validate_request("canic_ready", 60), Ok(()));
+        assert_eq!(
+            validate_request("", 60),
+            Err(BlueGreenPolicyViolation::EmptySmokeCheckMethod)
+        );
+        assert!(matches!(
+            validate_request(&"m".repeat(BLUE_GREEN_SMOKE_CHECK_METHOD_MAX_BYTES + 1), 60),
+            Err(BlueGreenPolicyViolation::SmokeCheckMethodTooLong { .. })
+        ));
+        assert!(matches!(
+            validate_request("canic_ready", BLUE_GREEN_MAX_GRACE_PERIOD_SECS + 1),
+            Err(BlueGreenPolicyViolation::GracePeriodTooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn old_instance_retires_after_the_grace_period() {
+        assert_eq!(retire_at_ns(10, 0), 10);
+        assert_eq!(retire_at_ns(10, 2), 10 + 2 * NANOS_PER_SECOND);
+        assert_eq!(retire_at_ns(u64::MAX, 1), u64::MAX);
+    }
+}
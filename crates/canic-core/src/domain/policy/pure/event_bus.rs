@@ -0,0 +1,154 @@
+use thiserror::Error as ThisError;
+
+/// Maximum encoded topic length accepted by the root event bus.
+pub const EVENT_TOPIC_MAX_BYTES: usize = 128;
+
+/// Maximum subscriber callback method-name length.
+pub const EVENT_CALLBACK_METHOD_MAX_BYTES: usize = 64;
+
+/// Maximum opaque payload buffered per event.
+pub const EVENT_PAYLOAD_MAX_BYTES: usize = 16 * 1024;
+
+/// Delivery attempts before a pending delivery moves to the dead-letter queue.
+pub const EVENT_DELIVERY_MAX_ATTEMPTS: u32 = 8;
+
+const EVENT_DELIVERY_BASE_BACKOFF_SECS: u64 = 5;
+const EVENT_DELIVERY_MAX_BACKOFF_SECS: u64 = 3_600;
+
+///
+/// EventBusPolicyViolation
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ThisError)]
+pub enum EventBusPolicyViolation {
+    #[error("event topic must not be empty")]
+    TopicEmpty,
+
+    #[error("event topic is {len} bytes; maximum is {max}")]
+    TopicTooLong { len: usize, max: usize },
+
+    #[error("event topic may only contain ASCII letters, digits, '.', '_', '-', ':' or '/'")]
+    TopicInvalidChar,
+
+    #[error("event callback method must not be empty")]
+    CallbackMethodEmpty,
+
+    #[error("event callback method is {len} bytes; maximum is {max}")]
+    CallbackMethodTooLong { len: usize, max: usize },
+
+    #[error("event payload is {len} bytes; maximum is {max}")]
+    PayloadTooLarge { len: usize, max: usize },
+}
+
+/// Validate a publish/subscribe topic name.
+pub fn validate_topic(topic: &str) -> Result<(), EventBusPolicyViolation> {
+    if topic.is_empty() {
+        return Err(EventBusPolicyViolation::TopicEmpty);
+    }
+    if topic.len() > EVENT_TOPIC_MAX_BYTES {
+        return Err(EventBusPolicyViolation::TopicTooLong {
+            len: topic.len(),
+            max: EVENT_TOPIC_MAX_BYTES,
+        });
+    }
+    if !topic
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-' | b':' | b'/'))
+    {
+        return Err(EventBusPolicyViolation::TopicInvalidChar);
+    }
+
+    Ok(())
+}
+
+/// Validate the subscriber endpoint that receives event envelopes.
+pub const fn validate_callback_method(method: &str) -> Result<(), EventBusPolicyViolation> {
+    if method.is_empty() {
+        return Err(EventBusPolicyViolation::CallbackMethodEmpty);
+    }
+    if method.len() > EVENT_CALLBACK_METHOD_MAX_BYTES {
+        return Err(EventBusPolicyViolation::CallbackMethodTooLong {
+            len: method.len(),
+            max: EVENT_CALLBACK_METHOD_MAX_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate the opaque payload size buffered by root.
+pub const fn validate_payload(len: usize) -> Result<(), EventBusPolicyViolation> {
+    if len > EVENT_PAYLOAD_MAX_BYTES {
+        return Err(EventBusPolicyViolation::PayloadTooLarge {
+            len,
+            max: EVENT_PAYLOAD_MAX_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Return whether a delivery with `attempts` failed attempts is exhausted.
+#[must_use]
+pub const fn delivery_exhausted(attempts: u32) -> bool {
+    attempts >= EVENT_DELIVERY_MAX_ATTEMPTS
+}
+
+/// Exponential retry delay after the given number of failed attempts.
+///
+/// The first retry waits the base delay; each further failure doubles it up
+/// to a one-hour ceiling.
+#[must_use]
+pub const fn retry_backoff_secs(attempts: u32) -> u64 {
+    let exponent = attempts.saturating_sub(1);
+    if exponent >= u64::BITS {
+        return EVENT_DELIVERY_MAX_BACKOFF_SECS;
+    }
+
+    let delay = EVENT_DELIVERY_BASE_BACKOFF_SECS.saturating_mul(1_u64 << exponent);
+    if delay > EVENT_DELIVERY_MAX_BACKOFF_SECS {
+        EVENT_DELIVERY_MAX_BACKOFF_SECS
+    } else {
+        delay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topics_are_bounded_and_restricted_to_path_characters() {
+        assert_eq!(validate_topic("billing/charge.v1"), Ok(()));
+        assert_eq!(validate_topic(""), Err(EventBusPolicyViolation::TopicEmpty));
+        assert_eq!(
+            validate_topic("has space"),
+            Err(EventBusPolicyViolation::TopicInvalidChar)
+        );
+        assert_eq!(
+            validate_topic(&"a".repeat(EVENT_TOPIC_MAX_BYTES + 1)),
+            Err(EventBusPolicyViolation::TopicTooLong {
+                len: EVENT_TOPIC_MAX_BYTES + 1,
+                max: EVENT_TOPIC_MAX_BYTES,
+            })
+        );
+    }
+
+    #[test]
+    fn retry_backoff_doubles_up_to_the_ceiling() {
+        assert_eq!(retry_backoff_secs(1), 5);
+        assert_eq!(retry_backoff_secs(2), 10);
+        assert_eq!(retry_backoff_secs(4), 40);
+        assert_eq!(retry_backoff_secs(20), EVENT_DELIVERY_MAX_BACKOFF_SECS);
+        assert_eq!(
+            retry_backoff_secs(u32::MAX),
+            EVENT_DELIVERY_MAX_BACKOFF_SECS
+        );
+    }
+
+    #[test]
+    fn deliveries_exhaust_at_the_attempt_limit() {
+        assert!(!delivery_exhausted(EVENT_DELIVERY_MAX_ATTEMPTS - 1));
+        assert!(delivery_exhausted(EVENT_DELIVERY_MAX_ATTEMPTS));
+    }
+}
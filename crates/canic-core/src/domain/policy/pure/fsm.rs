@@ -0,0 +1,163 @@
+use thiserror::Error as ThisError;
+
+/// Maximum Candid-encoded state bytes persisted per state-machine instance.
+pub const FSM_STATE_MAX_BYTES: usize = 16 * 1024;
+
+/// Maximum state-machine kind and state-label length.
+pub const FSM_NAME_MAX_BYTES: usize = 64;
+
+/// Consecutive failed attempts of one step before the instance is marked failed.
+pub const FSM_STEP_MAX_ATTEMPTS: u32 = 10;
+
+/// Delay before re-checking a due instance whose kind is not registered yet.
+pub const FSM_UNREGISTERED_RETRY_SECS: u64 = 60;
+
+/// How long completed and failed instances stay queryable.
+pub const FSM_TERMINAL_RETENTION_SECS: u64 = 7 * 24 * 60 * 60;
+
+const FSM_BASE_BACKOFF_SECS: u64 = 2;
+const FSM_MAX_BACKOFF_SECS: u64 = 1_800;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+///
+/// FsmPolicyViolation
+///
+
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum FsmPolicyViolation {
+    #[error("state-machine name must not be empty")]
+    NameEmpty,
+
+    #[error("state-machine name is {len} bytes; maximum is {max}")]
+    NameTooLong { len: usize, max: usize },
+
+    #[error("state-machine state is {len} bytes; maximum is {max}")]
+    StateTooLarge { len: usize, max: usize },
+
+    #[error("state machine '{kind}' does not allow {from} -> {to}")]
+    TransitionNotAllowed {
+        kind: &'static str,
+        from: &'static str,
+        to: &'static str,
+    },
+}
+
+/// Validate a state-machine kind or state label.
+pub const fn validate_name(name: &str) -> Result<(), FsmPolicyViolation> {
+    if name.is_empty() {
+        return Err(FsmPolicyViolation::NameEmpty);
+    }
+    if name.len() > FSM_NAME_MAX_BYTES {
+        return Err(FsmPolicyViolation::NameTooLong {
+            len: name.len(),
+            max: FSM_NAME_MAX_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate the encoded size of one persisted state.
+pub const fn validate_state_len(len: usize) -> Result<(), FsmPolicyViolation> {
+    if len > FSM_STATE_MAX_BYTES {
+        return Err(FsmPolicyViolation::StateTooLarge {
+            len,
+            max: FSM_STATE_MAX_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate one `from -> to` step against a machine's declared transition table.
+pub fn validate_transition(
+    kind: &'static str,
+    transitions: &[(&'static str, &'static str)],
+    from: &'static str,
+    to: &'static str,
+) -> Result<(), FsmPolicyViolation> {
+    if transitions
+        .iter()
+        .any(|(allowed_from, allowed_to)| *allowed_from == from && *allowed_to == to)
+    {
+        return Ok(());
+    }
+
+    Err(FsmPolicyViolation::TransitionNotAllowed { kind, from, to })
+}
+
+/// Return whether a step with `attempts` consecutive failures is exhausted.
+#[must_use]
+pub const fn attempts_exhausted(attempts: u32) -> bool {
+    attempts >= FSM_STEP_MAX_ATTEMPTS
+}
+
+/// Exponential retry delay after the given number of failed attempts,
+/// doubling from the base delay up to a thirty-minute ceiling.
+#[must_use]
+pub const fn retry_backoff_secs(attempts: u32) -> u64 {
+    let exponent = attempts.saturating_sub(1);
+    if exponent >= u64::BITS {
+        return FSM_MAX_BACKOFF_SECS;
+    }
+
+    let delay = FSM_BASE_BACKOFF_SECS.saturating_mul(1_u64 << exponent);
+    if delay > FSM_MAX_BACKOFF_SECS {
+        FSM_MAX_BACKOFF_SECS
+    } else {
+        delay
+    }
+}
+
+/// Return whether a terminal instance last updated at `updated_at_ns` can be pruned.
+#[must_use]
+pub const fn terminal_expired(updated_at_ns: u64, now_ns: u64) -> bool {
+    now_ns.saturating_sub(updated_at_ns) > FSM_TERMINAL_RETENTION_SECS * NANOS_PER_SECOND
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transitions_must_be_declared() {
+        let table = [("allocate", "install"), ("install", "register")];
+        assert_eq!(
+            validate_transition("provision", &table, "allocate", "install"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_transition("provision", &table, "allocate", "register"),
+            Err(FsmPolicyViolation::TransitionNotAllowed {
+                kind: "provision",
+                from: "allocate",
+                to: "register",
+            })
+        );
+    }
+
+    #[test]
+    fn names_and_state_are_bounded() {
+        assert_eq!(validate_name("provision"), Ok(()));
+        assert_eq!(validate_name(""), Err(FsmPolicyViolation::NameEmpty));
+        assert_eq!(
+            validate_state_len(FSM_STATE_MAX_BYTES + 1),
+            Err(FsmPolicyViolation::StateTooLarge {
+                len: FSM_STATE_MAX_BYTES + 1,
+                max: FSM_STATE_MAX_BYTES,
+            })
+        );
+    }
+
+    #[test]
+    fn retry_backoff_doubles_up_to_the_ceiling() {
+        assert_eq!(retry_backoff_secs(1), 2);
+        assert_eq!(retry_backoff_secs(4), 16);
+        assert_eq!(retry_backoff_secs(u32::MAX), FSM_MAX_BACKOFF_SECS);
+        assert!(attempts_exhausted(FSM_STEP_MAX_ATTEMPTS));
+        assert!(!terminal_expired(
+            0,
+            FSM_TERMINAL_RETENTION_SECS * NANOS_PER_SECOND
+        ));
+    }
+}
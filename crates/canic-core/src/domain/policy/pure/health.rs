@@ -0,0 +1,120 @@
+use thiserror::Error as ThisError;
+
+/// Base delay between health reports pushed from a child to root.
+pub const HEALTH_REPORT_INTERVAL_SECS: u64 = 300;
+
+/// Upper bound of the per-report jitter added to the base interval.
+pub const HEALTH_REPORT_JITTER_SECS: u64 = 60;
+
+/// Age after which root flags a child's latest report as stale.
+pub const HEALTH_REPORT_STALE_AFTER_SECS: u64 =
+    3 * (HEALTH_REPORT_INTERVAL_SECS + HEALTH_REPORT_JITTER_SECS);
+
+/// Maximum custom gauges carried per report.
+pub const HEALTH_GAUGE_MAX_COUNT: usize = 16;
+
+/// Maximum custom gauge name length.
+pub const HEALTH_GAUGE_NAME_MAX_BYTES: usize = 64;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+///
+/// HealthPolicyViolation
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ThisError)]
+pub enum HealthPolicyViolation {
+    #[error("health gauge name must not be empty")]
+    GaugeNameEmpty,
+
+    #[error("health gauge name is {len} bytes; maximum is {max}")]
+    GaugeNameTooLong { len: usize, max: usize },
+
+    #[error("health report carries {count} gauges; maximum is {max}")]
+    TooManyGauges { count: usize, max: usize },
+}
+
+/// Validate one custom gauge name.
+pub const fn validate_gauge_name(name: &str) -> Result<(), HealthPolicyViolation> {
+    if name.is_empty() {
+        return Err(HealthPolicyViolation::GaugeNameEmpty);
+    }
+    if name.len() > HEALTH_GAUGE_NAME_MAX_BYTES {
+        return Err(HealthPolicyViolation::GaugeNameTooLong {
+            len: name.len(),
+            max: HEALTH_GAUGE_NAME_MAX_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate the gauge count of one report or one local gauge set.
+pub const fn validate_gauge_count(count: usize) -> Result<(), HealthPolicyViolation> {
+    if count > HEALTH_GAUGE_MAX_COUNT {
+        return Err(HealthPolicyViolation::TooManyGauges {
+            count,
+            max: HEALTH_GAUGE_MAX_COUNT,
+        });
+    }
+
+    Ok(())
+}
+
+/// Delay before the next report, spread by `seed` so children sharing an
+/// install time do not report to root in the same round.
+#[must_use]
+pub const fn report_delay_secs(seed: u64) -> u64 {
+    HEALTH_REPORT_INTERVAL_SECS + seed % (HEALTH_REPORT_JITTER_SECS + 1)
+}
+
+/// Return whether a report received at `received_at_ns` is stale at `now_ns`.
+#[must_use]
+pub const fn is_stale(received_at_ns: u64, now_ns: u64) -> bool {
+    now_ns.saturating_sub(received_at_ns) > HEALTH_REPORT_STALE_AFTER_SECS * NANOS_PER_SECOND
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_delay_stays_inside_the_jitter_window() {
+        assert_eq!(report_delay_secs(0), HEALTH_REPORT_INTERVAL_SECS);
+        assert_eq!(
+            report_delay_secs(HEALTH_REPORT_JITTER_SECS),
+            HEALTH_REPORT_INTERVAL_SECS + HEALTH_REPORT_JITTER_SECS
+        );
+        assert_eq!(
+            report_delay_secs(HEALTH_REPORT_JITTER_SECS + 1),
+            HEALTH_REPORT_INTERVAL_SECS
+        );
+        assert!(
+            report_delay_secs(u64::MAX) <= HEALTH_REPORT_INTERVAL_SECS + HEALTH_REPORT_JITTER_SECS
+        );
+    }
+
+    #[test]
+    fn reports_go_stale_after_three_missed_rounds() {
+        let limit_ns = HEALTH_REPORT_STALE_AFTER_SECS * NANOS_PER_SECOND;
+        assert!(!is_stale(10, 10 + limit_ns));
+        assert!(is_stale(10, 11 + limit_ns));
+        assert!(!is_stale(20, 10));
+    }
+
+    #[test]
+    fn gauges_are_bounded() {
+        assert_eq!(validate_gauge_name("queue_depth"), Ok(()));
+        assert_eq!(
+            validate_gauge_name(""),
+            Err(HealthPolicyViolation::GaugeNameEmpty)
+        );
+        assert_eq!(
+            validate_gauge_count(HEALTH_GAUGE_MAX_COUNT + 1),
+            Err(HealthPolicyViolation::TooManyGauges {
+                count: HEALTH_GAUGE_MAX_COUNT + 1,
+                max: HEALTH_GAUGE_MAX_COUNT,
+            })
+        );
+    }
+}
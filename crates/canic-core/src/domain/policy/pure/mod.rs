@@ -4,18 +4,26 @@
 //! storage, call IC/runtime APIs, spawn timers, serialize wire/storage payloads,
 //! or mutate state.
 
+pub mod acl;
 pub mod auth;
 #[cfg(feature = "blob-storage-billing")]
 pub mod blob_storage;
+pub mod blue_green;
 pub mod cycles;
 pub mod cycles_funding;
 pub mod env;
+pub mod event_bus;
 pub mod fleet_activation;
+pub mod fsm;
+pub mod health;
 pub mod icp_refill;
 pub mod intent;
 pub mod log;
+pub mod outbox;
 pub mod placement;
 pub mod pool;
+pub mod query_cache;
+pub mod session;
 pub mod topology;
 pub mod upgrade;
 
@@ -0,0 +1,104 @@
+use thiserror::Error as ThisError;
+
+/// Maximum encoded argument bytes stored per outbox entry.
+pub const OUTBOX_ARGS_MAX_BYTES: usize = 16 * 1024;
+
+/// Maximum target method-name length.
+pub const OUTBOX_METHOD_MAX_BYTES: usize = 64;
+
+/// Dispatch attempts before an entry is marked permanently failed.
+pub const OUTBOX_MAX_ATTEMPTS: u32 = 10;
+
+const OUTBOX_BASE_BACKOFF_SECS: u64 = 2;
+const OUTBOX_MAX_BACKOFF_SECS: u64 = 1_800;
+
+///
+/// OutboxPolicyViolation
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ThisError)]
+pub enum OutboxPolicyViolation {
+    #[error("outbox method must not be empty")]
+    MethodEmpty,
+
+    #[error("outbox method is {len} bytes; maximum is {max}")]
+    MethodTooLong { len: usize, max: usize },
+
+    #[error("outbox call arguments are {len} bytes; maximum is {max}")]
+    ArgsTooLarge { len: usize, max: usize },
+}
+
+/// Validate one call descriptor before it is persisted.
+pub const fn validate_call(method: &str, args_len: usize) -> Result<(), OutboxPolicyViolation> {
+    if method.is_empty() {
+        return Err(OutboxPolicyViolation::MethodEmpty);
+    }
+    if method.len() > OUTBOX_METHOD_MAX_BYTES {
+        return Err(OutboxPolicyViolation::MethodTooLong {
+            len: method.len(),
+            max: OUTBOX_METHOD_MAX_BYTES,
+        });
+    }
+    if args_len > OUTBOX_ARGS_MAX_BYTES {
+        return Err(OutboxPolicyViolation::ArgsTooLarge {
+            len: args_len,
+            max: OUTBOX_ARGS_MAX_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Return whether an entry with `attempts` failed dispatches is exhausted.
+#[must_use]
+pub const fn attempts_exhausted(attempts: u32) -> bool {
+    attempts >= OUTBOX_MAX_ATTEMPTS
+}
+
+/// Exponential retry delay after the given number of failed dispatches,
+/// doubling from the base delay up to a thirty-minute ceiling.
+#[must_use]
+pub const fn retry_backoff_secs(attempts: u32) -> u64 {
+    let exponent = attempts.saturating_sub(1);
+    if exponent >= u64::BITS {
+        return OUTBOX_MAX_BACKOFF_SECS;
+    }
+
+    let delay = OUTBOX_BASE_BACKOFF_SECS.saturating_mul(1_u64 << exponent);
+    if delay > OUTBOX_MAX_BACKOFF_SECS {
+        OUTBOX_MAX_BACKOFF_SECS
+    } else {
+        delay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_descriptors_are_bounded() {
+        assert_eq!(validate_call("notify", 0), Ok(()));
+        assert_eq!(
+            validate_call("", 0),
+            Err(OutboxPolicyViolation::MethodEmpty)
+        );
+        assert_eq!(
+            validate_call("notify", OUTBOX_ARGS_MAX_BYTES + 1),
+            Err(OutboxPolicyViolation::ArgsTooLarge {
+                len: OUTBOX_ARGS_MAX_BYTES + 1,
+                max: OUTBOX_ARGS_MAX_BYTES,
+            })
+        );
+    }
+
+    #[test]
+    fn retry_backoff_doubles_up_to_the_ceiling() {
+        assert_eq!(retry_backoff_secs(1), 2);
+        assert_eq!(retry_backoff_secs(3), 8);
+        assert_eq!(retry_backoff_secs(30), OUTBOX_MAX_BACKOFF_SECS);
+        assert_eq!(retry_backoff_secs(u32::MAX), OUTBOX_MAX_BACKOFF_SECS);
+        assert!(attempts_exhausted(OUTBOX_MAX_ATTEMPTS));
+        assert!(!attempts_exhausted(OUTBOX_MAX_ATTEMPTS - 1));
+    }
+}
@@ -1,3 +1,5 @@
 pub mod scaling;
 #[cfg(feature = "sharding")]
 pub mod sharding;
+#[cfg(feature = "sharding")]
+pub mod strategy;
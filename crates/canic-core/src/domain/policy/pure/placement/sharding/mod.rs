@@ -7,9 +7,15 @@ pub use metrics::{PoolMetrics, compute_pool_metrics};
 
 use crate::{
     InternalError, InternalErrorOrigin,
-    domain::value::Principal,
+    domain::{
+        policy::pure::placement::strategy::{
+            PlacementCandidate, PlacementInput, PlacementStrategy,
+        },
+        value::Principal,
+    },
     model::placement::sharding::{
-        CreateBlockedReason, ShardPartitionKeyAssignment, ShardPlacement, ShardingPlanState,
+        CreateBlockedReason, PlacementStrategyId, ShardPartitionKeyAssignment, ShardPlacement,
+        ShardingPlanState,
     },
 };
 use backfill::plan_slot_backfill;
@@ -35,6 +41,9 @@ pub enum ShardingPolicyError {
 
     #[error("sharding disabled")]
     ShardingDisabled,
+
+    #[error("shard pool '{pool}' uses unregistered custom placement strategy '{name}'")]
+    UnknownPlacementStrategy { pool: String, name: String },
 }
 
 impl From<ShardingPolicyError> for InternalError {
@@ -49,12 +58,14 @@ pub struct ShardingState<'a> {
     pub metrics: &'a PoolMetrics,
     pub entries: &'a [(Principal, ShardPlacement)],
     pub assignments: &'a [ShardPartitionKeyAssignment],
+    pub strategy: &'a dyn PlacementStrategy,
 }
 
 #[derive(Clone, Debug)]
 pub struct ShardingPlan {
     pub state: ShardingPlanState,
     pub target_slot: Option<u32>,
+    pub strategy: PlacementStrategyId,
 }
 
 pub struct ShardingPolicy;
@@ -84,28 +95,47 @@ impl ShardingPolicy {
         let metrics = state.metrics;
         let entries = state.entries;
 
+        let strategy = state.strategy.id();
+
         let slot_plan = plan_slot_backfill(state.pool, entries, state.max_shards);
 
         if let Some(pid) = Self::lookup_partition_key(partition_key, state.assignments)
             .filter(|pid| exclude_pid != Some(*pid))
         {
             let slot = slot_plan.slots.get(&pid).copied();
-            return Self::make_plan(ShardingPlanState::AlreadyAssigned { pid }, slot);
+            return Self::make_plan(ShardingPlanState::AlreadyAssigned { pid }, slot, strategy);
         }
 
-        let shards_with_capacity: Vec<_> = entries
+        let mut candidates: Vec<_> = entries
             .iter()
             .filter(|(pid, entry)| {
                 entry.pool.as_str() == state.pool
                     && entry_has_capacity(entry)
                     && exclude_pid != Some(*pid)
             })
-            .map(|(pid, _)| *pid)
+            .map(|(pid, entry)| PlacementCandidate {
+                pid: *pid,
+                slot: slot_plan.slots.get(pid).copied().unwrap_or(entry.slot),
+                count: entry.count,
+                capacity: entry.capacity,
+            })
             .collect();
+        candidates.sort_by_key(|candidate| (candidate.slot, candidate.pid));
+
+        let input = PlacementInput {
+            pool: state.pool,
+            partition_key,
+            candidates: &candidates,
+            assignment_count: state.assignments.len() as u64,
+        };
 
-        if let Some(target_pid) = HrwSelector::select(partition_key, &shards_with_capacity) {
+        if let Some(target_pid) = state.strategy.select(&input) {
             let slot = slot_plan.slots.get(&target_pid).copied();
-            return Self::make_plan(ShardingPlanState::UseExisting { pid: target_pid }, slot);
+            return Self::make_plan(
+                ShardingPlanState::UseExisting { pid: target_pid },
+                slot,
+                strategy,
+            );
         }
 
         let max_slots = state.max_shards;
@@ -121,25 +151,36 @@ impl ShardingPolicy {
                     reason: CreateBlockedReason::NoFreeSlots,
                 },
                 None,
+                strategy,
             );
         };
 
         if Self::can_create(*metrics, state.max_shards) {
-            Self::make_plan(ShardingPlanState::CreateAllowed, Some(target_slot))
+            Self::make_plan(
+                ShardingPlanState::CreateAllowed,
+                Some(target_slot),
+                strategy,
+            )
         } else {
             Self::make_plan(
                 ShardingPlanState::CreateBlocked {
                     reason: CreateBlockedReason::PoolAtCapacity,
                 },
                 Some(target_slot),
+                strategy,
             )
         }
     }
 
-    const fn make_plan(state: ShardingPlanState, slot: Option<u32>) -> ShardingPlan {
+    const fn make_plan(
+        state: ShardingPlanState,
+        slot: Option<u32>,
+        strategy: PlacementStrategyId,
+    ) -> ShardingPlan {
         ShardingPlan {
             state,
             target_slot: slot,
+            strategy,
         }
     }
 }
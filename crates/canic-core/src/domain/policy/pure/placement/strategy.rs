@@ -0,0 +1,254 @@
+//! Module: domain::policy::pure::placement::strategy
+//!
+//! Responsibility: select one existing shard for a partition key under a named strategy.
+//! Does not own: strategy configuration, custom strategy registration, or slot allocation.
+//! Boundary: sharding policy consumes these selectors while planning assignments.
+
+use crate::{
+    domain::{policy::pure::placement::sharding::HrwSelector, value::Principal},
+    model::placement::sharding::PlacementStrategyId,
+};
+use std::sync::Arc;
+
+type PlacementSelectFn = dyn Fn(&PlacementInput<'_>) -> Option<Principal> + 'static;
+
+///
+/// PlacementCandidate
+///
+/// One routable shard with spare capacity offered to a placement strategy.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PlacementCandidate {
+    pub pid: Principal,
+    pub slot: u32,
+    pub count: u32,
+    pub capacity: u32,
+}
+
+///
+/// PlacementInput
+///
+/// Pure strategy input for one partition-key placement decision.
+///
+/// `candidates` are ordered by slot, then principal, so strategies observe the
+/// same deterministic sequence on every replica.
+///
+
+#[derive(Clone, Copy, Debug)]
+pub struct PlacementInput<'a> {
+    pub pool: &'a str,
+    pub partition_key: &'a str,
+    pub candidates: &'a [PlacementCandidate],
+    pub assignment_count: u64,
+}
+
+///
+/// PlacementStrategy
+///
+/// Pure selector choosing an existing shard for a new partition-key assignment.
+///
+/// Implementations must be deterministic over their input and must not read
+/// state or perform side effects. Returning `None` makes the planner fall back
+/// to creating a new shard when capacity allows.
+///
+
+pub trait PlacementStrategy {
+    /// Stable identifier recorded in the sharding plan.
+    fn id(&self) -> PlacementStrategyId;
+
+    /// Select one candidate shard, or `None` to request a new shard.
+    fn select(&self, input: &PlacementInput<'_>) -> Option<Principal>;
+}
+
+///
+/// ConsistentHashStrategy
+///
+/// Rendezvous (HRW) hashing of the partition key over candidate principals.
+///
+
+pub struct ConsistentHashStrategy;
+
+impl PlacementStrategy for ConsistentHashStrategy {
+    fn id(&self) -> PlacementStrategyId {
+        PlacementStrategyId::ConsistentHash
+    }
+
+    fn select(&self, input: &PlacementInput<'_>) -> Option<Principal> {
+        let pids: Vec<Principal> = input.candidates.iter().map(|c| c.pid).collect();
+        HrwSelector::select(input.partition_key, &pids)
+    }
+}
+
+///
+/// LeastLoadedStrategy
+///
+/// Select the candidate with the lowest fill ratio, breaking ties by slot.
+///
+
+pub struct LeastLoadedStrategy;
+
+impl PlacementStrategy for LeastLoadedStrategy {
+    fn id(&self) -> PlacementStrategyId {
+        PlacementStrategyId::LeastLoaded
+    }
+
+    fn select(&self, input: &PlacementInput<'_>) -> Option<Principal> {
+        input
+            .candidates
+            .iter()
+            .min_by(|a, b| {
+                // Compare count/capacity without floating point.
+                let lhs = u64::from(a.count) * u64::from(b.capacity.max(1));
+                let rhs = u64::from(b.count) * u64::from(a.capacity.max(1));
+                lhs.cmp(&rhs)
+                    .then(a.slot.cmp(&b.slot))
+                    .then(a.pid.cmp(&b.pid))
+            })
+            .map(|candidate| candidate.pid)
+    }
+}
+
+///
+/// RoundRobinStrategy
+///
+/// Rotate through candidates by the pool's running assignment count.
+///
+
+pub struct RoundRobinStrategy;
+
+impl PlacementStrategy for RoundRobinStrategy {
+    fn id(&self) -> PlacementStrategyId {
+        PlacementStrategyId::RoundRobin
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn select(&self, input: &PlacementInput<'_>) -> Option<Principal> {
+        if input.candidates.is_empty() {
+            return None;
+        }
+
+        let len = input.candidates.len() as u64;
+        let idx = (input.assignment_count % len) as usize;
+        Some(input.candidates[idx].pid)
+    }
+}
+
+///
+/// CustomPlacementStrategy
+///
+/// Named wrapper around a caller-supplied selection closure.
+///
+
+#[derive(Clone)]
+pub struct CustomPlacementStrategy {
+    name: String,
+    select: Arc<PlacementSelectFn>,
+}
+
+impl CustomPlacementStrategy {
+    #[must_use]
+    pub fn new<F>(name: impl Into<String>, select: F) -> Self
+    where
+        F: Fn(&PlacementInput<'_>) -> Option<Principal> + 'static,
+    {
+        Self {
+            name: name.into(),
+            select: Arc::new(select),
+        }
+    }
+}
+
+impl PlacementStrategy for CustomPlacementStrategy {
+    fn id(&self) -> PlacementStrategyId {
+        PlacementStrategyId::Custom(self.name.clone())
+    }
+
+    fn select(&self, input: &PlacementInput<'_>) -> Option<Principal> {
+        // A custom selector may only choose among the offered candidates.
+        (self.select)(input).filter(|pid| input.candidates.iter().any(|c| c.pid == *pid))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(id: u8) -> Principal {
+        Principal::from_slice(&[id; 29])
+    }
+
+    fn candidate(id: u8, slot: u32, count: u32) -> PlacementCandidate {
+        PlacementCandidate {
+            pid: p(id),
+            slot,
+            count,
+            capacity: 10,
+        }
+    }
+
+    fn input(candidates: &[PlacementCandidate], assignment_count: u64) -> PlacementInput<'_> {
+        PlacementInput {
+            pool: "users",
+            partition_key: "alice",
+            candidates,
+            assignment_count,
+        }
+    }
+
+    #[test]
+    fn consistent_hash_matches_hrw_selector() {
+        let candidates = [candidate(1, 0, 0), candidate(2, 1, 0), candidate(3, 2, 0)];
+        let expected = HrwSelector::select("alice", &[p(1), p(2), p(3)]);
+
+        assert_eq!(
+            ConsistentHashStrategy.select(&input(&candidates, 0)),
+            expected
+        );
+    }
+
+    #[test]
+    fn least_loaded_prefers_lowest_fill_then_lowest_slot() {
+        let candidates = [candidate(1, 0, 5), candidate(2, 1, 2), candidate(3, 2, 2)];
+
+        assert_eq!(
+            LeastLoadedStrategy.select(&input(&candidates, 0)),
+            Some(p(2))
+        );
+    }
+
+    #[test]
+    fn round_robin_rotates_by_assignment_count() {
+        let candidates = [candidate(1, 0, 0), candidate(2, 1, 0)];
+
+        assert_eq!(
+            RoundRobinStrategy.select(&input(&candidates, 0)),
+            Some(p(1))
+        );
+        assert_eq!(
+            RoundRobinStrategy.select(&input(&candidates, 1)),
+            Some(p(2))
+        );
+        assert_eq!(
+            RoundRobinStrategy.select(&input(&candidates, 2)),
+            Some(p(1))
+        );
+        assert_eq!(RoundRobinStrategy.select(&input(&[], 2)), None);
+    }
+
+    #[test]
+    fn custom_strategy_cannot_select_outside_candidates() {
+        let candidates = [candidate(1, 0, 0)];
+        let rogue = CustomPlacementStrategy::new("rogue", |_| Some(p(9)));
+        let last =
+            CustomPlacementStrategy::new("last", |input| input.candidates.last().map(|c| c.pid));
+
+        assert_eq!(rogue.select(&input(&candidates, 0)), None);
+        assert_eq!(last.select(&input(&candidates, 0)), Some(p(1)));
+        assert_eq!(last.id(), PlacementStrategyId::Custom("last".to_string()));
+    }
+}
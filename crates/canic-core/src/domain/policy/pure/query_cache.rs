@@ -0,0 +1,88 @@
+use thiserror::Error as ThisError;
+
+/// Maximum Candid-encoded response bytes cached per entry.
+pub const QUERY_CACHE_ENTRY_MAX_BYTES: usize = 32 * 1024;
+
+/// Total response bytes kept in the heap tier before evicting.
+pub const QUERY_CACHE_HEAP_MAX_BYTES: usize = 8 * 1024 * 1024;
+
+/// Entries kept in the heap tier before evicting.
+pub const QUERY_CACHE_HEAP_MAX_ENTRIES: usize = 1_024;
+
+/// Entries kept in the stable spillover tier before evicting.
+pub const QUERY_CACHE_STABLE_MAX_ENTRIES: usize = 4_096;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+///
+/// QueryCachePolicyViolation
+///
+
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum QueryCachePolicyViolation {
+    #[error("cached response is {len} bytes; maximum is {max}")]
+    ResponseTooLarge { len: usize, max: usize },
+}
+
+/// Validate the encoded size of one cached response.
+pub const fn validate_response_len(len: usize) -> Result<(), QueryCachePolicyViolation> {
+    if len > QUERY_CACHE_ENTRY_MAX_BYTES {
+        return Err(QueryCachePolicyViolation::ResponseTooLarge {
+            len,
+            max: QUERY_CACHE_ENTRY_MAX_BYTES,
+        });
+    }
+
+    Ok(())
+}
+
+/// Absolute expiry of an entry stored at `now_ns` with a TTL in seconds.
+#[must_use]
+pub const fn expires_at_ns(now_ns: u64, ttl_secs: u64) -> u64 {
+    now_ns.saturating_add(ttl_secs.saturating_mul(NANOS_PER_SECOND))
+}
+
+/// Return whether an entry expiring at `expires_at_ns` may still be served.
+#[must_use]
+pub const fn is_fresh(expires_at_ns: u64, now_ns: u64) -> bool {
+    now_ns < expires_at_ns
+}
+
+/// Return whether the heap tier must evict before holding `entries` entries of `bytes` total.
+#[must_use]
+pub const fn heap_over_capacity(entries: usize, bytes: usize) -> bool {
+    entries > QUERY_CACHE_HEAP_MAX_ENTRIES || bytes > QUERY_CACHE_HEAP_MAX_BYTES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn responses_are_bounded() {
+        assert_eq!(validate_response_len(QUERY_CACHE_ENTRY_MAX_BYTES), Ok(()));
+        assert_eq!(
+            validate_response_len(QUERY_CACHE_ENTRY_MAX_BYTES + 1),
+            Err(QueryCachePolicyViolation::ResponseTooLarge {
+                len: QUERY_CACHE_ENTRY_MAX_BYTES + 1,
+                max: QUERY_CACHE_ENTRY_MAX_BYTES,
+            })
+        );
+    }
+
+    #[test]
+    fn entries_expire_at_the_ttl_boundary() {
+        let expires_at = expires_at_ns(10, 30);
+        assert_eq!(expires_at, 10 + 30 * NANOS_PER_SECOND);
+        assert!(is_fresh(expires_at, expires_at - 1));
+        assert!(!is_fresh(expires_at, expires_at));
+        assert_eq!(expires_at_ns(u64::MAX, 1), u64::MAX);
+    }
+
+    #[test]
+    fn heap_capacity_counts_entries_and_bytes() {
+        assert!(!heap_over_capacity(QUERY_CACHE_HEAP_MAX_ENTRIES, 0));
+        assert!(heap_over_capacity(QUERY_CACHE_HEAP_MAX_ENTRIES + 1, 0));
+        assert!(heap_over_capacity(1, QUERY_CACHE_HEAP_MAX_BYTES + 1));
+    }
+}
@@ -0,0 +1,64 @@
+/// Sessions kept before the least recently seen one is evicted.
+pub const SESSION_MAX_ENTRIES: usize = 4_096;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Return whether a session expiring at `expires_at_ns` has ended.
+#[must_use]
+pub const fn is_expired(expires_at_ns: u64, now_ns: u64) -> bool {
+    now_ns >= expires_at_ns
+}
+
+/// Return whether a token issued at `token_issued_at_ns` starts a new session
+/// over one opened by a token issued at `session_issued_at_ns`.
+#[must_use]
+pub const fn token_replaces_session(session_issued_at_ns: u64, token_issued_at_ns: u64) -> bool {
+    token_issued_at_ns > session_issued_at_ns
+}
+
+/// Last activity before the current call.
+///
+/// IC time is fixed for the whole message, so a session already touched at
+/// `now_ns` was touched by this call and its previous activity counts instead.
+#[must_use]
+pub const fn seen_before_call_ns(last_seen_ns: u64, previous_seen_ns: u64, now_ns: u64) -> u64 {
+    if last_seen_ns == now_ns {
+        previous_seen_ns
+    } else {
+        last_seen_ns
+    }
+}
+
+/// Return whether a session last active at `seen_ns` exceeded its idle timeout.
+#[must_use]
+pub const fn is_idle(seen_ns: u64, now_ns: u64, idle_timeout_secs: u64) -> bool {
+    now_ns.saturating_sub(seen_ns) > idle_timeout_secs.saturating_mul(NANOS_PER_SECOND)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sessions_expire_at_the_token_expiry() {
+        assert!(!is_expired(10, 9));
+        assert!(is_expired(10, 10));
+    }
+
+    #[test]
+    fn only_newer_tokens_replace_a_session() {
+        assert!(token_replaces_session(5, 6));
+        assert!(!token_replaces_session(5, 5));
+        assert!(!token_replaces_session(5, 4));
+    }
+
+    #[test]
+    fn idle_time_is_measured_from_activity_before_this_call() {
+        assert_eq!(seen_before_call_ns(20, 10, 20), 10);
+        assert_eq!(seen_before_call_ns(20, 10, 30), 20);
+
+        assert!(!is_idle(0, 60 * NANOS_PER_SECOND, 60));
+        assert!(is_idle(0, 60 * NANOS_PER_SECOND + 1, 60));
+        assert!(!is_idle(0, u64::MAX, u64::MAX));
+    }
+}
@@ -0,0 +1,25 @@
+use crate::dto::prelude::*;
+
+//
+// AclEntry
+//
+// Roles granted to one principal, sorted by name.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct AclEntry {
+    pub principal: Principal,
+    pub roles: Vec<String>,
+}
+
+//
+// AclRoleRequest
+//
+// One role to grant to or revoke from a principal.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct AclRoleRequest {
+    pub principal: Principal,
+    pub role: String,
+}
@@ -0,0 +1,52 @@
+use crate::dto::prelude::*;
+
+//
+// BlueGreenDeployRequest
+//
+// Replace the root child of `role` with a freshly provisioned instance.
+// The new instance must answer `smoke_check_method` (an update called with
+// no arguments) before routing flips to it; the old instance is deleted
+// `grace_period_secs` after the flip.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct BlueGreenDeployRequest {
+    pub role: CanisterRole,
+    pub smoke_check_method: String,
+    pub grace_period_secs: u64,
+}
+
+//
+// BlueGreenPhase
+//
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum BlueGreenPhase {
+    Provisioning,
+    SmokeChecking,
+    Draining,
+}
+
+//
+// BlueGreenStatus
+//
+// In-flight blue/green deployment of one role. `routed_pid` is the instance
+// the role's index entry points at; `retire_at_ns` is set once routing has
+// flipped to the green instance. `workflow_id` is the fsm instance reported by
+// `canic_workflow_status`, which keeps the outcome after the deployment ends.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct BlueGreenStatus {
+    pub role: CanisterRole,
+    pub blue_pid: Principal,
+    pub green_pid: Option<Principal>,
+    pub routed_pid: Principal,
+    pub phase: BlueGreenPhase,
+    pub smoke_check_method: String,
+    pub grace_period_secs: u64,
+    pub started_at_ns: u64,
+    pub flipped_at_ns: Option<u64>,
+    pub retire_at_ns: Option<u64>,
+    pub workflow_id: u64,
+}
@@ -1,4 +1,5 @@
 use crate::dto::{
+    config::ConfigPatch,
     prelude::*,
     state::FleetStateInput,
     topology::{FleetDirectoryInput, SubnetDirectoryInput},
@@ -15,6 +16,7 @@ pub struct StateSnapshotInput {
     pub fleet_state: Option<FleetStateInput>,
     pub fleet_directory: Option<FleetDirectoryInput>,
     pub subnet_directory: Option<SubnetDirectoryInput>,
+    pub config_patch: Option<ConfigPatch>,
 }
 
 //
@@ -0,0 +1,53 @@
+use crate::dto::prelude::*;
+
+//
+// ConfigPatch
+//
+// Runtime override for the tunable subset of the embedded `canic.toml`.
+// Omitted fields keep their current value.
+//
+
+#[derive(CandidType, Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct ConfigPatch {
+    pub log: Option<LogConfigPatch>,
+    pub scaling_pools: Vec<ScalePoolPolicyPatch>,
+}
+
+//
+// LogConfigPatch
+//
+
+#[derive(CandidType, Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct LogConfigPatch {
+    pub max_entries: Option<u64>,
+    pub max_entry_bytes: Option<u32>,
+    pub max_age: Option<LogMaxAgePatch>,
+}
+
+//
+// LogMaxAgePatch
+//
+// `Unbounded` removes age-based retention.
+//
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum LogMaxAgePatch {
+    Unbounded,
+    Secs(u64),
+}
+
+//
+// ScalePoolPolicyPatch
+//
+// Worker bounds for one configured scaling pool.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct ScalePoolPolicyPatch {
+    pub subnet: SubnetSlotId,
+    pub canister_role: CanisterRole,
+    pub pool: String,
+    pub initial_workers: Option<u32>,
+    pub min_workers: Option<u32>,
+    pub max_workers: Option<u32>,
+}
@@ -0,0 +1,92 @@
+use crate::dto::prelude::*;
+
+//
+// EventPublishRequest
+//
+// One event sent to the root broker. The payload is opaque to root;
+// publishers and subscribers agree on its Candid type per topic.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventPublishRequest {
+    pub topic: String,
+    pub payload: Vec<u8>,
+}
+
+//
+// EventPublishResponse
+//
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventPublishResponse {
+    pub event_id: u64,
+    pub deliveries: u32,
+}
+
+//
+// EventSubscribeRequest
+//
+// Register the calling canister's `method` as the callback for `topic`.
+// Subscribing again replaces the callback method.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventSubscribeRequest {
+    pub topic: String,
+    pub method: String,
+}
+
+//
+// EventUnsubscribeRequest
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventUnsubscribeRequest {
+    pub topic: String,
+}
+
+//
+// EventEnvelope
+//
+// Argument passed to subscriber callbacks. Delivery is at-least-once, so
+// subscribers should treat `event_id` as the deduplication key.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventEnvelope {
+    pub event_id: u64,
+    pub topic: String,
+    pub publisher: Principal,
+    pub published_at_ns: u64,
+    pub payload: Vec<u8>,
+}
+
+//
+// EventDeadLetter
+//
+// Delivery that exhausted its retry budget.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EventDeadLetter {
+    pub delivery_id: u64,
+    pub event_id: u64,
+    pub topic: String,
+    pub publisher: Principal,
+    pub published_at_ns: u64,
+    pub subscriber: Principal,
+    pub method: String,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+//
+// EventBusAdminCommand
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+#[remain::sorted]
+pub enum EventBusAdminCommand {
+    DiscardDeadLetter { delivery_id: u64 },
+    RedriveDeadLetter { delivery_id: u64 },
+}
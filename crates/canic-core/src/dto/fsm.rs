@@ -0,0 +1,34 @@
+use crate::dto::prelude::*;
+
+//
+// WorkflowStatusResponse
+//
+// Current state of one persisted workflow state-machine instance.
+// `state` is the label of the state the next step runs from, or the final
+// state once the instance completed or failed.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct WorkflowStatusResponse {
+    pub instance_id: u64,
+    pub kind: String,
+    pub state: String,
+    pub status: WorkflowRunStatus,
+    pub attempts: u32,
+    pub created_at_ns: u64,
+    pub updated_at_ns: u64,
+    pub next_run_at_ns: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+//
+// WorkflowRunStatus
+//
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[remain::sorted]
+pub enum WorkflowRunStatus {
+    Completed,
+    Failed,
+    Running,
+}
@@ -0,0 +1,61 @@
+use crate::dto::prelude::*;
+
+//
+// HealthReport
+//
+// Compact health snapshot a child pushes to root on a jittered timer.
+// `last_upgrade_at_ns` is when the running code was last installed or
+// upgraded.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct HealthReport {
+    pub cycles: u128,
+    pub heap_memory_pages: u64,
+    pub stable_memory_pages: u64,
+    pub canister_version: u64,
+    pub last_upgrade_at_ns: u64,
+    pub reported_at_ns: u64,
+    pub gauges: Vec<HealthGauge>,
+}
+
+//
+// HealthGauge
+//
+// Application-defined gauge sampled into each health report.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct HealthGauge {
+    pub name: String,
+    pub value: u64,
+}
+
+//
+// FleetHealthResponse
+//
+// Latest report per registered child, as received by root.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct FleetHealthResponse {
+    pub observed_at_ns: u64,
+    pub stale_after_secs: u64,
+    pub entries: Vec<FleetHealthEntry>,
+}
+
+//
+// FleetHealthEntry
+//
+// `report` is `None` until the child's first report reaches root; such
+// entries are always flagged stale.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct FleetHealthEntry {
+    pub pid: Principal,
+    pub role: CanisterRole,
+    pub report: Option<HealthReport>,
+    pub received_at_ns: Option<u64>,
+    pub stale: bool,
+}
@@ -118,6 +118,115 @@ pub struct MemoryLedgerGenerationEntry {
     pub committed_at: Option<u64>,
 }
 
+///
+/// StoreCatalogResponse
+///
+/// Inventory of every stable store registered through `ic_memory_key!`.
+///
+
+#[derive(CandidType, Clone, Debug, Deserialize)]
+pub struct StoreCatalogResponse {
+    pub stores: Vec<StoreCatalogEntry>,
+}
+
+///
+/// StoreCatalogEntry
+///
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct StoreCatalogEntry {
+    pub stable_key: String,
+    pub label: Option<String>,
+    pub authority: String,
+    pub memory_manager_id: u8,
+    pub range: Option<StoreMemoryRangeEntry>,
+    pub codec: StoreCodec,
+    pub schema_version: Option<u32>,
+    pub entry_count: Option<u64>,
+    pub size: MemoryAllocationSizeEntry,
+    pub retention: StoreRetention,
+    pub indexes: Vec<String>,
+}
+
+///
+/// StoreMemoryRangeEntry
+///
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub struct StoreMemoryRangeEntry {
+    pub start: u8,
+    pub end: u8,
+    pub mode: MemoryRangeAuthorityMode,
+}
+
+///
+/// StoreCodec
+///
+/// Stable structure detected from the memory's layout header.
+///
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum StoreCodec {
+    BTreeMap,
+    Cell,
+    Vec,
+    LogIndex,
+    LogData,
+    Unallocated,
+    Unknown,
+}
+
+///
+/// StoreRetention
+///
+/// Upgrade retention declared by the owning state contract.
+///
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum StoreRetention {
+    Persistent,
+    DiscardOnUpgrade,
+    Undeclared,
+}
+
+///
+/// EagerInitReport
+///
+/// Fallible eager statics that fell back to their default during canister start.
+///
+
+#[derive(CandidType, Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct EagerInitReport {
+    pub failures: Vec<EagerInitFailureEntry>,
+}
+
+///
+/// EagerInitFailureEntry
+///
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct EagerInitFailureEntry {
+    pub name: String,
+    pub error: String,
+}
+
+///
+/// MapMigrationStatus
+///
+/// Progress of one resumable stable map migration.
+///
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct MapMigrationStatus {
+    pub memory_id: u8,
+    pub target: String,
+    pub migrated: u64,
+    pub batches: u64,
+    pub completed: bool,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +240,8 @@ mod tests {
         assert_enum_candid_contract(MemoryCommitRecoveryErrorResponse::UnexpectedGeneration);
         assert_enum_candid_contract(MemoryRangeAuthorityMode::Allowed);
         assert_enum_candid_contract(MemoryAllocationState::Retired);
+        assert_enum_candid_contract(StoreCodec::BTreeMap);
+        assert_enum_candid_contract(StoreRetention::DiscardOnUpgrade);
     }
 
     fn assert_enum_candid_contract<T>(value: T)
@@ -27,26 +27,35 @@
 //! transported, not what guarantees it provides.
 
 pub mod abi;
+pub mod acl;
 pub mod auth;
 pub mod blob_storage;
+pub mod blue_green;
 pub mod canister;
 pub mod capability;
 pub mod cascade;
+pub mod config;
 pub mod cycles;
 pub mod env;
 pub mod error;
+pub mod event_bus;
 pub mod fleet_activation;
+pub mod fsm;
+pub mod health;
 pub mod icp_refill;
 pub mod icrc21;
 pub mod log;
 pub mod memory;
 pub mod metadata;
 pub mod metrics;
+pub mod model;
+pub mod outbox;
 pub mod page;
 pub mod placement;
 pub mod pool;
 pub mod rpc;
 pub mod runtime;
+pub mod session;
 pub mod state;
 pub mod topology;
 pub mod validation;
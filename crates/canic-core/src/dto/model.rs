@@ -0,0 +1,13 @@
+use crate::dto::prelude::*;
+
+//
+// ModelEntry
+//
+// One key/value row read from an application model map.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct ModelEntry<K, V> {
+    pub key: K,
+    pub value: V,
+}
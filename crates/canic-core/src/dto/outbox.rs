@@ -0,0 +1,31 @@
+use crate::dto::prelude::*;
+
+//
+// OutboxFailedEntry
+//
+// Outbox call that exhausted its retry budget and is kept for inspection.
+// Argument bytes are omitted; `args_len` reports their encoded size.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct OutboxFailedEntry {
+    pub entry_id: u64,
+    pub target: Principal,
+    pub method: String,
+    pub args_len: u64,
+    pub cycles: u128,
+    pub enqueued_at_ns: u64,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+//
+// OutboxAdminCommand
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+#[remain::sorted]
+pub enum OutboxAdminCommand {
+    DiscardFailed { entry_id: u64 },
+    RetryFailed { entry_id: u64 },
+}
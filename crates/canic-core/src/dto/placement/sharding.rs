@@ -39,6 +39,29 @@ pub struct ShardEntry {
     pub created_at: u64,
 }
 
+//
+// ShardingPlanResponse
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct ShardingPlanResponse {
+    // Placement strategy configured for the pool that produced this plan.
+    pub strategy: ShardPlacementStrategyResponse,
+    pub state: ShardingPlanStateResponse,
+}
+
+//
+// ShardPlacementStrategyResponse
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub enum ShardPlacementStrategyResponse {
+    ConsistentHash,
+    LeastLoaded,
+    RoundRobin,
+    Custom { name: String },
+}
+
 //
 // ShardingPlanStateResponse
 //
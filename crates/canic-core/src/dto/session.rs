@@ -0,0 +1,20 @@
+use crate::dto::prelude::*;
+
+//
+// SessionEntry
+//
+// Session opened by a verified delegated token. `last_seen_ns` is the latest
+// update call that verified a token or passed an idle check; `idle_closed`
+// sessions stay closed until the subject presents a newer token.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct SessionEntry {
+    pub principal: Principal,
+    pub issuer_pid: Principal,
+    pub scopes: Vec<String>,
+    pub created_at_ns: u64,
+    pub expires_at_ns: u64,
+    pub last_seen_ns: u64,
+    pub idle_closed: bool,
+}
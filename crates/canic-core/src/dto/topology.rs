@@ -1,4 +1,4 @@
-use crate::dto::{canister::CanisterInfo, prelude::*};
+use crate::dto::{canister::CanisterInfo, cycles::Cycles, prelude::*};
 
 //
 // SubnetRegistryResponse
@@ -55,3 +55,57 @@ pub struct IndexEntryResponse {
     pub role: CanisterRole,
     pub pid: Principal,
 }
+
+//
+// CanisterFilter
+//
+// Registry listing filter; unset fields match every canister.
+//
+
+#[derive(CandidType, Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct CanisterFilter {
+    pub role: Option<CanisterRole>,
+    pub parent: Option<Principal>,
+    pub status: Option<CanisterRegistryStatus>,
+}
+
+//
+// CanisterRegistryStatus
+//
+// Install state recorded by the subnet registry.
+//
+
+#[derive(CandidType, Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+pub enum CanisterRegistryStatus {
+    Created,
+    Installed,
+}
+
+//
+// CanisterSummary
+//
+// One registered canister with its root-held metadata.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct CanisterSummary {
+    pub pid: Principal,
+    pub role: CanisterRole,
+    pub parent_pid: Option<Principal>,
+    pub created_at: u64,
+    pub module_hash: Option<Vec<u8>>,
+    pub cycles: Option<CanisterCyclesSnapshot>,
+    pub status: CanisterRegistryStatus,
+}
+
+//
+// CanisterCyclesSnapshot
+//
+// Cycles root has granted to a child, as recorded by the funding ledger.
+//
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub struct CanisterCyclesSnapshot {
+    pub granted_total: Cycles,
+    pub last_granted_at: u64,
+}
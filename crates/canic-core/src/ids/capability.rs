@@ -2,10 +2,105 @@
 //!
 //! Responsibility: canonical capability scope names for delegated auth.
 //! Does not own: authorization policy or capability validation.
-//! Boundary: exposes broad string constants that consumers may specialize later.
+//! Boundary: exposes typed scope constants; `canic::caps!` declares app namespaces.
 
-pub const READ: &str = "read";
-pub const WRITE: &str = "write";
-pub const VERIFY: &str = "verify";
-pub const SESSION: &str = "session";
-pub const ADMIN: &str = "admin";
+use std::fmt::{self, Display};
+
+///
+/// Capability
+///
+/// One delegated-token scope. Only constructible from a `'static` name so
+/// endpoint guards reference declared constants rather than ad hoc strings.
+///
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Capability(&'static str);
+
+impl Capability {
+    /// Declare a capability scope.
+    ///
+    /// # Panics
+    ///
+    /// Panics (at compile time in const contexts) when `scope` is empty.
+    #[must_use]
+    pub const fn new(scope: &'static str) -> Self {
+        assert!(!scope.is_empty(), "capability scope must not be empty");
+        Self(scope)
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl PartialEq<str> for Capability {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Capability {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Capability> for &str {
+    fn eq(&self, other: &Capability) -> bool {
+        *self == other.0
+    }
+}
+
+/// Reject a capability registry that declares the same scope twice.
+///
+/// # Panics
+///
+/// Panics (at compile time in const contexts) on the first duplicate scope.
+pub const fn assert_unique(registry: &[Capability]) {
+    let mut i = 0;
+    while i < registry.len() {
+        let mut j = i + 1;
+        while j < registry.len() {
+            assert!(
+                !bytes_eq(registry[i].0.as_bytes(), registry[j].0.as_bytes()),
+                "capability registry declares a scope twice"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+const fn bytes_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+pub const READ: Capability = Capability::new("read");
+pub const WRITE: Capability = Capability::new("write");
+pub const VERIFY: Capability = Capability::new("verify");
+pub const SESSION: Capability = Capability::new("session");
+pub const ADMIN: Capability = Capability::new("admin");
+
+/// Every built-in capability scope.
+pub const ALL: &[Capability] = &[READ, WRITE, VERIFY, SESSION, ADMIN];
+
+const _: () = assert_unique(ALL);
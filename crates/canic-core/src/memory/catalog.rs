@@ -0,0 +1,181 @@
+//! Module: memory::catalog
+//!
+//! Responsibility: probe declared stable memories for their structure and length.
+//! Does not own: declaration registration, schema metadata, or catalog DTOs.
+//! Boundary: store catalog diagnostics read layout headers through this module.
+
+use super::{manager::MEMORY_MANAGER, policy};
+use crate::cdk::structures::{Memory, memory::MemoryId};
+use ic_memory::{DiagnosticMemorySize, MemoryManagerAuthorityRecord, StaticMemoryDeclarationError};
+
+const BTREE_MAGIC: &[u8; 3] = b"BTR";
+const CELL_MAGIC: &[u8; 3] = b"SCL";
+const VEC_MAGIC: &[u8; 3] = b"SVC";
+const LOG_INDEX_MAGIC: &[u8; 3] = b"GLI";
+const LOG_DATA_MAGIC: &[u8; 3] = b"GLD";
+
+// Header offsets of the persisted length field for each stable structure.
+const BTREE_LENGTH_OFFSET: u64 = 20;
+const VEC_LENGTH_OFFSET: u64 = 4;
+const LOG_INDEX_LENGTH_OFFSET: u64 = 32;
+
+///
+/// StoreLayout
+///
+/// Stable structure identified by its layout header magic.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StoreLayout {
+    BTreeMap,
+    Cell,
+    Vec,
+    LogIndex,
+    LogData,
+    Unallocated,
+    Unknown,
+}
+
+///
+/// StoreProbe
+///
+/// Header-derived facts about one stable memory.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StoreProbe {
+    pub layout: StoreLayout,
+    pub entry_count: Option<u64>,
+    pub size: DiagnosticMemorySize,
+}
+
+/// Return the canonical Canic authority ranges followed by registered static ranges.
+pub fn authority_records() -> Result<Vec<MemoryManagerAuthorityRecord>, StaticMemoryDeclarationError>
+{
+    let mut records = policy::canonical_authority_records();
+    records.extend(
+        ic_memory::static_memory_range_declarations()?
+            .into_iter()
+            .map(ic_memory::StaticMemoryRangeDeclaration::into_record),
+    );
+    Ok(records)
+}
+
+/// Probe the memory-manager memory behind one declared ID without decoding payloads.
+#[must_use]
+pub fn probe_store(id: u8) -> StoreProbe {
+    let memory = MEMORY_MANAGER.with_borrow_mut(|mgr| mgr.get(MemoryId::new(id)));
+    probe_memory(&memory)
+}
+
+fn probe_memory<M: Memory>(memory: &M) -> StoreProbe {
+    let size = DiagnosticMemorySize::from_wasm_pages(memory.size());
+    if size.wasm_pages == 0 {
+        return StoreProbe {
+            layout: StoreLayout::Unallocated,
+            entry_count: None,
+            size,
+        };
+    }
+
+    let mut magic = [0; 3];
+    memory.read(0, &mut magic);
+    let (layout, entry_count) = match &magic {
+        BTREE_MAGIC => (
+            StoreLayout::BTreeMap,
+            Some(read_u64(memory, BTREE_LENGTH_OFFSET)),
+        ),
+        CELL_MAGIC => (StoreLayout::Cell, Some(1)),
+        VEC_MAGIC => (StoreLayout::Vec, Some(read_u64(memory, VEC_LENGTH_OFFSET))),
+        LOG_INDEX_MAGIC => (
+            StoreLayout::LogIndex,
+            Some(read_u64(memory, LOG_INDEX_LENGTH_OFFSET)),
+        ),
+        LOG_DATA_MAGIC => (StoreLayout::LogData, None),
+        _ => (StoreLayout::Unknown, None),
+    };
+
+    StoreProbe {
+        layout,
+        entry_count,
+        size,
+    }
+}
+
+fn read_u64<M: Memory>(memory: &M, offset: u64) -> u64 {
+    let mut buf = [0; 8];
+    memory.read(offset, &mut buf);
+    u64::from_le_bytes(buf)
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdk::structures::{
+        BTreeMap, DefaultMemoryImpl, StableVec,
+        cell::Cell,
+        memory::{MemoryManager, VirtualMemory},
+    };
+
+    fn memory(id: u8) -> VirtualMemory<DefaultMemoryImpl> {
+        MemoryManager::init(DefaultMemoryImpl::default()).get(MemoryId::new(id))
+    }
+
+    #[test]
+    fn probe_reports_unallocated_memory() {
+        let probe = probe_memory(&memory(1));
+
+        assert_eq!(probe.layout, StoreLayout::Unallocated);
+        assert_eq!(probe.entry_count, None);
+        assert_eq!(probe.size.bytes, 0);
+    }
+
+    #[test]
+    fn probe_reads_btree_map_length() {
+        let mem = memory(1);
+        let mut map = BTreeMap::<u64, u64, _>::init(mem.clone());
+        map.insert(1, 10);
+        map.insert(2, 20);
+
+        let probe = probe_memory(&mem);
+        assert_eq!(probe.layout, StoreLayout::BTreeMap);
+        assert_eq!(probe.entry_count, Some(2));
+        assert_eq!(probe.size.bytes, probe.size.wasm_pages * 65_536);
+    }
+
+    #[test]
+    fn probe_reads_vec_length_and_cell_presence() {
+        let vec_mem = memory(1);
+        let vec = StableVec::<u64, _>::init(vec_mem.clone());
+        vec.push(&7);
+        vec.push(&8);
+        vec.push(&9);
+
+        let cell_mem = memory(2);
+        let _cell = Cell::init(cell_mem.clone(), 5_u64);
+
+        assert_eq!(
+            probe_memory(&vec_mem),
+            StoreProbe {
+                layout: StoreLayout::Vec,
+                entry_count: Some(3),
+                size: DiagnosticMemorySize::from_wasm_pages(vec_mem.size()),
+            }
+        );
+        assert_eq!(probe_memory(&cell_mem).layout, StoreLayout::Cell);
+        assert_eq!(probe_memory(&cell_mem).entry_count, Some(1));
+    }
+
+    #[test]
+    fn probe_reports_unknown_header() {
+        let mem = memory(1);
+        mem.grow(1);
+        mem.write(0, b"XYZ");
+
+        assert_eq!(probe_memory(&mem).layout, StoreLayout::Unknown);
+    }
+}
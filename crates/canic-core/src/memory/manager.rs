@@ -4,9 +4,7 @@
 //! Does not own: allocation policy, stable schema records, or lifecycle bootstrap order.
 //! Boundary: memory macros and ledger helpers use this after bootstrap validation.
 
-#[cfg(any(test, target_arch = "wasm32"))]
-use crate::cdk::structures::Memory;
-use crate::cdk::structures::{DefaultMemoryImpl, memory::MemoryManager};
+use crate::cdk::structures::{DefaultMemoryImpl, Memory, memory::MemoryManager};
 use std::cell::RefCell;
 
 #[cfg(any(test, target_arch = "wasm32"))]
@@ -42,6 +40,11 @@ thread_local! {
         RefCell::new(MemoryManager::init(DefaultMemoryImpl::default()));
 }
 
+/// Return the raw stable memory size in 64 KiB pages.
+pub fn stable_memory_pages() -> u64 {
+    DefaultMemoryImpl::default().size()
+}
+
 /// Classify the raw stable memory state before memory-manager bootstrap.
 #[cfg(target_arch = "wasm32")]
 pub fn classify_raw_stable_memory() -> RawStableMemoryState {
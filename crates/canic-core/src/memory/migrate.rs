@@ -0,0 +1,215 @@
+//! Module: memory::migrate
+//!
+//! Responsibility: rewrite one stable BTreeMap's values from an old layout to a new one in bounded batches.
+//! Does not own: cursor persistence, timer scheduling, or value conversion logic.
+//! Boundary: the map migration workflow drives batches and records the returned cursor.
+
+use super::manager::MEMORY_MANAGER;
+use crate::{
+    cdk::structures::{BTreeMap, Memory, Storable, memory::MemoryId, storable::Bound},
+    perf::perf_counter,
+};
+use std::{borrow::Cow, ops::Bound as RangeBound};
+
+///
+/// RawValue
+///
+/// Undecoded value bytes, so one map can hold old and new layouts mid-migration.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct RawValue(Vec<u8>);
+
+impl Storable for RawValue {
+    const BOUND: Bound = Bound::Unbounded;
+
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.0)
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    fn from_bytes(bytes: Cow<'_, [u8]>) -> Self {
+        Self(bytes.into_owned())
+    }
+}
+
+///
+/// MapMigrationLimits
+///
+/// Per-batch bounds; a batch stops at whichever limit is reached first.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MapMigrationLimits {
+    pub max_entries: usize,
+    pub instruction_budget: u64,
+}
+
+///
+/// MapMigrationBatch
+///
+/// Outcome of one bounded batch.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MapMigrationBatch {
+    pub last_key: Option<Vec<u8>>,
+    pub migrated: u64,
+    pub done: bool,
+}
+
+/// Migrate the next batch of the memory-manager map behind `memory_id`.
+///
+/// Entries after `after` (an encoded key returned by a previous batch) are
+/// decoded as `VOld`, converted with `f`, and written back as `VNew`.
+///
+/// The map must not be held open by another handle while the migration runs,
+/// because each batch rewrites the map header through its own handle.
+pub fn migrate_map_batch<K, VOld, VNew>(
+    memory_id: u8,
+    after: Option<&[u8]>,
+    limits: MapMigrationLimits,
+    f: fn(VOld) -> VNew,
+) -> MapMigrationBatch
+where
+    K: Storable + Ord + Clone,
+    VOld: Storable,
+    VNew: Storable,
+{
+    let memory = MEMORY_MANAGER.with_borrow_mut(|mgr| mgr.get(MemoryId::new(memory_id)));
+    migrate_memory_batch::<K, VOld, VNew, _>(memory, after, limits, f)
+}
+
+fn migrate_memory_batch<K, VOld, VNew, M>(
+    memory: M,
+    after: Option<&[u8]>,
+    limits: MapMigrationLimits,
+    f: fn(VOld) -> VNew,
+) -> MapMigrationBatch
+where
+    K: Storable + Ord + Clone,
+    VOld: Storable,
+    VNew: Storable,
+    M: Memory,
+{
+    let start = perf_counter();
+    let mut map = BTreeMap::<K, RawValue, M>::init(memory);
+    let lower = after.map_or(RangeBound::Unbounded, |bytes| {
+        RangeBound::Excluded(K::from_bytes(Cow::Borrowed(bytes)))
+    });
+
+    let pending: Vec<(K, RawValue)> = map
+        .range((lower, RangeBound::Unbounded))
+        .take(limits.max_entries)
+        .map(|entry| (entry.key().clone(), entry.value()))
+        .collect();
+
+    let mut last_key = after.map(<[u8]>::to_vec);
+    let mut migrated = 0;
+    for (key, raw) in pending {
+        let value = f(VOld::from_bytes(Cow::Owned(raw.0)));
+        map.insert(key.clone(), RawValue(value.into_bytes()));
+        last_key = Some(key.into_bytes());
+        migrated += 1;
+
+        if perf_counter().saturating_sub(start) >= limits.instruction_budget {
+            break;
+        }
+    }
+
+    let done = match &last_key {
+        Some(bytes) => {
+            let key = K::from_bytes(Cow::Borrowed(bytes));
+            map.range((RangeBound::Excluded(key), RangeBound::Unbounded))
+                .next()
+                .is_none()
+        }
+        None => map.is_empty(),
+    };
+
+    MapMigrationBatch {
+        last_key,
+        migrated,
+        done,
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cdk::structures::{
+        DefaultMemoryImpl,
+        memory::{MemoryManager, VirtualMemory},
+    };
+
+    const LIMITS: MapMigrationLimits = MapMigrationLimits {
+        max_entries: 2,
+        instruction_budget: u64::MAX,
+    };
+
+    fn memory() -> VirtualMemory<DefaultMemoryImpl> {
+        MemoryManager::init(DefaultMemoryImpl::default()).get(MemoryId::new(1))
+    }
+
+    fn widen(old: u32) -> u64 {
+        u64::from(old) * 10
+    }
+
+    #[test]
+    fn batches_resume_from_cursor_until_map_is_migrated() {
+        let mem = memory();
+        let mut old = BTreeMap::<u64, u32, _>::init(mem.clone());
+        for key in 1..=5 {
+            old.insert(key, u32::try_from(key).expect("small key"));
+        }
+
+        let mut cursor = None;
+        let mut batches = 0;
+        let mut migrated = 0;
+        loop {
+            let batch = migrate_memory_batch::<u64, u32, u64, _>(
+                mem.clone(),
+                cursor.as_deref(),
+                LIMITS,
+                widen,
+            );
+            batches += 1;
+            migrated += batch.migrated;
+            cursor = batch.last_key;
+            if batch.done {
+                break;
+            }
+        }
+
+        assert_eq!(batches, 3);
+        assert_eq!(migrated, 5);
+        let new = BTreeMap::<u64, u64, _>::init(mem);
+        assert_eq!(
+            new.iter()
+                .map(|entry| (*entry.key(), entry.value()))
+                .collect::<Vec<_>>(),
+            vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50)]
+        );
+    }
+
+    #[test]
+    fn empty_map_completes_in_one_batch() {
+        let batch = migrate_memory_batch::<u64, u32, u64, _>(memory(), None, LIMITS, widen);
+
+        assert_eq!(
+            batch,
+            MapMigrationBatch {
+                last_key: None,
+                migrated: 0,
+                done: true,
+            }
+        );
+    }
+}
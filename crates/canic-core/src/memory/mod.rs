@@ -4,13 +4,16 @@
 //! Does not own: stable data schemas, ops storage APIs, or lifecycle orchestration.
 //! Boundary: lifecycle initializes this before stable structures are accessed.
 
+pub(crate) mod catalog;
 pub(crate) mod ledger;
 mod manager;
+pub(crate) mod migrate;
 mod policy;
 pub mod registry;
 pub mod runtime;
 
-pub use crate::{eager_init, eager_static, ic_memory_key, ic_memory_range};
+pub use crate::{eager_init, eager_static, eager_static_try, ic_memory_key, ic_memory_range};
+pub(crate) use manager::stable_memory_pages;
 
 /// Stable allocation-policy authority for Canic core memory declarations.
 pub const CANIC_CORE_MEMORY_AUTHORITY: &str = "canic-core";
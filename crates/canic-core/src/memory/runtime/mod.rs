@@ -4,7 +4,7 @@
 //! Does not own: stable schema definitions, allocation policy, or lifecycle hooks.
 //! Boundary: macros and lifecycle call this before stable-memory-backed statics are used.
 
-use std::sync::Mutex;
+use std::{fmt::Display, sync::Mutex};
 
 // -----------------------------------------------------------------------------
 // Eager TLS
@@ -23,6 +23,7 @@ use std::sync::Mutex;
 // -----------------------------------------------------------------------------
 
 static CANIC_EAGER_TLS: Mutex<Vec<fn()>> = Mutex::new(Vec::new());
+static CANIC_EAGER_INIT_FAILURES: Mutex<Vec<EagerInitFailure>> = Mutex::new(Vec::new());
 #[cfg(any(test, debug_assertions))]
 static TEST_BOOTSTRAP_HOOK: Mutex<Option<fn()>> = Mutex::new(None);
 
@@ -62,6 +63,49 @@ pub fn init_eager_tls() {
     }
 }
 
+///
+/// EagerInitFailure
+///
+/// One `eager_static_try!` initializer that returned an error and fell back.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EagerInitFailure {
+    pub name: &'static str,
+    pub error: String,
+}
+
+/// Record a failed fallible eager initializer.
+///
+/// This is called by the `eager_static_try!` macro before it installs the
+/// fallback value, so failures survive until logging is ready.
+///
+/// # Panics
+///
+/// Panics if the process-local eager init failure mutex is poisoned.
+pub fn record_eager_init_failure(name: &'static str, error: &dyn Display) {
+    CANIC_EAGER_INIT_FAILURES
+        .lock()
+        .expect("eager init failures poisoned")
+        .push(EagerInitFailure {
+            name,
+            error: error.to_string(),
+        });
+}
+
+/// Return every fallible eager initializer that failed during this process.
+///
+/// # Panics
+///
+/// Panics if the process-local eager init failure mutex is poisoned.
+#[must_use]
+pub fn eager_init_report() -> Vec<EagerInitFailure> {
+    CANIC_EAGER_INIT_FAILURES
+        .lock()
+        .expect("eager init failures poisoned")
+        .clone()
+}
+
 /// Return whether memory access is currently allowed during bootstrap.
 #[must_use]
 pub fn is_memory_bootstrap_ready() -> bool {
@@ -167,6 +211,10 @@ mod tests {
             .lock()
             .expect("eager tls queue poisoned")
             .clear();
+        CANIC_EAGER_INIT_FAILURES
+            .lock()
+            .expect("eager init failures poisoned")
+            .clear();
     }
 
     fn bump() {
@@ -191,4 +239,34 @@ mod tests {
         let second = COUNT.load(Ordering::SeqCst);
         assert_eq!(second, 1);
     }
+
+    #[test]
+    fn eager_static_try_falls_back_and_reports_failure() {
+        crate::eager_static_try! {
+            static FALLIBLE: u32 = "not-a-number".parse::<u32>() => 7;
+        }
+
+        let _guard = TEST_LOCK.lock().expect("test lock poisoned");
+        clear_test_queues();
+
+        assert_eq!(FALLIBLE.with(|value| *value), 7);
+
+        let report = eager_init_report();
+        assert_eq!(report.len(), 1);
+        assert!(report[0].name.ends_with("::FALLIBLE"));
+        assert_eq!(report[0].error, "invalid digit found in string");
+    }
+
+    #[test]
+    fn eager_static_try_keeps_successful_value_unreported() {
+        crate::eager_static_try! {
+            static INFALLIBLE: u32 = "42".parse::<u32>() => 0;
+        }
+
+        let _guard = TEST_LOCK.lock().expect("test lock poisoned");
+        clear_test_queues();
+
+        assert_eq!(INFALLIBLE.with(|value| *value), 42);
+        assert!(eager_init_report().is_empty());
+    }
 }
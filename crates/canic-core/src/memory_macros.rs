@@ -137,8 +137,9 @@ macro_rules! eager_init {
 /// Declare a thread-local static and schedule an eager initialization touch.
 #[macro_export]
 macro_rules! eager_static {
-    ($vis:vis static $name:ident : $ty:ty = $init:expr;) => {
+    ($(#[$meta:meta])* $vis:vis static $name:ident : $ty:ty = $init:expr;) => {
         thread_local! {
+            $(#[$meta])*
             $vis static $name: $ty = $init;
         }
 
@@ -154,3 +155,26 @@ macro_rules! eager_static {
         };
     };
 }
+
+/// Declare a thread-local static whose eager initializer may fail.
+///
+/// The initializer must return a `Result` whose error implements `Display`.
+/// On failure the error is recorded for `eager_init_report()`, logged once the
+/// runtime log is ready, and the static is initialized from the fallback.
+#[macro_export]
+macro_rules! eager_static_try {
+    ($vis:vis static $name:ident : $ty:ty = $init:expr => $fallback:expr;) => {
+        $crate::eager_static! {
+            $vis static $name: $ty = match $init {
+                Ok(value) => value,
+                Err(err) => {
+                    $crate::memory::runtime::record_eager_init_failure(
+                        concat!(module_path!(), "::", stringify!($name)),
+                        &err,
+                    );
+                    $fallback
+                }
+            };
+        }
+    };
+}
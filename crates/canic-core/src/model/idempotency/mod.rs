@@ -0,0 +1,120 @@
+//! Module: model::idempotency
+//!
+//! Responsibility: derive replay identities for idempotent update endpoints.
+//! Does not own: receipt storage, response encoding, or endpoint dispatch.
+//! Boundary: the idempotency workflow maps these identities onto shared replay receipts.
+
+use crate::{
+    cdk::types::Principal,
+    model::replay::{CommandKind, OperationId, ReplayActor, ReplayPayloadHasher},
+};
+
+pub const IDEMPOTENT_ENDPOINT_COMMAND_PREFIX: &str = "endpoint.idempotent:";
+pub const IDEMPOTENT_RESPONSE_SCHEMA_VERSION: u32 = 1;
+
+///
+/// IdempotentCall
+///
+/// One update call admitted through an `idempotent(...)` endpoint.
+/// `key` is the client-supplied idempotency key, when the endpoint names one.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IdempotentCall<'a> {
+    pub method: &'a str,
+    pub caller: Principal,
+    pub key: Option<&'a [u8]>,
+    pub args: &'a [u8],
+}
+
+impl IdempotentCall<'_> {
+    /// Replay namespace for this endpoint; bytes the namespace rejects become `_`.
+    #[must_use]
+    pub fn command_kind(&self) -> CommandKind {
+        let method: String = self
+            .method
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-' | ':') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        CommandKind::new(format!("{IDEMPOTENT_ENDPOINT_COMMAND_PREFIX}{method}"))
+            .expect("sanitized idempotent command kind is valid")
+    }
+
+    #[must_use]
+    pub const fn actor(&self) -> ReplayActor {
+        ReplayActor::direct_caller(self.caller)
+    }
+
+    /// Receipt identity: the explicit key when present, otherwise the encoded arguments.
+    #[must_use]
+    pub fn operation_id(&self, command_kind: &CommandKind) -> OperationId {
+        let mut hasher = ReplayPayloadHasher::new(command_kind, &self.actor());
+        hasher.hash_str("operation");
+        hasher.hash_bool(self.key.is_some());
+        hasher.hash_bytes(self.key.unwrap_or(self.args));
+
+        OperationId::from_bytes(hasher.finish())
+    }
+
+    /// Payload binding, so a reused explicit key with different arguments is rejected.
+    #[must_use]
+    pub fn payload_hash(&self, command_kind: &CommandKind) -> [u8; 32] {
+        let mut hasher = ReplayPayloadHasher::new(command_kind, &self.actor());
+        hasher.hash_str("payload");
+        hasher.hash_bytes(self.args);
+
+        hasher.finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call<'a>(key: Option<&'a [u8]>, args: &'a [u8]) -> IdempotentCall<'a> {
+        IdempotentCall {
+            method: "transfer",
+            caller: Principal::from_slice(&[1; 29]),
+            key,
+            args,
+        }
+    }
+
+    #[test]
+    fn explicit_key_identifies_the_operation_but_not_the_payload() {
+        let first = call(Some(b"req-1"), b"a");
+        let second = call(Some(b"req-1"), b"b");
+        let kind = first.command_kind();
+
+        assert_eq!(first.operation_id(&kind), second.operation_id(&kind));
+        assert_ne!(first.payload_hash(&kind), second.payload_hash(&kind));
+        assert_ne!(
+            first.operation_id(&kind),
+            call(None, b"a").operation_id(&kind)
+        );
+    }
+
+    #[test]
+    fn command_kind_sanitizes_exported_method_names() {
+        let call = IdempotentCall {
+            method: "icrc1 transfer!",
+            ..call(None, b"")
+        };
+
+        assert_eq!(
+            call.command_kind().as_str(),
+            "endpoint.idempotent:icrc1_transfer_"
+        );
+    }
+}
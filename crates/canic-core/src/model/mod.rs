@@ -10,6 +10,7 @@ pub mod blob_storage;
 pub mod cycles_funding;
 pub mod env;
 pub mod fleet_activation;
+pub mod idempotency;
 pub mod intent;
 pub mod placement;
 pub mod replay;
@@ -25,6 +25,15 @@ pub struct ShardPartitionKeyAssignment {
     pub pid: Principal,
 }
 
+/// Identifier of the placement strategy that produced a sharding plan.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PlacementStrategyId {
+    ConsistentHash,
+    LeastLoaded,
+    RoundRobin,
+    Custom(String),
+}
+
 /// Result of planning one sharding assignment.
 #[derive(Clone, Debug)]
 pub enum ShardingPlanState {
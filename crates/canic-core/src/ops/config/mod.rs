@@ -8,11 +8,15 @@ use crate::{
     InternalError,
     config::{
         Config, ConfigError, ConfigModel,
+        overrides::{
+            ConfigOverrides, LogMaxAgeOverride, LogOverrides, ScalePoolKey, ScalePoolOverrides,
+        },
         schema::{
             BindingConfig, CanisterConfig, DelegatedTokenConfig, FleetInitMode, LogConfig,
             RoleAttestationConfig, ScalingConfig, SubnetConfig,
         },
     },
+    dto::config::{ConfigPatch, LogConfigPatch, LogMaxAgePatch, ScalePoolPolicyPatch},
     ids::{CanisterRole, SubnetSlotId},
     model::cycles_funding::FundingLimits,
     ops::{OpsError, prelude::*, runtime::env::EnvOps},
@@ -62,6 +66,20 @@ impl ConfigOps {
         Ok(toml)
     }
 
+    /// Validate and layer a runtime patch onto the embedded configuration.
+    pub(crate) fn apply_patch(patch: &ConfigPatch) -> Result<(), InternalError> {
+        Config::apply_overrides(&patch_to_overrides(patch))?;
+
+        Ok(())
+    }
+
+    /// Return the accumulated runtime overrides as one patch, when any are active.
+    pub(crate) fn current_patch() -> Result<Option<ConfigPatch>, InternalError> {
+        let overrides = Config::overrides()?;
+
+        Ok((!overrides.is_empty()).then(|| overrides_to_patch(&overrides)))
+    }
+
     // ---------------------------------------------------------------------
     // Explicit / fallible lookups
     // ---------------------------------------------------------------------
@@ -177,3 +195,63 @@ impl ConfigOps {
         })
     }
 }
+
+fn patch_to_overrides(patch: &ConfigPatch) -> ConfigOverrides {
+    let log = patch.log.unwrap_or_default();
+
+    ConfigOverrides {
+        log: LogOverrides {
+            max_entries: log.max_entries,
+            max_entry_bytes: log.max_entry_bytes,
+            max_age: log.max_age.map(|max_age| match max_age {
+                LogMaxAgePatch::Unbounded => LogMaxAgeOverride::Unbounded,
+                LogMaxAgePatch::Secs(secs) => LogMaxAgeOverride::Secs(secs),
+            }),
+        },
+        scaling_pools: patch
+            .scaling_pools
+            .iter()
+            .map(|pool| {
+                (
+                    ScalePoolKey {
+                        subnet: pool.subnet.clone(),
+                        canister_role: pool.canister_role.clone(),
+                        pool: pool.pool.clone(),
+                    },
+                    ScalePoolOverrides {
+                        initial_workers: pool.initial_workers,
+                        min_workers: pool.min_workers,
+                        max_workers: pool.max_workers,
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
+fn overrides_to_patch(overrides: &ConfigOverrides) -> ConfigPatch {
+    let log = overrides.log;
+
+    ConfigPatch {
+        log: (log != LogOverrides::default()).then_some(LogConfigPatch {
+            max_entries: log.max_entries,
+            max_entry_bytes: log.max_entry_bytes,
+            max_age: log.max_age.map(|max_age| match max_age {
+                LogMaxAgeOverride::Unbounded => LogMaxAgePatch::Unbounded,
+                LogMaxAgeOverride::Secs(secs) => LogMaxAgePatch::Secs(secs),
+            }),
+        }),
+        scaling_pools: overrides
+            .scaling_pools
+            .iter()
+            .map(|(key, pool)| ScalePoolPolicyPatch {
+                subnet: key.subnet.clone(),
+                canister_role: key.canister_role.clone(),
+                pool: key.pool.clone(),
+                initial_workers: pool.initial_workers,
+                min_workers: pool.min_workers,
+                max_workers: pool.max_workers,
+            })
+            .collect(),
+    }
+}
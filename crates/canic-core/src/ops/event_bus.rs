@@ -0,0 +1,55 @@
+//! Module: ops::event_bus
+//!
+//! Responsibility: send event-bus publish, subscription, and delivery calls through RPC.
+//! Does not own: delivery retry decisions, queue storage, or endpoint auth.
+//! Boundary: ops wrapper around the RPC transport for event-bus message names.
+
+use crate::{
+    InternalError,
+    dto::event_bus::{
+        EventEnvelope, EventPublishRequest, EventPublishResponse, EventSubscribeRequest,
+        EventUnsubscribeRequest,
+    },
+    ops::{prelude::*, rpc::RpcOps},
+    protocol,
+};
+
+///
+/// EventBusOps
+///
+/// Operations-layer facade for event-bus RPC sends.
+///
+
+pub struct EventBusOps;
+
+impl EventBusOps {
+    pub async fn publish(
+        root_pid: Principal,
+        request: EventPublishRequest,
+    ) -> Result<EventPublishResponse, InternalError> {
+        RpcOps::call_rpc_result(root_pid, protocol::CANIC_EVENT_PUBLISH, request).await
+    }
+
+    pub async fn subscribe(
+        root_pid: Principal,
+        request: EventSubscribeRequest,
+    ) -> Result<(), InternalError> {
+        RpcOps::call_rpc_result::<()>(root_pid, protocol::CANIC_EVENT_SUBSCRIBE, request).await
+    }
+
+    pub async fn unsubscribe(
+        root_pid: Principal,
+        request: EventUnsubscribeRequest,
+    ) -> Result<(), InternalError> {
+        RpcOps::call_rpc_result::<()>(root_pid, protocol::CANIC_EVENT_UNSUBSCRIBE, request).await
+    }
+
+    /// Invoke one subscriber callback; `Ok` acknowledges the delivery.
+    pub async fn deliver(
+        subscriber: Principal,
+        method: &str,
+        envelope: EventEnvelope,
+    ) -> Result<(), InternalError> {
+        RpcOps::call_rpc_result::<()>(subscriber, method, envelope).await
+    }
+}
@@ -0,0 +1,26 @@
+//! Module: ops::health
+//!
+//! Responsibility: send child health reports to root through RPC.
+//! Does not own: report sampling, scheduling, or staleness policy.
+//! Boundary: ops wrapper around the RPC transport for the health report message.
+
+use crate::{
+    InternalError,
+    dto::health::HealthReport,
+    ops::{prelude::*, rpc::RpcOps},
+    protocol,
+};
+
+///
+/// HealthOps
+///
+/// Operations-layer facade for health report RPC sends.
+///
+
+pub struct HealthOps;
+
+impl HealthOps {
+    pub async fn report(root_pid: Principal, report: HealthReport) -> Result<(), InternalError> {
+        RpcOps::call_rpc_result::<()>(root_pid, protocol::CANIC_HEALTH_REPORT, report).await
+    }
+}
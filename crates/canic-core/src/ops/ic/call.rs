@@ -16,8 +16,11 @@ use crate::{
     ops::{
         OpsError,
         prelude::*,
-        runtime::metrics::{
-            inter_canister_call::InterCanisterCallMetrics, platform_call::PlatformCallMetrics,
+        runtime::{
+            call_concurrency::{CallConcurrencyOps, ConcurrencyClass},
+            metrics::{
+                inter_canister_call::InterCanisterCallMetrics, platform_call::PlatformCallMetrics,
+            },
         },
     },
 };
@@ -45,6 +48,7 @@ impl CallOps {
         CallBuilder {
             inner: InfraCall::bounded_wait(canister_id, method),
             mode: PlatformCallMetricMode::BoundedWait,
+            concurrency: None,
         }
     }
 
@@ -56,6 +60,7 @@ impl CallOps {
         CallBuilder {
             inner: InfraCall::unbounded_wait(canister_id, method),
             mode: PlatformCallMetricMode::UnboundedWait,
+            concurrency: None,
         }
     }
 }
@@ -68,6 +73,7 @@ impl CallOps {
 pub struct CallBuilder<'a> {
     inner: InfraCallBuilder<'a>,
     mode: PlatformCallMetricMode,
+    concurrency: Option<ConcurrencyClass>,
 }
 
 impl CallBuilder<'_> {
@@ -89,7 +95,11 @@ impl CallBuilder<'_> {
                 return Err(err.into());
             }
         };
-        Ok(Self { inner, mode })
+        Ok(Self {
+            inner,
+            mode,
+            concurrency: self.concurrency,
+        })
     }
 
     // multi-arg convenience (IMPORTANT FIX)
@@ -110,7 +120,11 @@ impl CallBuilder<'_> {
                 return Err(err.into());
             }
         };
-        Ok(Self { inner, mode })
+        Ok(Self {
+            inner,
+            mode,
+            concurrency: self.concurrency,
+        })
     }
 
     /// Use pre-encoded Candid arguments (no validation performed).
@@ -119,6 +133,7 @@ impl CallBuilder<'_> {
         CallBuilder {
             inner: self.inner.with_raw_args(args),
             mode: self.mode,
+            concurrency: self.concurrency,
         }
     }
 
@@ -128,7 +143,35 @@ impl CallBuilder<'_> {
         self
     }
 
+    /// Limit calls sharing `class` to `max_in_flight` at once (minimum one).
+    ///
+    /// Calls beyond the limit wait in FIFO order unless
+    /// [`reject_when_saturated`](Self::reject_when_saturated) is set.
+    #[must_use]
+    pub const fn with_concurrency_class(mut self, class: &'static str, max_in_flight: u32) -> Self {
+        self.concurrency = Some(ConcurrencyClass {
+            name: class,
+            max_in_flight,
+            reject_when_saturated: false,
+        });
+        self
+    }
+
+    /// Fail immediately instead of queueing when the concurrency class is saturated.
+    #[must_use]
+    pub const fn reject_when_saturated(mut self) -> Self {
+        if let Some(class) = self.concurrency.as_mut() {
+            class.reject_when_saturated = true;
+        }
+        self
+    }
+
     pub async fn execute(self) -> Result<CallResult, InternalError> {
+        let _permit = match self.concurrency {
+            Some(class) => Some(CallConcurrencyOps::acquire(class).await?),
+            None => None,
+        };
+
         record_generic_call(
             self.mode,
             PlatformCallMetricOutcome::Started,
@@ -9,6 +9,7 @@ pub mod call;
 pub mod icp_refill;
 pub mod mgmt;
 pub mod nns;
+pub mod outbox;
 pub mod release_build;
 
 use crate::cdk::types::Principal;
@@ -35,6 +36,54 @@ impl IcOps {
         ic_cdk::api::canister_cycle_balance().into()
     }
 
+    /// Return the current canister version, or zero on host targets.
+    #[must_use]
+    #[cfg_attr(
+        not(target_arch = "wasm32"),
+        expect(
+            clippy::missing_const_for_fn,
+            reason = "wasm path delegates to ic0-backed version lookup, which is not const"
+        )
+    )]
+    pub fn canister_version() -> u64 {
+        #[cfg(target_arch = "wasm32")]
+        {
+            ic_cdk::api::canister_version()
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            0
+        }
+    }
+
+    /// Return the current heap size in 64 KiB Wasm pages, or zero on host targets.
+    #[must_use]
+    #[cfg_attr(
+        not(target_arch = "wasm32"),
+        expect(
+            clippy::missing_const_for_fn,
+            reason = "wasm path reads the live memory size, which is not const"
+        )
+    )]
+    pub fn heap_memory_pages() -> u64 {
+        #[cfg(target_arch = "wasm32")]
+        {
+            core::arch::wasm32::memory_size(0) as u64
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            0
+        }
+    }
+
+    /// Return the current stable memory size in 64 KiB pages.
+    #[must_use]
+    pub fn stable_memory_pages() -> u64 {
+        crate::memory::stable_memory_pages()
+    }
+
     /// Return the current caller principal.
     #[must_use]
     pub fn msg_caller() -> Principal {
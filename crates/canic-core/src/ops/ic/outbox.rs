@@ -0,0 +1,30 @@
+//! Module: ops::ic::outbox
+//!
+//! Responsibility: dispatch persisted outbox call descriptors.
+//! Does not own: outbox storage, retry decisions, or descriptor validation.
+//! Boundary: ops wrapper around bounded-wait raw calls for the outbox workflow.
+
+use crate::{
+    InternalError,
+    ops::{ic::call::CallOps, storage::outbox::OutboxEntry},
+};
+
+///
+/// OutboxOps
+///
+/// Operations-layer facade for outbox call dispatch.
+///
+
+pub struct OutboxOps;
+
+impl OutboxOps {
+    /// Issue the stored call once; any reply counts as delivered.
+    pub async fn dispatch(entry: &OutboxEntry) -> Result<(), InternalError> {
+        CallOps::bounded_wait(entry.target(), entry.method())
+            .with_raw_args(entry.args())
+            .with_cycles(entry.cycles())
+            .execute()
+            .await
+            .map(|_| ())
+    }
+}
@@ -20,6 +20,8 @@ pub mod cascade;
 pub mod cashier;
 pub mod config;
 pub mod cost_guard;
+pub mod event_bus;
+pub mod health;
 pub mod ic;
 pub mod perf;
 pub mod placement;
@@ -6,8 +6,13 @@
 
 use crate::{
     cdk::types::Principal,
-    dto::placement::sharding::{ShardEntry, ShardingPlanStateResponse},
-    model::placement::sharding::{ShardPartitionKeyAssignment, ShardPlacement, ShardingPlanState},
+    domain::policy::pure::placement::sharding::ShardingPlan,
+    dto::placement::sharding::{
+        ShardEntry, ShardPlacementStrategyResponse, ShardingPlanResponse, ShardingPlanStateResponse,
+    },
+    model::placement::sharding::{
+        PlacementStrategyId, ShardPartitionKeyAssignment, ShardPlacement, ShardingPlanState,
+    },
     storage::stable::sharding::{ShardEntryRecord, ShardKey},
 };
 
@@ -78,16 +83,32 @@ impl ShardEntryMapper {
 }
 
 ///
-/// ShardingPlanStateResponseMapper
+/// ShardingPlanResponseMapper
 ///
-/// Operations-layer mapper for sharding plan states and response views.
+/// Operations-layer mapper for sharding plans and response views.
 ///
 
-pub struct ShardingPlanStateResponseMapper;
+pub struct ShardingPlanResponseMapper;
 
-impl ShardingPlanStateResponseMapper {
+impl ShardingPlanResponseMapper {
     #[must_use]
-    pub fn plan_to_response(state: ShardingPlanState) -> ShardingPlanStateResponse {
+    pub fn plan_to_response(plan: ShardingPlan) -> ShardingPlanResponse {
+        ShardingPlanResponse {
+            strategy: Self::strategy_to_response(plan.strategy),
+            state: Self::state_to_response(plan.state),
+        }
+    }
+
+    fn strategy_to_response(strategy: PlacementStrategyId) -> ShardPlacementStrategyResponse {
+        match strategy {
+            PlacementStrategyId::ConsistentHash => ShardPlacementStrategyResponse::ConsistentHash,
+            PlacementStrategyId::LeastLoaded => ShardPlacementStrategyResponse::LeastLoaded,
+            PlacementStrategyId::RoundRobin => ShardPlacementStrategyResponse::RoundRobin,
+            PlacementStrategyId::Custom(name) => ShardPlacementStrategyResponse::Custom { name },
+        }
+    }
+
+    fn state_to_response(state: ShardingPlanState) -> ShardingPlanStateResponse {
         match state {
             ShardingPlanState::AlreadyAssigned { pid } => {
                 ShardingPlanStateResponse::AlreadyAssigned { pid }
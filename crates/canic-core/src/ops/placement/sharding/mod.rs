@@ -1,7 +1,8 @@
 //! Module: ops::placement::sharding
 //!
-//! Responsibility: group sharding placement mappers.
+//! Responsibility: group sharding placement mappers and strategy resolution.
 //! Does not own: sharding policy, registry mutation, or endpoint DTOs.
 //! Boundary: ops conversion layer for sharding placement views.
 
 pub mod mapper;
+pub mod strategy;
@@ -0,0 +1,84 @@
+//! Module: ops::placement::sharding::strategy
+//!
+//! Responsibility: register custom placement strategies and resolve configured selectors.
+//! Does not own: placement decisions, pool configuration, or registry mutation.
+//! Boundary: sharding workflows resolve one strategy per pool before planning.
+
+use crate::{
+    InternalError,
+    config::schema::ShardPlacementStrategy,
+    domain::{
+        policy::pure::placement::{
+            sharding::ShardingPolicyError,
+            strategy::{
+                ConsistentHashStrategy, CustomPlacementStrategy, LeastLoadedStrategy,
+                PlacementInput, PlacementStrategy, RoundRobinStrategy,
+            },
+        },
+        value::Principal,
+    },
+    log,
+    log::Topic,
+};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+thread_local! {
+    static CUSTOM_STRATEGIES: RefCell<BTreeMap<String, CustomPlacementStrategy>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+///
+/// PlacementStrategyOps
+///
+/// Process-local registry of custom sharding placement strategies.
+///
+/// Invariants:
+/// - Custom strategies must be registered during init/startup before any
+///   assignment for a pool that names them.
+/// - The registry is heap-only and is rebuilt by user startup code after upgrade.
+///
+
+pub struct PlacementStrategyOps;
+
+impl PlacementStrategyOps {
+    /// Register or replace the custom strategy selected by `custom = "<name>"`.
+    pub fn register<F>(name: &str, select: F)
+    where
+        F: Fn(&PlacementInput<'_>) -> Option<Principal> + 'static,
+    {
+        let strategy = CustomPlacementStrategy::new(name, select);
+        CUSTOM_STRATEGIES.with_borrow_mut(|registry| {
+            if registry.insert(name.to_string(), strategy).is_some() {
+                log!(
+                    Topic::Sharding,
+                    Warn,
+                    "custom placement strategy replaced name={name}"
+                );
+            }
+        });
+    }
+
+    /// Resolve the configured strategy for one pool.
+    pub fn resolve(
+        pool: &str,
+        strategy: &ShardPlacementStrategy,
+    ) -> Result<Rc<dyn PlacementStrategy>, InternalError> {
+        let resolved: Rc<dyn PlacementStrategy> = match strategy {
+            ShardPlacementStrategy::ConsistentHash => Rc::new(ConsistentHashStrategy),
+            ShardPlacementStrategy::LeastLoaded => Rc::new(LeastLoadedStrategy),
+            ShardPlacementStrategy::RoundRobin => Rc::new(RoundRobinStrategy),
+            ShardPlacementStrategy::Custom(name) => {
+                let custom = CUSTOM_STRATEGIES.with_borrow(|registry| registry.get(name).cloned());
+                let custom =
+                    custom.ok_or_else(|| ShardingPolicyError::UnknownPlacementStrategy {
+                        pool: pool.to_string(),
+                        name: name.clone(),
+                    })?;
+
+                Rc::new(custom)
+            }
+        };
+
+        Ok(resolved)
+    }
+}
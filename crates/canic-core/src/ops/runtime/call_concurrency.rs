@@ -0,0 +1,362 @@
+//! Module: ops::runtime::call_concurrency
+//!
+//! Responsibility: keep heap-only in-flight counts and FIFO wait queues for named call classes.
+//! Does not own: call construction, transport mechanics, or retry decisions.
+//! Boundary: the IC call builder acquires a permit here before issuing a limited call.
+
+use crate::{
+    InternalError,
+    dto::error::Error,
+    ops::runtime::metrics::call_concurrency::{CallConcurrencyMetricEvent, CallConcurrencyMetrics},
+};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, VecDeque},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+thread_local! {
+    static CLASSES: RefCell<BTreeMap<&'static str, ClassState>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+///
+/// ConcurrencyClass
+///
+/// Named call class with an in-flight bound and a saturation behavior.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ConcurrencyClass {
+    pub name: &'static str,
+    pub max_in_flight: u32,
+    pub reject_when_saturated: bool,
+}
+
+///
+/// CallConcurrencySnapshot
+///
+/// Live in-flight and queued counts for one call class.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CallConcurrencySnapshot {
+    pub class: &'static str,
+    pub in_flight: u32,
+    pub queued: u64,
+}
+
+#[derive(Default)]
+struct ClassState {
+    in_flight: u32,
+    next_ticket: u64,
+    waiters: VecDeque<(u64, Waker)>,
+}
+
+impl ClassState {
+    // Wake the queue head when a slot is free; it claims the slot on its next poll.
+    fn wake_head(&self, max_in_flight: u32) {
+        if self.in_flight < max_in_flight
+            && let Some((_, waker)) = self.waiters.front()
+        {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+///
+/// CallConcurrencyOps
+///
+/// Per-class concurrency limiter for outbound calls.
+///
+/// Saturated classes either queue callers in FIFO order or reject them
+/// immediately. Queued callers resume when an in-flight call of the same
+/// class finishes, so queueing only helps callers that share a message with
+/// that call (for example a fan-out joined in one update); other callers
+/// should prefer rejection.
+///
+
+pub struct CallConcurrencyOps;
+
+impl CallConcurrencyOps {
+    /// Wait for, or fail to obtain, one in-flight slot in `class`.
+    pub fn acquire(class: ConcurrencyClass) -> Acquire {
+        Acquire {
+            class: ConcurrencyClass {
+                max_in_flight: class.max_in_flight.max(1),
+                ..class
+            },
+            ticket: None,
+        }
+    }
+
+    /// Snapshot every class that has been used since the last upgrade.
+    #[must_use]
+    pub fn snapshot() -> Vec<CallConcurrencySnapshot> {
+        CLASSES.with_borrow(|classes| {
+            classes
+                .iter()
+                .map(|(class, state)| CallConcurrencySnapshot {
+                    class,
+                    in_flight: state.in_flight,
+                    queued: state.waiters.len() as u64,
+                })
+                .collect()
+        })
+    }
+
+    #[cfg(test)]
+    pub fn reset() {
+        CLASSES.with_borrow_mut(BTreeMap::clear);
+    }
+}
+
+///
+/// Acquire
+///
+/// Future resolving to a permit once the class admits the caller.
+/// Dropping it while queued gives up the caller's place in line.
+///
+
+pub struct Acquire {
+    class: ConcurrencyClass,
+    ticket: Option<u64>,
+}
+
+impl Future for Acquire {
+    type Output = Result<CallPermit, InternalError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let class = self.class;
+        let ticket = self.ticket;
+
+        let (poll, ticket) = CLASSES.with_borrow_mut(|classes| {
+            let state = classes.entry(class.name).or_default();
+            let has_slot = state.in_flight < class.max_in_flight;
+
+            match ticket {
+                None if has_slot && state.waiters.is_empty() => {
+                    state.in_flight += 1;
+                    (Poll::Ready(Ok(())), None)
+                }
+                None if class.reject_when_saturated => (Poll::Ready(Err(())), None),
+                None => {
+                    let ticket = state.next_ticket;
+                    state.next_ticket = state.next_ticket.wrapping_add(1);
+                    state.waiters.push_back((ticket, cx.waker().clone()));
+                    CallConcurrencyMetrics::record(class.name, CallConcurrencyMetricEvent::Queued);
+                    (Poll::Pending, Some(ticket))
+                }
+                Some(ticket) => {
+                    let at_head = state
+                        .waiters
+                        .front()
+                        .is_some_and(|(head, _)| *head == ticket);
+                    if at_head && has_slot {
+                        state.waiters.pop_front();
+                        state.in_flight += 1;
+                        state.wake_head(class.max_in_flight);
+                        return (Poll::Ready(Ok(())), None);
+                    }
+
+                    if let Some((_, waker)) = state
+                        .waiters
+                        .iter_mut()
+                        .find(|(queued, _)| *queued == ticket)
+                    {
+                        waker.clone_from(cx.waker());
+                    }
+                    (Poll::Pending, Some(ticket))
+                }
+            }
+        });
+        self.ticket = ticket;
+
+        match poll {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Ok(())) => {
+                CallConcurrencyMetrics::record(class.name, CallConcurrencyMetricEvent::Admitted);
+                Poll::Ready(Ok(CallPermit { class }))
+            }
+            Poll::Ready(Err(())) => {
+                CallConcurrencyMetrics::record(class.name, CallConcurrencyMetricEvent::Rejected);
+                Poll::Ready(Err(InternalError::public(Error::exhausted(format!(
+                    "call class '{}' is saturated ({} in flight)",
+                    class.name, class.max_in_flight
+                )))))
+            }
+        }
+    }
+}
+
+impl Drop for Acquire {
+    fn drop(&mut self) {
+        let Some(ticket) = self.ticket else {
+            return;
+        };
+
+        CLASSES.with_borrow_mut(|classes| {
+            if let Some(state) = classes.get_mut(self.class.name) {
+                state.waiters.retain(|(queued, _)| *queued != ticket);
+                state.wake_head(self.class.max_in_flight);
+            }
+        });
+    }
+}
+
+///
+/// CallPermit
+///
+/// One in-flight slot; released on drop.
+///
+
+pub struct CallPermit {
+    class: ConcurrencyClass,
+}
+
+impl Drop for CallPermit {
+    fn drop(&mut self) {
+        CLASSES.with_borrow_mut(|classes| {
+            if let Some(state) = classes.get_mut(self.class.name) {
+                state.in_flight = state.in_flight.saturating_sub(1);
+                state.wake_head(self.class.max_in_flight);
+            }
+        });
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::Wake,
+    };
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    const fn class(reject_when_saturated: bool) -> ConcurrencyClass {
+        ConcurrencyClass {
+            name: "ledger",
+            max_in_flight: 2,
+            reject_when_saturated,
+        }
+    }
+
+    fn poll(acquire: &mut Acquire, waker: &Waker) -> Poll<Result<CallPermit, InternalError>> {
+        Pin::new(acquire).poll(&mut Context::from_waker(waker))
+    }
+
+    fn event_count(event: CallConcurrencyMetricEvent) -> u64 {
+        CallConcurrencyMetrics::snapshot()
+            .into_iter()
+            .find(|(key, _)| key.class == "ledger" && key.event == event)
+            .map_or(0, |(_, count)| count)
+    }
+
+    fn live() -> CallConcurrencySnapshot {
+        CallConcurrencyOps::snapshot()
+            .into_iter()
+            .find(|snapshot| snapshot.class == "ledger")
+            .expect("ledger class")
+    }
+
+    #[test]
+    fn saturated_class_rejects_when_configured() {
+        CallConcurrencyOps::reset();
+        CallConcurrencyMetrics::reset();
+        let waker = Waker::noop();
+
+        let Poll::Ready(Ok(_first)) = poll(&mut CallConcurrencyOps::acquire(class(true)), waker)
+        else {
+            panic!("first call admitted");
+        };
+        let Poll::Ready(Ok(_second)) = poll(&mut CallConcurrencyOps::acquire(class(true)), waker)
+        else {
+            panic!("second call admitted");
+        };
+        let Poll::Ready(Err(err)) = poll(&mut CallConcurrencyOps::acquire(class(true)), waker)
+        else {
+            panic!("third call rejected");
+        };
+
+        assert!(err.to_string().contains("saturated"));
+        assert_eq!(event_count(CallConcurrencyMetricEvent::Admitted), 2);
+        assert_eq!(event_count(CallConcurrencyMetricEvent::Rejected), 1);
+        assert_eq!(live().in_flight, 2);
+    }
+
+    #[test]
+    fn saturated_class_queues_in_fifo_order() {
+        CallConcurrencyOps::reset();
+        CallConcurrencyMetrics::reset();
+        let noop = Waker::noop();
+        let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+
+        let Poll::Ready(Ok(first)) = poll(&mut CallConcurrencyOps::acquire(class(false)), noop)
+        else {
+            panic!("first call admitted");
+        };
+        let Poll::Ready(Ok(_second)) = poll(&mut CallConcurrencyOps::acquire(class(false)), noop)
+        else {
+            panic!("second call admitted");
+        };
+
+        let mut third = CallConcurrencyOps::acquire(class(false));
+        let mut fourth = CallConcurrencyOps::acquire(class(false));
+        assert!(poll(&mut third, &waker).is_pending());
+        assert!(poll(&mut fourth, noop).is_pending());
+        assert_eq!(live().queued, 2);
+        assert_eq!(event_count(CallConcurrencyMetricEvent::Queued), 2);
+
+        // The queue head cannot be overtaken by a later caller once a slot frees.
+        drop(first);
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert!(poll(&mut fourth, noop).is_pending());
+        let Poll::Ready(Ok(_third)) = poll(&mut third, &waker) else {
+            panic!("queue head admitted");
+        };
+
+        assert_eq!(live().in_flight, 2);
+        assert_eq!(live().queued, 1);
+    }
+
+    #[test]
+    fn dropped_waiter_leaves_the_queue() {
+        CallConcurrencyOps::reset();
+        let noop = Waker::noop();
+        let single = ConcurrencyClass {
+            max_in_flight: 0,
+            ..class(false)
+        };
+
+        let Poll::Ready(Ok(permit)) = poll(&mut CallConcurrencyOps::acquire(single), noop) else {
+            panic!("zero limit still admits one call");
+        };
+        let mut waiter = CallConcurrencyOps::acquire(single);
+        let mut next = CallConcurrencyOps::acquire(single);
+        assert!(poll(&mut waiter, noop).is_pending());
+        assert!(poll(&mut next, noop).is_pending());
+
+        drop(waiter);
+        drop(permit);
+        assert!(matches!(poll(&mut next, noop), Poll::Ready(Ok(_))));
+        assert_eq!(live().queued, 0);
+    }
+}
@@ -0,0 +1,184 @@
+//! Module: ops::runtime::health
+//!
+//! Responsibility: keep heap-only health gauges and the latest child reports received by root.
+//! Does not own: report scheduling, staleness policy, or stable schemas.
+//! Boundary: the health workflow samples and projects this state; upgrades clear it.
+
+use crate::{
+    dto::health::{HealthGauge, HealthReport},
+    ops::prelude::*,
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+thread_local! {
+    static STARTED_AT_NS: Cell<u64> = const { Cell::new(0) };
+    static GAUGES: RefCell<BTreeMap<String, u64>> = const { RefCell::new(BTreeMap::new()) };
+    static REPORTS: RefCell<BTreeMap<Principal, ReceivedHealthReport>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+///
+/// ReceivedHealthReport
+///
+/// One child report as last received by root.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceivedHealthReport {
+    pub report: HealthReport,
+    pub received_at_ns: u64,
+}
+
+///
+/// HealthStateOps
+///
+/// Heap-only health state: local gauges and start time on every canister,
+/// latest child reports on root.
+///
+
+pub struct HealthStateOps;
+
+impl HealthStateOps {
+    /// Record when the running code started; called once per install or upgrade.
+    pub fn record_started(now_ns: u64) {
+        STARTED_AT_NS.with(|started| started.set(now_ns));
+    }
+
+    #[must_use]
+    pub fn gauge_count() -> usize {
+        GAUGES.with_borrow(BTreeMap::len)
+    }
+
+    #[must_use]
+    pub fn has_gauge(name: &str) -> bool {
+        GAUGES.with_borrow(|gauges| gauges.contains_key(name))
+    }
+
+    pub fn set_gauge(name: &str, value: u64) {
+        GAUGES.with_borrow_mut(|gauges| {
+            gauges.insert(name.to_string(), value);
+        });
+    }
+
+    pub fn remove_gauge(name: &str) -> bool {
+        GAUGES.with_borrow_mut(|gauges| gauges.remove(name).is_some())
+    }
+
+    #[must_use]
+    pub fn started_at_ns() -> u64 {
+        STARTED_AT_NS.with(Cell::get)
+    }
+
+    /// Current gauges in name order.
+    #[must_use]
+    pub fn gauges() -> Vec<HealthGauge> {
+        GAUGES.with_borrow(|gauges| {
+            gauges
+                .iter()
+                .map(|(name, value)| HealthGauge {
+                    name: name.clone(),
+                    value: *value,
+                })
+                .collect()
+        })
+    }
+
+    /// Replace the latest report held for `pid`.
+    pub fn record_report(pid: Principal, report: HealthReport, received_at_ns: u64) {
+        REPORTS.with_borrow_mut(|reports| {
+            reports.insert(
+                pid,
+                ReceivedHealthReport {
+                    report,
+                    received_at_ns,
+                },
+            );
+        });
+    }
+
+    /// Drop reports from canisters no longer in `keep`.
+    pub fn retain_reports(keep: impl Fn(&Principal) -> bool) {
+        REPORTS.with_borrow_mut(|reports| reports.retain(|pid, _| keep(pid)));
+    }
+
+    #[must_use]
+    pub fn report(pid: &Principal) -> Option<ReceivedHealthReport> {
+        REPORTS.with_borrow(|reports| reports.get(pid).cloned())
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        STARTED_AT_NS.with(|started| started.set(0));
+        GAUGES.with_borrow_mut(BTreeMap::clear);
+        REPORTS.with_borrow_mut(BTreeMap::clear);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(reported_at_ns: u64) -> HealthReport {
+        HealthReport {
+            cycles: 1,
+            heap_memory_pages: 2,
+            stable_memory_pages: 3,
+            canister_version: 4,
+            last_upgrade_at_ns: 5,
+            reported_at_ns,
+            gauges: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn gauges_are_listed_in_name_order() {
+        HealthStateOps::reset_for_tests();
+        HealthStateOps::record_started(7);
+        HealthStateOps::set_gauge("queue_depth", 3);
+        HealthStateOps::set_gauge("backlog", 1);
+        HealthStateOps::set_gauge("queue_depth", 4);
+
+        assert_eq!(HealthStateOps::started_at_ns(), 7);
+        assert_eq!(
+            HealthStateOps::gauges(),
+            vec![
+                HealthGauge {
+                    name: "backlog".to_string(),
+                    value: 1,
+                },
+                HealthGauge {
+                    name: "queue_depth".to_string(),
+                    value: 4,
+                },
+            ]
+        );
+
+        assert!(HealthStateOps::remove_gauge("backlog"));
+        assert!(!HealthStateOps::remove_gauge("backlog"));
+        assert_eq!(HealthStateOps::gauge_count(), 1);
+    }
+
+    #[test]
+    fn latest_report_replaces_previous_and_retain_prunes() {
+        HealthStateOps::reset_for_tests();
+        let kept = Principal::from_slice(&[1; 29]);
+        let dropped = Principal::from_slice(&[2; 29]);
+
+        HealthStateOps::record_report(kept, report(1), 10);
+        HealthStateOps::record_report(kept, report(2), 20);
+        HealthStateOps::record_report(dropped, report(3), 30);
+        HealthStateOps::retain_reports(|pid| *pid == kept);
+
+        let latest = HealthStateOps::report(&kept).expect("kept report");
+        assert_eq!(latest.received_at_ns, 20);
+        assert_eq!(latest.report.reported_at_ns, 2);
+        assert_eq!(HealthStateOps::report(&dropped), None);
+    }
+}
@@ -6,21 +6,35 @@
 
 use crate::{
     InternalError,
+    cdk::structures::Storable,
     domain::memory::{
         MemoryAllocationState, MemoryCommitRecoveryErrorResponse, MemoryRangeAuthorityMode,
     },
     dto::memory::{
-        MemoryAllocationRecordEntry, MemoryAllocationSizeEntry, MemoryCommitRecoveryResponse,
-        MemoryCommitSlotResponse, MemoryLedgerGenerationEntry, MemoryLedgerMemoryEntry,
-        MemoryLedgerResponse, MemoryRangeAuthorityEntry, MemorySchemaMetadataEntry,
+        EagerInitFailureEntry, EagerInitReport, MemoryAllocationRecordEntry,
+        MemoryAllocationSizeEntry, MemoryCommitRecoveryResponse, MemoryCommitSlotResponse,
+        MemoryLedgerGenerationEntry, MemoryLedgerMemoryEntry, MemoryLedgerResponse,
+        MemoryRangeAuthorityEntry, MemorySchemaMetadataEntry, StoreCatalogEntry,
+        StoreCatalogResponse, StoreCodec, StoreMemoryRangeEntry, StoreRetention,
+    },
+    memory::{
+        self,
+        catalog::{self, StoreLayout},
+        ledger,
+        migrate::{self, MapMigrationBatch, MapMigrationLimits},
+        registry::MemoryRegistryError,
+        runtime::{eager_init_report, init_eager_tls},
     },
-    memory::{self, ledger, registry::MemoryRegistryError, runtime::init_eager_tls},
     ops::runtime::RuntimeOpsError,
+    state_contract::{
+        MigrationPolicy, StateDomainManifest, StoreIndexManifest, canic_state_descriptors,
+        canic_store_indexes,
+    },
 };
 use ic_memory::{
     AllocationState, CommitRecoveryError, CommitSlotDiagnostic, CommitStoreDiagnostic,
-    DiagnosticGeneration, DiagnosticMemorySize, DiagnosticRecord, MemoryManagerRangeMode,
-    SchemaMetadataRecord,
+    DiagnosticGeneration, DiagnosticMemorySize, DiagnosticRecord, MemoryManagerAuthorityRecord,
+    MemoryManagerRangeMode, SchemaMetadataRecord, StaticMemoryDeclaration,
 };
 use thiserror::Error as ThisError;
 
@@ -38,6 +52,9 @@ pub enum MemoryRegistryOpsError {
     // this error comes from the generic ic-memory runtime boundary
     #[error(transparent)]
     Runtime(#[from] ic_memory::RuntimeBootstrapError<MemoryRegistryError>),
+    // this error comes from the ic-memory static declaration registry
+    #[error(transparent)]
+    Declarations(#[from] ic_memory::StaticMemoryDeclarationError),
 }
 
 impl From<MemoryRegistryOpsError> for InternalError {
@@ -60,6 +77,35 @@ impl MemoryRegistryOps {
         init_eager_tls();
     }
 
+    // Report fallible eager statics that fell back during canister start.
+    #[must_use]
+    pub fn eager_init_report() -> EagerInitReport {
+        EagerInitReport {
+            failures: eager_init_report()
+                .into_iter()
+                .map(|failure| EagerInitFailureEntry {
+                    name: failure.name.to_string(),
+                    error: failure.error,
+                })
+                .collect(),
+        }
+    }
+
+    // Rewrite the next bounded batch of one memory-manager map.
+    pub fn migrate_map_batch<K, VOld, VNew>(
+        memory_id: u8,
+        after: Option<&[u8]>,
+        limits: MapMigrationLimits,
+        f: fn(VOld) -> VNew,
+    ) -> MapMigrationBatch
+    where
+        K: Storable + Ord + Clone,
+        VOld: Storable,
+        VNew: Storable,
+    {
+        migrate::migrate_map_batch::<K, VOld, VNew>(memory_id, after, limits, f)
+    }
+
     // Initialize the stable-memory registry for this crate and summarize the layout.
     pub(crate) fn init_registry() -> Result<(), InternalError> {
         memory::bootstrap_default_memory_manager().map_err(MemoryRegistryOpsError::from)?;
@@ -128,6 +174,130 @@ impl MemoryRegistryOps {
             generations,
         })
     }
+
+    // Assemble the stable store catalog from static declarations and layout headers.
+    pub fn store_catalog() -> Result<StoreCatalogResponse, InternalError> {
+        let declarations =
+            ic_memory::static_memory_declarations().map_err(MemoryRegistryOpsError::from)?;
+        let authorities = catalog::authority_records().map_err(MemoryRegistryOpsError::from)?;
+        let domains: Vec<StateDomainManifest> = canic_state_descriptors()
+            .into_iter()
+            .flat_map(|descriptor| descriptor.state)
+            .collect();
+
+        let mut stores: Vec<StoreCatalogEntry> = declarations
+            .iter()
+            .filter_map(|declaration| store_catalog_entry(declaration, &authorities, &domains))
+            .collect();
+        stores.sort_by_key(|store| store.memory_manager_id);
+        link_store_indexes(&mut stores, &domains, &canic_store_indexes());
+
+        Ok(StoreCatalogResponse { stores })
+    }
+}
+
+fn store_catalog_entry(
+    declaration: &StaticMemoryDeclaration,
+    authorities: &[MemoryManagerAuthorityRecord],
+    domains: &[StateDomainManifest],
+) -> Option<StoreCatalogEntry> {
+    let authority = declaration.authority();
+    let declaration = declaration.declaration();
+    let memory_manager_id = declaration.slot().memory_manager_id().ok()?;
+    let domain = domains
+        .iter()
+        .find(|domain| domain.memory_id == Some(memory_manager_id));
+    let probe = catalog::probe_store(memory_manager_id);
+
+    Some(StoreCatalogEntry {
+        stable_key: declaration.stable_key().as_str().to_string(),
+        label: declaration.label().map(str::to_string),
+        authority: authority.to_string(),
+        memory_manager_id,
+        range: authorities
+            .iter()
+            .find(|record| {
+                record.authority() == authority && record.range().contains(memory_manager_id)
+            })
+            .map(store_memory_range_entry),
+        codec: store_codec(probe.layout),
+        schema_version: declaration
+            .schema()
+            .schema_version()
+            .or_else(|| domain.map(|domain| domain.version)),
+        entry_count: probe.entry_count,
+        size: memory_allocation_size_response(probe.size),
+        retention: store_retention(domain),
+        indexes: Vec::new(),
+    })
+}
+
+// Attach declared secondary index stores to their primary store entries.
+fn link_store_indexes(
+    stores: &mut [StoreCatalogEntry],
+    domains: &[StateDomainManifest],
+    links: &[StoreIndexManifest],
+) {
+    let memory_id = |name: &str| {
+        domains
+            .iter()
+            .find(|domain| domain.domain == name)
+            .and_then(|domain| domain.memory_id)
+    };
+
+    for link in links {
+        let (Some(primary), Some(index)) = (memory_id(&link.domain), memory_id(&link.index_domain))
+        else {
+            continue;
+        };
+        let Some(index_key) = stores
+            .iter()
+            .find(|store| store.memory_manager_id == index)
+            .map(|store| store.stable_key.clone())
+        else {
+            continue;
+        };
+        if let Some(store) = stores
+            .iter_mut()
+            .find(|store| store.memory_manager_id == primary)
+        {
+            store.indexes.push(index_key);
+        }
+    }
+}
+
+const fn store_memory_range_entry(record: &MemoryManagerAuthorityRecord) -> StoreMemoryRangeEntry {
+    let range = record.range();
+    StoreMemoryRangeEntry {
+        start: range.start(),
+        end: range.end(),
+        mode: memory_range_authority_mode(record.mode()),
+    }
+}
+
+const fn store_codec(layout: StoreLayout) -> StoreCodec {
+    match layout {
+        StoreLayout::BTreeMap => StoreCodec::BTreeMap,
+        StoreLayout::Cell => StoreCodec::Cell,
+        StoreLayout::Vec => StoreCodec::Vec,
+        StoreLayout::LogIndex => StoreCodec::LogIndex,
+        StoreLayout::LogData => StoreCodec::LogData,
+        StoreLayout::Unallocated => StoreCodec::Unallocated,
+        StoreLayout::Unknown => StoreCodec::Unknown,
+    }
+}
+
+const fn store_retention(domain: Option<&StateDomainManifest>) -> StoreRetention {
+    match domain {
+        Some(domain) => match domain.migration_policy {
+            MigrationPolicy::DiscardDeclared => StoreRetention::DiscardOnUpgrade,
+            MigrationPolicy::NotApplicable => StoreRetention::Undeclared,
+            MigrationPolicy::NewDomain
+            | MigrationPolicy::Migrate
+            | MigrationPolicy::ManualMigrationRequired => StoreRetention::Persistent,
+        },
+        None => StoreRetention::Undeclared,
+    }
 }
 
 const fn memory_range_authority_mode(mode: MemoryManagerRangeMode) -> MemoryRangeAuthorityMode {
@@ -305,6 +475,9 @@ const fn commit_recovery_error_response(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::role_contract::allocation::memory::intent::{
+        INTENT_EXPIRY_INDEX_ID, INTENT_RECORDS_ID,
+    };
     use ic_memory::{
         AllocationDeclaration, AllocationHistory, AllocationLedger, AllocationSlotDescriptor,
         SchemaMetadata,
@@ -396,4 +569,64 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn store_catalog_links_declared_indexes_to_primary_stores() {
+        let domains: Vec<StateDomainManifest> = canic_state_descriptors()
+            .into_iter()
+            .flat_map(|descriptor| descriptor.state)
+            .collect();
+        let declaration = |stable_key: &str, id: u8| {
+            StaticMemoryDeclaration::new(
+                crate::memory::CANIC_CORE_MEMORY_AUTHORITY,
+                AllocationDeclaration::memory_manager(stable_key, id, "Record")
+                    .expect("declaration"),
+            )
+            .expect("static declaration")
+        };
+        let authorities = catalog::authority_records().expect("authority records");
+        let mut stores: Vec<StoreCatalogEntry> = [
+            declaration("canic.core.intent_records.v1", INTENT_RECORDS_ID),
+            declaration("canic.core.intent_expiry_index.v1", INTENT_EXPIRY_INDEX_ID),
+        ]
+        .iter()
+        .filter_map(|declaration| store_catalog_entry(declaration, &authorities, &domains))
+        .collect();
+
+        link_store_indexes(&mut stores, &domains, &canic_store_indexes());
+
+        assert_eq!(
+            stores[0].indexes,
+            vec!["canic.core.intent_expiry_index.v1".to_string()]
+        );
+        assert!(stores[1].indexes.is_empty());
+        assert_eq!(stores[0].retention, StoreRetention::Persistent);
+        assert_eq!(stores[0].schema_version, Some(1));
+        assert_eq!(stores[0].codec, StoreCodec::Unallocated);
+        assert_eq!(
+            stores[0].range.map(|range| range.mode),
+            Some(MemoryRangeAuthorityMode::Reserved)
+        );
+    }
+
+    #[test]
+    fn store_retention_follows_declared_migration_policy() {
+        let mut domain = domains_for_retention();
+        assert_eq!(store_retention(None), StoreRetention::Undeclared);
+        assert_eq!(store_retention(Some(&domain)), StoreRetention::Persistent);
+
+        domain.migration_policy = MigrationPolicy::DiscardDeclared;
+        assert_eq!(
+            store_retention(Some(&domain)),
+            StoreRetention::DiscardOnUpgrade
+        );
+    }
+
+    fn domains_for_retention() -> StateDomainManifest {
+        canic_state_descriptors()
+            .into_iter()
+            .flat_map(|descriptor| descriptor.state)
+            .next()
+            .expect("at least one declared state domain")
+    }
 }
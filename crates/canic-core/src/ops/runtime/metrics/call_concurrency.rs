@@ -0,0 +1,61 @@
+//! Module: ops::runtime::metrics::call_concurrency
+//!
+//! Responsibility: record and snapshot low-cardinality runtime metrics for the call_concurrency family.
+//! Does not own: workflow decisions, persisted records, or endpoint DTOs.
+//! Boundary: ops-layer metrics consumed by workflow metrics projection.
+
+use std::{cell::RefCell, collections::HashMap};
+
+pub use crate::domain::metrics::CallConcurrencyMetricEvent;
+
+thread_local! {
+    static CALL_CONCURRENCY_METRICS: RefCell<HashMap<CallConcurrencyMetricKey, u64>> =
+        RefCell::new(HashMap::new());
+}
+
+///
+/// CallConcurrencyMetricKey
+///
+/// Cardinality is bounded by the static class names chosen at call sites.
+///
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct CallConcurrencyMetricKey {
+    pub class: &'static str,
+    pub event: CallConcurrencyMetricEvent,
+}
+
+///
+/// CallConcurrencyMetrics
+///
+/// Operations-layer recorder for call-class admission counters.
+///
+
+pub struct CallConcurrencyMetrics;
+
+impl CallConcurrencyMetrics {
+    /// Record one admission event for a call class.
+    pub fn record(class: &'static str, event: CallConcurrencyMetricEvent) {
+        CALL_CONCURRENCY_METRICS.with_borrow_mut(|counts| {
+            let entry = counts
+                .entry(CallConcurrencyMetricKey { class, event })
+                .or_insert(0);
+            *entry = entry.saturating_add(1);
+        });
+    }
+
+    /// Snapshot the current call-class admission counters as stable rows.
+    #[must_use]
+    pub fn snapshot() -> Vec<(CallConcurrencyMetricKey, u64)> {
+        CALL_CONCURRENCY_METRICS
+            .with_borrow(std::clone::Clone::clone)
+            .into_iter()
+            .collect()
+    }
+
+    /// Test-only helper: clear all call-class admission counters.
+    #[cfg(test)]
+    pub fn reset() {
+        CALL_CONCURRENCY_METRICS.with_borrow_mut(HashMap::clear);
+    }
+}
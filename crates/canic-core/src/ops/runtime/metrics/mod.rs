@@ -6,6 +6,7 @@
 
 pub mod access;
 pub mod auth;
+pub mod call_concurrency;
 pub mod canister_ops;
 pub mod cascade;
 pub mod cycles_funding;
@@ -33,14 +34,15 @@ pub mod wasm_store;
 use crate::{
     domain::{metrics::MetricsKind, runtime::TimerMode},
     dto::metrics::{MetricEntry, MetricValue},
-    ops::runtime::env::EnvOps,
+    ops::runtime::{call_concurrency::CallConcurrencyOps, env::EnvOps},
     perf::{self, PerfKey},
 };
 use {
-    access::AccessMetrics, auth::AuthMetrics, canister_ops::CanisterOpsMetrics,
-    cascade::CascadeMetrics, cycles_funding::CyclesFundingMetrics,
-    cycles_topup::CyclesTopupMetrics, delegated_auth::DelegatedAuthMetrics,
-    directory::DirectoryMetrics, icp_refill::IcpRefillMetrics, intent::IntentMetrics,
+    access::AccessMetrics, auth::AuthMetrics, call_concurrency::CallConcurrencyMetrics,
+    canister_ops::CanisterOpsMetrics, cascade::CascadeMetrics,
+    cycles_funding::CyclesFundingMetrics, cycles_topup::CyclesTopupMetrics,
+    delegated_auth::DelegatedAuthMetrics, directory::DirectoryMetrics,
+    icp_refill::IcpRefillMetrics, intent::IntentMetrics,
     inter_canister_call::InterCanisterCallMetrics, lifecycle::LifecycleMetrics,
     platform_call::PlatformCallMetrics, pool::PoolMetrics, replay::ReplayMetrics,
     root_capability::RootCapabilityMetrics, scaling::ScalingMetrics, timer::TimerMetrics,
@@ -96,6 +98,10 @@ pub fn platform_entries() -> Vec<MetricEntry> {
         "inter_canister_call",
         inter_canister_call_entries(),
     ));
+    entries.extend(prefix_entries(
+        "call_concurrency",
+        call_concurrency_entries(),
+    ));
     entries
 }
 
@@ -137,6 +143,7 @@ fn prefix_entries(family: &'static str, entries: Vec<MetricEntry>) -> Vec<Metric
 pub fn reset_for_tests() {
     AccessMetrics::reset();
     AuthMetrics::reset();
+    CallConcurrencyMetrics::reset();
     CanisterOpsMetrics::reset();
     CascadeMetrics::reset();
     CyclesFundingMetrics::reset();
@@ -387,6 +394,34 @@ fn inter_canister_call_entries() -> Vec<MetricEntry> {
         .collect()
 }
 
+/// Project call-class admission counters and live queue gauges into public metrics rows.
+#[must_use]
+fn call_concurrency_entries() -> Vec<MetricEntry> {
+    let mut entries: Vec<_> = CallConcurrencyMetrics::snapshot()
+        .into_iter()
+        .map(|(key, count)| MetricEntry {
+            labels: vec![key.class.to_string(), key.event.metric_label().to_string()],
+            principal: None,
+            value: MetricValue::Count(count),
+        })
+        .collect();
+
+    for snapshot in CallConcurrencyOps::snapshot() {
+        entries.push(MetricEntry {
+            labels: vec![snapshot.class.to_string(), "in_flight".to_string()],
+            principal: None,
+            value: MetricValue::Count(u64::from(snapshot.in_flight)),
+        });
+        entries.push(MetricEntry {
+            labels: vec![snapshot.class.to_string(), "queue_depth".to_string()],
+            principal: None,
+            value: MetricValue::Count(snapshot.queued),
+        });
+    }
+
+    entries
+}
+
 /// Project timer counters into the unified public metrics row shape.
 #[must_use]
 fn timer_entries() -> Vec<MetricEntry> {
@@ -724,6 +724,20 @@ fn platform_call_metrics_are_exposed_with_stable_labels() {
     );
 }
 
+#[test]
+fn call_concurrency_metrics_are_exposed_with_stable_labels() {
+    reset_for_tests();
+
+    CallConcurrencyMetrics::record(
+        "ledger",
+        call_concurrency::CallConcurrencyMetricEvent::Rejected,
+    );
+
+    let entries = entries(MetricsKind::Platform);
+
+    assert_metric_count(&entries, &["call_concurrency", "ledger", "rejected"], 1);
+}
+
 #[test]
 fn intent_metrics_are_exposed_with_stable_labels() {
     reset_for_tests();
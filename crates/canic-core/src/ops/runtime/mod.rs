@@ -5,13 +5,16 @@
 //! Boundary: exposes ops-layer runtime facades and their typed error surface.
 
 pub mod bootstrap;
+pub mod call_concurrency;
 pub mod cycles_funding;
 pub mod env;
 pub mod fleet_activation;
+pub mod health;
 pub mod install_source;
 pub mod log;
 pub mod memory;
 pub mod metrics;
+pub mod query_cache;
 pub mod ready;
 pub mod recent_failure;
 pub mod timer;
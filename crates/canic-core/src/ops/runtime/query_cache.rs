@@ -0,0 +1,220 @@
+//! Module: ops::runtime::query_cache
+//!
+//! Responsibility: hold cached-query registrations and the heap tier of cached responses.
+//! Does not own: freshness policy, stable spillover, or response encoding.
+//! Boundary: the query-cache workflow reads and fills this tier; endpoint macros register here.
+
+use crate::{
+    domain::policy::pure::query_cache as policy, dto::error::Error,
+    ops::storage::query_cache::CachedResponse,
+};
+use sha2::{Digest, Sha256};
+use std::{cell::RefCell, collections::BTreeMap, future::Future, pin::Pin, sync::Mutex};
+
+static CACHED_QUERIES: Mutex<Vec<CachedQuery>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static HEAP: RefCell<HeapTier> = RefCell::new(HeapTier::default());
+}
+
+/// Future returned by a cached query's compute function.
+pub type CachedQueryFuture = Pin<Box<dyn Future<Output = Result<CachedQueryResponse, Error>>>>;
+
+///
+/// CachedQuery
+///
+/// Cache settings and compute entry point registered for one query endpoint.
+///
+/// `compute` decodes Candid arguments, runs the endpoint handler, and returns
+/// the canonical argument key with the encoded response.
+///
+
+#[derive(Clone, Copy, Debug)]
+pub struct CachedQuery {
+    pub endpoint: &'static str,
+    pub ttl_secs: u64,
+    pub stable: bool,
+    pub compute: fn(Vec<u8>) -> CachedQueryFuture,
+}
+
+///
+/// CachedQueryResponse
+///
+/// Canonical argument key and Candid-encoded response produced by a compute run.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachedQueryResponse {
+    pub key: Vec<u8>,
+    pub response: Vec<u8>,
+}
+
+#[derive(Default)]
+struct HeapTier {
+    entries: BTreeMap<[u8; 32], (CachedResponse, u64)>,
+    bytes: usize,
+    next_tick: u64,
+}
+
+///
+/// QueryCacheOps
+///
+/// Process-local cached-query registry and heap response tier.
+///
+/// Entries are evicted least-recently-written first. Replicated queries cannot
+/// persist state, so reads never refresh an entry's position.
+///
+
+pub struct QueryCacheOps;
+
+impl QueryCacheOps {
+    /// Register one cached query endpoint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the process-local cached-query registry mutex is poisoned.
+    pub fn register(query: CachedQuery) {
+        CACHED_QUERIES
+            .lock()
+            .expect("cached query registry poisoned")
+            .push(query);
+    }
+
+    /// Return the registration of one cached query endpoint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the process-local cached-query registry mutex is poisoned.
+    #[must_use]
+    pub fn registration(endpoint: &str) -> Option<CachedQuery> {
+        CACHED_QUERIES
+            .lock()
+            .expect("cached query registry poisoned")
+            .iter()
+            .find(|query| query.endpoint == endpoint)
+            .copied()
+    }
+
+    /// Digest identifying one (endpoint, canonical argument key) pair in both tiers.
+    #[must_use]
+    pub fn digest(endpoint: &str, key: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(endpoint.as_bytes());
+        hasher.update([0]);
+        hasher.update(key);
+
+        hasher.finalize().into()
+    }
+
+    #[must_use]
+    pub fn get(digest: [u8; 32]) -> Option<CachedResponse> {
+        HEAP.with_borrow(|heap| heap.entries.get(&digest).map(|(entry, _)| entry.clone()))
+    }
+
+    /// Store one response and return the entries evicted to stay within capacity.
+    pub fn insert(digest: [u8; 32], entry: CachedResponse) -> Vec<([u8; 32], CachedResponse)> {
+        HEAP.with_borrow_mut(|heap| {
+            let tick = heap.next_tick;
+            heap.next_tick = heap.next_tick.saturating_add(1);
+            heap.bytes = heap.bytes.saturating_add(entry.response.len());
+            if let Some((previous, _)) = heap.entries.insert(digest, (entry, tick)) {
+                heap.bytes = heap.bytes.saturating_sub(previous.response.len());
+            }
+
+            let mut evicted = Vec::new();
+            while policy::heap_over_capacity(heap.entries.len(), heap.bytes) {
+                let Some(oldest) = heap
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, (_, tick))| *tick)
+                    .map(|(digest, _)| *digest)
+                else {
+                    break;
+                };
+                if let Some((entry, _)) = heap.entries.remove(&oldest) {
+                    heap.bytes = heap.bytes.saturating_sub(entry.response.len());
+                    evicted.push((oldest, entry));
+                }
+            }
+
+            evicted
+        })
+    }
+
+    /// Drop every heap response of one endpoint and return how many were removed.
+    pub fn remove_endpoint(endpoint: &str) -> usize {
+        HEAP.with_borrow_mut(|heap| {
+            let before = heap.entries.len();
+            heap.entries
+                .retain(|_, (entry, _)| entry.endpoint != endpoint);
+            heap.bytes = heap
+                .entries
+                .values()
+                .map(|(entry, _)| entry.response.len())
+                .sum();
+
+            before - heap.entries.len()
+        })
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        HEAP.with_borrow_mut(|heap| *heap = HeapTier::default());
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(endpoint: &str, len: usize) -> CachedResponse {
+        CachedResponse {
+            endpoint: endpoint.to_string(),
+            response: vec![1; len],
+            expires_at_ns: 10,
+        }
+    }
+
+    #[test]
+    fn digest_separates_endpoint_from_key() {
+        assert_ne!(
+            QueryCacheOps::digest("ab", b"c"),
+            QueryCacheOps::digest("a", b"bc")
+        );
+        assert_eq!(
+            QueryCacheOps::digest("dashboard", b"k"),
+            QueryCacheOps::digest("dashboard", b"k")
+        );
+    }
+
+    #[test]
+    fn heap_evicts_least_recently_written_entries() {
+        QueryCacheOps::reset_for_tests();
+        let third = policy::QUERY_CACHE_HEAP_MAX_BYTES / 3;
+        QueryCacheOps::insert([1; 32], entry("a", third));
+        QueryCacheOps::insert([2; 32], entry("b", third));
+        QueryCacheOps::insert([1; 32], entry("a", third));
+        assert!(QueryCacheOps::insert([3; 32], entry("c", third)).is_empty());
+
+        let evicted = QueryCacheOps::insert([4; 32], entry("d", third));
+        assert_eq!(evicted, vec![([2; 32], entry("b", third))]);
+        assert!(QueryCacheOps::get([1; 32]).is_some());
+        QueryCacheOps::reset_for_tests();
+    }
+
+    #[test]
+    fn endpoints_are_invalidated_independently() {
+        QueryCacheOps::reset_for_tests();
+        QueryCacheOps::insert([1; 32], entry("dashboard", 4));
+        QueryCacheOps::insert([2; 32], entry("stats", 4));
+
+        assert_eq!(QueryCacheOps::remove_endpoint("dashboard"), 1);
+        assert_eq!(QueryCacheOps::get([1; 32]), None);
+        assert_eq!(QueryCacheOps::get([2; 32]), Some(entry("stats", 4)));
+        QueryCacheOps::reset_for_tests();
+    }
+}
@@ -0,0 +1,142 @@
+//! Module: ops::storage::acl
+//!
+//! Responsibility: read and mutate the stable principal-to-roles access control list.
+//! Does not own: role-name validation, grant limits, or endpoint authorization.
+//! Boundary: the ACL workflow mutates grants here and auth predicates read them.
+
+use crate::{cdk::types::Principal, dto::acl::AclEntry, storage::stable::acl::AclStore};
+
+///
+/// AclStoreOps
+///
+/// Stable access control list keyed by principal.
+///
+
+pub struct AclStoreOps;
+
+impl AclStoreOps {
+    /// Roles currently granted to one principal.
+    #[must_use]
+    pub fn roles(principal: Principal) -> Vec<String> {
+        AclStore::get(principal)
+            .map(|record| record.roles)
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn has_role(principal: Principal, role: &str) -> bool {
+        AclStore::get(principal).is_some_and(|record| {
+            record
+                .roles
+                .binary_search_by(|granted| granted.as_str().cmp(role))
+                .is_ok()
+        })
+    }
+
+    /// Grant one role and return whether it was newly added.
+    pub fn grant(principal: Principal, role: &str) -> bool {
+        let mut record = AclStore::get(principal).unwrap_or_default();
+        let Err(index) = record
+            .roles
+            .binary_search_by(|granted| granted.as_str().cmp(role))
+        else {
+            return false;
+        };
+
+        record.roles.insert(index, role.to_string());
+        AclStore::insert(principal, record);
+        true
+    }
+
+    /// Revoke one role and return whether it was held.
+    ///
+    /// Principals left without roles are removed from the list.
+    pub fn revoke(principal: Principal, role: &str) -> bool {
+        let Some(mut record) = AclStore::get(principal) else {
+            return false;
+        };
+        let Ok(index) = record
+            .roles
+            .binary_search_by(|granted| granted.as_str().cmp(role))
+        else {
+            return false;
+        };
+
+        record.roles.remove(index);
+        if record.roles.is_empty() {
+            AclStore::remove(principal);
+        } else {
+            AclStore::insert(principal, record);
+        }
+        true
+    }
+
+    /// Every principal with at least one role, in principal order.
+    #[must_use]
+    pub fn entries() -> Vec<AclEntry> {
+        AclStore::export()
+            .entries
+            .into_iter()
+            .map(|(principal, record)| AclEntry {
+                principal,
+                roles: record.roles,
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        AclStore::clear_for_tests();
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::seams;
+
+    fn p(id: u8) -> Principal {
+        Principal::from_slice(&[id; 29])
+    }
+
+    #[test]
+    fn grants_are_sorted_and_deduplicated() {
+        let _guard = seams::lock();
+        AclStoreOps::reset_for_tests();
+
+        assert!(AclStoreOps::grant(p(1), "support"));
+        assert!(AclStoreOps::grant(p(1), "admin"));
+        assert!(!AclStoreOps::grant(p(1), "admin"));
+
+        assert_eq!(AclStoreOps::roles(p(1)), vec!["admin", "support"]);
+        assert!(AclStoreOps::has_role(p(1), "admin"));
+        assert!(!AclStoreOps::has_role(p(2), "admin"));
+        AclStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn revoking_the_last_role_drops_the_principal() {
+        let _guard = seams::lock();
+        AclStoreOps::reset_for_tests();
+        AclStoreOps::grant(p(1), "admin");
+        AclStoreOps::grant(p(2), "admin");
+        AclStoreOps::grant(p(2), "support");
+
+        assert!(AclStoreOps::revoke(p(1), "admin"));
+        assert!(!AclStoreOps::revoke(p(1), "admin"));
+        assert!(AclStoreOps::revoke(p(2), "support"));
+
+        assert_eq!(
+            AclStoreOps::entries(),
+            vec![AclEntry {
+                principal: p(2),
+                roles: vec!["admin".to_string()],
+            }]
+        );
+        AclStoreOps::reset_for_tests();
+    }
+}
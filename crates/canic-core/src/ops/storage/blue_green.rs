@@ -0,0 +1,242 @@
+//! Module: ops::storage::blue_green
+//!
+//! Responsibility: track in-flight blue/green deployments and the routing entry each one selects.
+//! Does not own: provisioning, smoke-check calls, or index import.
+//! Boundary: the blue/green workflow mutates records here; registry ops apply the routing overlay.
+
+use crate::{
+    cdk::types::Principal,
+    domain::policy::pure::blue_green as policy,
+    dto::blue_green::{BlueGreenPhase, BlueGreenStatus},
+    ids::CanisterRole,
+    storage::stable::{
+        blue_green::{BlueGreenPhaseRecord, BlueGreenRecord, BlueGreenStore},
+        registry::subnet::SubnetRegistryData,
+    },
+};
+
+///
+/// BlueGreenStoreOps
+///
+/// Stable blue/green deployments keyed by role, at most one per role.
+///
+
+pub struct BlueGreenStoreOps;
+
+impl BlueGreenStoreOps {
+    #[must_use]
+    pub fn status(role: &CanisterRole) -> Option<BlueGreenStatus> {
+        BlueGreenStore::get(role).map(|record| record_to_status(role.clone(), record))
+    }
+
+    /// Every in-flight deployment, in role order.
+    #[must_use]
+    pub fn statuses() -> Vec<BlueGreenStatus> {
+        BlueGreenStore::export()
+            .entries
+            .into_iter()
+            .map(|(role, record)| record_to_status(role, record))
+            .collect()
+    }
+
+    /// Record a deployment that replaces `blue_pid`; routing stays on blue.
+    pub fn begin(
+        role: CanisterRole,
+        blue_pid: Principal,
+        smoke_check_method: String,
+        grace_period_secs: u64,
+        workflow_id: u64,
+        now_ns: u64,
+    ) {
+        BlueGreenStore::insert(
+            role,
+            BlueGreenRecord {
+                blue_pid,
+                green_pid: None,
+                routed_pid: blue_pid,
+                phase: BlueGreenPhaseRecord::Provisioning,
+                smoke_check_method,
+                grace_period_secs,
+                started_at_ns: now_ns,
+                flipped_at_ns: None,
+                workflow_id,
+            },
+        );
+    }
+
+    /// Record the provisioned green instance awaiting its smoke check.
+    pub fn provisioned(role: &CanisterRole, green_pid: Principal) {
+        let Some(mut record) = BlueGreenStore::get(role) else {
+            return;
+        };
+
+        record.green_pid = Some(green_pid);
+        record.phase = BlueGreenPhaseRecord::SmokeChecking;
+        BlueGreenStore::insert(role.clone(), record);
+    }
+
+    /// Point the role's routing entry at the green instance and return when
+    /// the blue one retires. Flipping again keeps the original flip time.
+    pub fn flip(role: &CanisterRole, now_ns: u64) -> Option<u64> {
+        let mut record = BlueGreenStore::get(role)?;
+        let green_pid = record.green_pid?;
+        let flipped_at_ns = *record.flipped_at_ns.get_or_insert(now_ns);
+
+        record.routed_pid = green_pid;
+        record.phase = BlueGreenPhaseRecord::Draining;
+        let grace_period_secs = record.grace_period_secs;
+        BlueGreenStore::insert(role.clone(), record);
+
+        Some(policy::retire_at_ns(flipped_at_ns, grace_period_secs))
+    }
+
+    pub fn clear(role: &CanisterRole) {
+        BlueGreenStore::remove(role);
+    }
+
+    /// Return whether `pid` is the blue instance of a deployment that is
+    /// still provisioning its replacement, so registering a second instance
+    /// of the role is expected.
+    #[must_use]
+    pub fn is_being_replaced(role: &CanisterRole, pid: Principal) -> bool {
+        BlueGreenStore::get(role).is_some_and(|record| {
+            record.blue_pid == pid && record.phase == BlueGreenPhaseRecord::Provisioning
+        })
+    }
+
+    /// Hide every instance of a role under deployment except the routed one.
+    #[must_use]
+    pub fn route(mut registry: SubnetRegistryData) -> SubnetRegistryData {
+        let deployments = BlueGreenStore::export().entries;
+        if deployments.is_empty() {
+            return registry;
+        }
+
+        registry.entries.retain(|entry| {
+            deployments
+                .iter()
+                .find(|(role, _)| *role == entry.record.role)
+                .is_none_or(|(_, record)| record.routed_pid == entry.pid)
+        });
+        registry
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        BlueGreenStore::clear_for_tests();
+    }
+}
+
+fn record_to_status(role: CanisterRole, record: BlueGreenRecord) -> BlueGreenStatus {
+    BlueGreenStatus {
+        role,
+        blue_pid: record.blue_pid,
+        green_pid: record.green_pid,
+        routed_pid: record.routed_pid,
+        phase: match record.phase {
+            BlueGreenPhaseRecord::Provisioning => BlueGreenPhase::Provisioning,
+            BlueGreenPhaseRecord::SmokeChecking => BlueGreenPhase::SmokeChecking,
+            BlueGreenPhaseRecord::Draining => BlueGreenPhase::Draining,
+        },
+        smoke_check_method: record.smoke_check_method,
+        grace_period_secs: record.grace_period_secs,
+        started_at_ns: record.started_at_ns,
+        flipped_at_ns: record.flipped_at_ns,
+        retire_at_ns: record
+            .flipped_at_ns
+            .map(|flipped_at_ns| policy::retire_at_ns(flipped_at_ns, record.grace_period_secs)),
+        workflow_id: record.workflow_id,
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        storage::canister::{CanisterEntryRecord, CanisterRecord},
+        test::seams::{self, p},
+    };
+
+    fn entry(pid: Principal, role: &CanisterRole) -> CanisterEntryRecord {
+        CanisterEntryRecord {
+            pid,
+            record: CanisterRecord {
+                role: role.clone(),
+                parent_pid: Some(p(0)),
+                module_hash: None,
+                created_at: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn routing_follows_the_flip_and_keeps_other_roles() {
+        let _guard = seams::lock();
+        BlueGreenStoreOps::reset_for_tests();
+        let app = CanisterRole::new("app");
+        let other = CanisterRole::new("other");
+        let registry = SubnetRegistryData {
+            entries: vec![entry(p(1), &app), entry(p(2), &app), entry(p(3), &other)],
+        };
+        let pids = |registry: SubnetRegistryData| {
+            registry
+                .entries
+                .into_iter()
+                .map(|entry| entry.pid)
+                .collect::<Vec<_>>()
+        };
+
+        BlueGreenStoreOps::begin(app.clone(), p(1), "ready".to_string(), 60, 7, 10);
+        assert!(BlueGreenStoreOps::is_being_replaced(&app, p(1)));
+        BlueGreenStoreOps::provisioned(&app, p(2));
+        assert!(!BlueGreenStoreOps::is_being_replaced(&app, p(1)));
+        assert_eq!(
+            pids(BlueGreenStoreOps::route(registry.clone())),
+            vec![p(1), p(3)]
+        );
+
+        assert_eq!(
+            BlueGreenStoreOps::flip(&app, 20),
+            Some(policy::retire_at_ns(20, 60))
+        );
+        assert_eq!(
+            BlueGreenStoreOps::flip(&app, 30),
+            Some(policy::retire_at_ns(20, 60))
+        );
+        assert_eq!(
+            pids(BlueGreenStoreOps::route(registry.clone())),
+            vec![p(2), p(3)]
+        );
+
+        let status = BlueGreenStoreOps::status(&app).expect("status");
+        assert_eq!(status.phase, BlueGreenPhase::Draining);
+        assert_eq!(status.routed_pid, p(2));
+
+        BlueGreenStoreOps::clear(&app);
+        assert_eq!(
+            pids(BlueGreenStoreOps::route(registry)),
+            vec![p(1), p(2), p(3)]
+        );
+        BlueGreenStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn flip_needs_a_provisioned_green_instance() {
+        let _guard = seams::lock();
+        BlueGreenStoreOps::reset_for_tests();
+        let app = CanisterRole::new("app");
+
+        assert_eq!(BlueGreenStoreOps::flip(&app, 10), None);
+        BlueGreenStoreOps::begin(app.clone(), p(1), "ready".to_string(), 60, 7, 10);
+        assert_eq!(BlueGreenStoreOps::flip(&app, 10), None);
+        assert_eq!(
+            BlueGreenStoreOps::status(&app).expect("status").routed_pid,
+            p(1)
+        );
+        BlueGreenStoreOps::reset_for_tests();
+    }
+}
@@ -0,0 +1,349 @@
+//! Module: ops::storage::event_bus
+//!
+//! Responsibility: mutate and project root event-bus subscriptions and delivery queues.
+//! Does not own: topic validation, retry policy, delivery calls, or endpoint authorization.
+//! Boundary: storage ops convert stable records into workflow views and DTOs.
+
+use crate::{
+    dto::event_bus::{EventDeadLetter, EventEnvelope},
+    ops::prelude::*,
+    storage::stable::event_bus::{
+        EventBusStore, EventDeliveryKeyRecord, EventDeliveryRecord, EventSubscriptionKeyRecord,
+        EventSubscriptionRecord,
+    },
+};
+
+const DELIVERY_ERROR_MAX_CHARS: usize = 512;
+
+///
+/// EventDelivery
+///
+/// Workflow view of one pending delivery, keyed by its current due time.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventDelivery {
+    key: EventDeliveryKeyRecord,
+    record: EventDeliveryRecord,
+}
+
+impl EventDelivery {
+    #[must_use]
+    pub const fn delivery_id(&self) -> u64 {
+        self.record.delivery_id
+    }
+
+    #[must_use]
+    pub const fn subscriber(&self) -> Principal {
+        self.record.subscriber
+    }
+
+    #[must_use]
+    pub fn method(&self) -> &str {
+        &self.record.method
+    }
+
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.record.attempts
+    }
+
+    #[must_use]
+    pub fn envelope(&self) -> EventEnvelope {
+        EventEnvelope {
+            event_id: self.record.event_id,
+            topic: self.record.topic.clone(),
+            publisher: self.record.publisher,
+            published_at_ns: self.record.published_at_ns,
+            payload: self.record.payload.clone(),
+        }
+    }
+}
+
+///
+/// PublishedEvent
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PublishedEvent {
+    pub event_id: u64,
+    pub deliveries: u32,
+}
+
+///
+/// EventBusStoreOps
+///
+/// Root-only event-bus storage operations.
+///
+
+pub struct EventBusStoreOps;
+
+impl EventBusStoreOps {
+    /// Register or replace `subscriber`'s callback for `topic`.
+    ///
+    /// Returns `true` when the subscription is new.
+    pub fn subscribe(topic: &str, subscriber: Principal, method: &str, now_ns: u64) -> bool {
+        EventBusStore::insert_subscription(
+            EventSubscriptionKeyRecord {
+                topic: topic.to_string(),
+                subscriber,
+            },
+            EventSubscriptionRecord {
+                method: method.to_string(),
+                subscribed_at_ns: now_ns,
+            },
+        )
+        .is_none()
+    }
+
+    /// Remove `subscriber` from `topic`; already-queued deliveries are kept.
+    pub fn unsubscribe(topic: &str, subscriber: Principal) -> bool {
+        EventBusStore::remove_subscription(&EventSubscriptionKeyRecord {
+            topic: topic.to_string(),
+            subscriber,
+        })
+        .is_some()
+    }
+
+    /// Assign an event id and enqueue one due-now delivery per current subscriber.
+    pub fn publish(
+        topic: &str,
+        publisher: Principal,
+        payload: &[u8],
+        now_ns: u64,
+    ) -> PublishedEvent {
+        let mut meta = EventBusStore::meta();
+        let event_id = meta.next_event_id;
+        meta.next_event_id = meta.next_event_id.saturating_add(1);
+
+        let mut deliveries = 0_u32;
+        for (subscriber, subscription) in EventBusStore::topic_subscriptions(topic) {
+            let delivery_id = meta.next_delivery_id;
+            meta.next_delivery_id = meta.next_delivery_id.saturating_add(1);
+
+            EventBusStore::insert_delivery(
+                EventDeliveryKeyRecord {
+                    due_at_ns: now_ns,
+                    delivery_id,
+                },
+                EventDeliveryRecord {
+                    delivery_id,
+                    event_id,
+                    topic: topic.to_string(),
+                    publisher,
+                    published_at_ns: now_ns,
+                    subscriber,
+                    method: subscription.method,
+                    payload: payload.to_vec(),
+                    attempts: 0,
+                    last_error: None,
+                },
+            );
+            deliveries = deliveries.saturating_add(1);
+        }
+        EventBusStore::set_meta(meta);
+
+        PublishedEvent {
+            event_id,
+            deliveries,
+        }
+    }
+
+    #[must_use]
+    pub fn due_deliveries(now_ns: u64, limit: usize) -> Vec<EventDelivery> {
+        EventBusStore::due_deliveries(now_ns, limit)
+            .into_iter()
+            .map(|(key, record)| EventDelivery { key, record })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn next_due_at_ns() -> Option<u64> {
+        EventBusStore::next_due_at_ns()
+    }
+
+    /// Drop a delivery the subscriber acknowledged.
+    pub fn complete(delivery: &EventDelivery) {
+        EventBusStore::remove_delivery(&delivery.key);
+    }
+
+    /// Record a failed attempt and move the delivery to `retry_at_ns`.
+    pub fn reschedule(delivery: EventDelivery, error: String, retry_at_ns: u64) {
+        let EventDelivery { key, mut record } = delivery;
+        EventBusStore::remove_delivery(&key);
+
+        record.attempts = record.attempts.saturating_add(1);
+        record.last_error = Some(truncate_delivery_error(error));
+        EventBusStore::insert_delivery(
+            EventDeliveryKeyRecord {
+                due_at_ns: retry_at_ns,
+                delivery_id: record.delivery_id,
+            },
+            record,
+        );
+    }
+
+    /// Record the final failed attempt and move the delivery to the dead-letter queue.
+    pub fn dead_letter(delivery: EventDelivery, error: String) {
+        let EventDelivery { key, mut record } = delivery;
+        EventBusStore::remove_delivery(&key);
+
+        record.attempts = record.attempts.saturating_add(1);
+        record.last_error = Some(truncate_delivery_error(error));
+        EventBusStore::insert_dead_letter(record);
+    }
+
+    #[must_use]
+    pub fn dead_letters() -> Vec<EventDeadLetter> {
+        let count = usize::try_from(EventBusStore::dead_letter_count()).unwrap_or(usize::MAX);
+        EventBusStore::dead_letters(0, count)
+            .into_iter()
+            .map(dead_letter_to_dto)
+            .collect()
+    }
+
+    /// Requeue a dead letter as due now with a fresh attempt budget.
+    pub fn redrive(delivery_id: u64, now_ns: u64) -> bool {
+        let Some(mut record) = EventBusStore::remove_dead_letter(delivery_id) else {
+            return false;
+        };
+
+        record.attempts = 0;
+        EventBusStore::insert_delivery(
+            EventDeliveryKeyRecord {
+                due_at_ns: now_ns,
+                delivery_id,
+            },
+            record,
+        );
+        true
+    }
+
+    pub fn discard_dead_letter(delivery_id: u64) -> bool {
+        EventBusStore::remove_dead_letter(delivery_id).is_some()
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        EventBusStore::clear_for_tests();
+    }
+}
+
+fn dead_letter_to_dto(record: EventDeliveryRecord) -> EventDeadLetter {
+    EventDeadLetter {
+        delivery_id: record.delivery_id,
+        event_id: record.event_id,
+        topic: record.topic,
+        publisher: record.publisher,
+        published_at_ns: record.published_at_ns,
+        subscriber: record.subscriber,
+        method: record.method,
+        attempts: record.attempts,
+        last_error: record.last_error,
+    }
+}
+
+fn truncate_delivery_error(error: String) -> String {
+    error.chars().take(DELIVERY_ERROR_MAX_CHARS).collect()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::policy::pure::event_bus as policy, test::seams};
+
+    #[test]
+    fn publish_fans_out_one_delivery_per_topic_subscriber() {
+        let _guard = seams::lock();
+        EventBusStoreOps::reset_for_tests();
+        assert!(EventBusStoreOps::subscribe(
+            "orders",
+            seams::p(1),
+            "on_order",
+            0
+        ));
+        assert!(EventBusStoreOps::subscribe(
+            "orders",
+            seams::p(2),
+            "on_order",
+            0
+        ));
+        assert!(EventBusStoreOps::subscribe(
+            "orders.v2",
+            seams::p(3),
+            "on_order",
+            0
+        ));
+        assert!(!EventBusStoreOps::subscribe(
+            "orders",
+            seams::p(2),
+            "on_order_v2",
+            0
+        ));
+
+        let first = EventBusStoreOps::publish("orders", seams::p(9), b"a", 10);
+        let second = EventBusStoreOps::publish("orders", seams::p(9), b"b", 11);
+        assert_eq!((first.event_id, first.deliveries), (1, 2));
+        assert_eq!((second.event_id, second.deliveries), (2, 2));
+
+        let due = EventBusStoreOps::due_deliveries(10, usize::MAX);
+        assert_eq!(due.len(), 2);
+        assert_eq!(due[1].method(), "on_order_v2");
+        assert_eq!(due[0].envelope().payload, b"a".to_vec());
+        assert_eq!(
+            EventBusStoreOps::due_deliveries(u64::MAX, usize::MAX).len(),
+            4
+        );
+        EventBusStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn failed_deliveries_reschedule_then_dead_letter_and_redrive() {
+        let _guard = seams::lock();
+        EventBusStoreOps::reset_for_tests();
+        EventBusStoreOps::subscribe("orders", seams::p(1), "on_order", 0);
+        EventBusStoreOps::publish("orders", seams::p(9), b"a", 10);
+
+        let delivery = EventBusStoreOps::due_deliveries(10, 1).remove(0);
+        let delivery_id = delivery.delivery_id();
+        EventBusStoreOps::reschedule(delivery, "rejected".to_string(), 50);
+        assert!(EventBusStoreOps::due_deliveries(49, 1).is_empty());
+        assert_eq!(EventBusStoreOps::next_due_at_ns(), Some(50));
+
+        let delivery = EventBusStoreOps::due_deliveries(50, 1).remove(0);
+        assert_eq!(delivery.attempts(), 1);
+        EventBusStoreOps::dead_letter(delivery, "rejected again".to_string());
+        assert_eq!(EventBusStoreOps::next_due_at_ns(), None);
+
+        let dead = EventBusStoreOps::dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, 2);
+        assert_eq!(dead[0].last_error.as_deref(), Some("rejected again"));
+
+        assert!(EventBusStoreOps::redrive(delivery_id, 60));
+        assert!(EventBusStoreOps::dead_letters().is_empty());
+        assert_eq!(EventBusStoreOps::due_deliveries(60, 1)[0].attempts(), 0);
+        EventBusStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn largest_allowed_payload_fits_the_stable_record() {
+        let _guard = seams::lock();
+        EventBusStoreOps::reset_for_tests();
+        let topic = "t".repeat(policy::EVENT_TOPIC_MAX_BYTES);
+        let method = "m".repeat(policy::EVENT_CALLBACK_METHOD_MAX_BYTES);
+        let payload = vec![0xff; policy::EVENT_PAYLOAD_MAX_BYTES];
+        EventBusStoreOps::subscribe(&topic, seams::p(1), &method, 0);
+        EventBusStoreOps::publish(&topic, seams::p(9), &payload, u64::MAX);
+
+        let delivery = EventBusStoreOps::due_deliveries(u64::MAX, 1).remove(0);
+        assert_eq!(delivery.envelope().payload, payload);
+        EventBusStoreOps::dead_letter(delivery, "x".repeat(DELIVERY_ERROR_MAX_CHARS * 4));
+        assert_eq!(EventBusStoreOps::dead_letters().len(), 1);
+        EventBusStoreOps::reset_for_tests();
+    }
+}
@@ -0,0 +1,265 @@
+//! Module: ops::storage::fsm
+//!
+//! Responsibility: mutate and project persisted workflow state-machine instances.
+//! Does not own: transition rules, retry policy, or step execution.
+//! Boundary: storage ops convert stable records into workflow views and DTOs.
+
+use crate::{
+    dto::fsm::{WorkflowRunStatus, WorkflowStatusResponse},
+    storage::stable::fsm::{FsmInstanceRecord, FsmStatusRecord, FsmStore},
+};
+
+const FSM_ERROR_MAX_CHARS: usize = 512;
+
+///
+/// FsmInstance
+///
+/// Workflow view of one running state-machine instance.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FsmInstance {
+    record: FsmInstanceRecord,
+}
+
+impl FsmInstance {
+    #[must_use]
+    pub const fn instance_id(&self) -> u64 {
+        self.record.instance_id
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> &str {
+        &self.record.kind
+    }
+
+    #[must_use]
+    pub fn state(&self) -> &[u8] {
+        &self.record.state
+    }
+
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.record.attempts
+    }
+}
+
+///
+/// FsmStoreOps
+///
+/// Per-canister state-machine instance storage operations.
+///
+
+pub struct FsmStoreOps;
+
+impl FsmStoreOps {
+    /// Persist a new running instance due now and return its id.
+    pub fn create(kind: &str, state_label: &str, state: Vec<u8>, now_ns: u64) -> u64 {
+        let mut meta = FsmStore::meta();
+        let instance_id = meta.next_instance_id;
+        meta.next_instance_id = meta.next_instance_id.saturating_add(1);
+        FsmStore::set_meta(meta);
+
+        FsmStore::insert(FsmInstanceRecord {
+            instance_id,
+            kind: kind.to_string(),
+            state_label: state_label.to_string(),
+            state,
+            status: FsmStatusRecord::Running,
+            attempts: 0,
+            next_run_at_ns: now_ns,
+            created_at_ns: now_ns,
+            updated_at_ns: now_ns,
+            last_error: None,
+        });
+        instance_id
+    }
+
+    /// Return up to `limit` running instances due at or before `now_ns`, oldest deadline first.
+    #[must_use]
+    pub fn due(now_ns: u64, limit: usize) -> Vec<FsmInstance> {
+        let mut due: Vec<_> = FsmStore::instances()
+            .into_iter()
+            .filter(|record| {
+                record.status == FsmStatusRecord::Running && record.next_run_at_ns <= now_ns
+            })
+            .collect();
+        due.sort_by_key(|record| (record.next_run_at_ns, record.instance_id));
+        due.truncate(limit);
+
+        due.into_iter()
+            .map(|record| FsmInstance { record })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn next_due_at_ns() -> Option<u64> {
+        FsmStore::instances()
+            .into_iter()
+            .filter(|record| record.status == FsmStatusRecord::Running)
+            .map(|record| record.next_run_at_ns)
+            .min()
+    }
+
+    /// Move an instance to its next state, due immediately with a fresh attempt budget.
+    pub fn advance(instance: FsmInstance, state_label: &str, state: Vec<u8>, now_ns: u64) {
+        let mut record = instance.record;
+        record.state_label = state_label.to_string();
+        record.state = state;
+        record.attempts = 0;
+        record.next_run_at_ns = now_ns;
+        record.updated_at_ns = now_ns;
+        record.last_error = None;
+        FsmStore::insert(record);
+    }
+
+    /// Keep the current state and run the same step again at `next_run_at_ns`.
+    pub fn retry(instance: FsmInstance, error: String, next_run_at_ns: u64, now_ns: u64) {
+        let mut record = instance.record;
+        record.attempts = record.attempts.saturating_add(1);
+        record.next_run_at_ns = next_run_at_ns;
+        record.updated_at_ns = now_ns;
+        record.last_error = Some(truncate_error(error));
+        FsmStore::insert(record);
+    }
+
+    /// Postpone an instance without spending an attempt.
+    pub fn defer(instance: FsmInstance, next_run_at_ns: u64) {
+        let mut record = instance.record;
+        record.next_run_at_ns = next_run_at_ns;
+        FsmStore::insert(record);
+    }
+
+    pub fn complete(instance: FsmInstance, now_ns: u64) {
+        let mut record = instance.record;
+        record.status = FsmStatusRecord::Completed;
+        record.updated_at_ns = now_ns;
+        FsmStore::insert(record);
+    }
+
+    pub fn fail(instance: FsmInstance, error: String, now_ns: u64) {
+        let mut record = instance.record;
+        record.status = FsmStatusRecord::Failed;
+        record.updated_at_ns = now_ns;
+        record.last_error = Some(truncate_error(error));
+        FsmStore::insert(record);
+    }
+
+    /// Drop completed and failed instances for which `expired(updated_at_ns)` holds.
+    pub fn prune_terminal(expired: impl Fn(u64) -> bool) -> usize {
+        let stale: Vec<_> = FsmStore::instances()
+            .into_iter()
+            .filter(|record| {
+                record.status != FsmStatusRecord::Running && expired(record.updated_at_ns)
+            })
+            .map(|record| record.instance_id)
+            .collect();
+        for instance_id in &stale {
+            FsmStore::remove(*instance_id);
+        }
+
+        stale.len()
+    }
+
+    #[must_use]
+    pub fn status(instance_id: u64) -> Option<WorkflowStatusResponse> {
+        FsmStore::get(instance_id).map(status_to_dto)
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        FsmStore::clear_for_tests();
+    }
+}
+
+fn status_to_dto(record: FsmInstanceRecord) -> WorkflowStatusResponse {
+    let status = match record.status {
+        FsmStatusRecord::Completed => WorkflowRunStatus::Completed,
+        FsmStatusRecord::Failed => WorkflowRunStatus::Failed,
+        FsmStatusRecord::Running => WorkflowRunStatus::Running,
+    };
+
+    WorkflowStatusResponse {
+        instance_id: record.instance_id,
+        kind: record.kind,
+        state: record.state_label,
+        status,
+        attempts: record.attempts,
+        created_at_ns: record.created_at_ns,
+        updated_at_ns: record.updated_at_ns,
+        next_run_at_ns: (status == WorkflowRunStatus::Running).then_some(record.next_run_at_ns),
+        last_error: record.last_error,
+    }
+}
+
+fn truncate_error(error: String) -> String {
+    error.chars().take(FSM_ERROR_MAX_CHARS).collect()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::policy::pure::fsm as policy, test::seams};
+
+    #[test]
+    fn instances_advance_retry_and_finish() {
+        let _guard = seams::lock();
+        FsmStoreOps::reset_for_tests();
+        let first = FsmStoreOps::create("provision", "allocate", vec![1], 10);
+        let second = FsmStoreOps::create("provision", "allocate", vec![2], 20);
+        assert_eq!((first, second), (1, 2));
+        assert_eq!(FsmStoreOps::next_due_at_ns(), Some(10));
+
+        let instance = FsmStoreOps::due(10, 4).remove(0);
+        FsmStoreOps::retry(instance, "busy".to_string(), 50, 10);
+        assert_eq!(FsmStoreOps::due(20, 4)[0].instance_id(), second);
+
+        let instance = FsmStoreOps::due(50, 4).remove(1);
+        assert_eq!((instance.instance_id(), instance.attempts()), (first, 1));
+        FsmStoreOps::advance(instance, "install", vec![3], 50);
+        let status = FsmStoreOps::status(first).expect("first status");
+        assert_eq!(status.state, "install");
+        assert_eq!((status.attempts, status.last_error), (0, None));
+
+        let instance = FsmStoreOps::due(50, 4).remove(0);
+        FsmStoreOps::complete(instance, 60);
+        let status = FsmStoreOps::status(second).expect("second status");
+        assert_eq!(status.status, WorkflowRunStatus::Completed);
+        assert_eq!(status.next_run_at_ns, None);
+
+        assert_eq!(
+            FsmStoreOps::prune_terminal(|updated_at_ns| updated_at_ns < 100),
+            1
+        );
+        assert_eq!(FsmStoreOps::status(second), None);
+        assert!(FsmStoreOps::status(first).is_some());
+        FsmStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn largest_allowed_state_fits_the_stable_record() {
+        let _guard = seams::lock();
+        FsmStoreOps::reset_for_tests();
+        let name = "n".repeat(policy::FSM_NAME_MAX_BYTES);
+        let state = vec![0xff; policy::FSM_STATE_MAX_BYTES];
+        let instance_id = FsmStoreOps::create(&name, &name, state.clone(), u64::MAX);
+
+        let instance = FsmStoreOps::due(u64::MAX, 1).remove(0);
+        assert_eq!(instance.state(), state.as_slice());
+        FsmStoreOps::retry(
+            instance,
+            "x".repeat(FSM_ERROR_MAX_CHARS * 4),
+            u64::MAX,
+            u64::MAX,
+        );
+        assert_eq!(
+            FsmStoreOps::status(instance_id).map(|status| status.attempts),
+            Some(1)
+        );
+        FsmStoreOps::reset_for_tests();
+    }
+}
@@ -0,0 +1,42 @@
+//! Module: ops::storage::migration
+//!
+//! Responsibility: provide deterministic access to map migration cursors.
+//! Does not own: batch execution, timer scheduling, or value conversion.
+//! Boundary: the map migration workflow reads and advances cursors through this facade.
+
+use crate::{
+    dto::memory::MapMigrationStatus,
+    storage::stable::migration::{MapMigrationCursorRecord, MapMigrationCursorStore},
+};
+
+///
+/// MapMigrationCursorOps
+///
+/// Mechanical map migration cursor store access (no policy).
+///
+
+pub struct MapMigrationCursorOps;
+
+impl MapMigrationCursorOps {
+    #[must_use]
+    pub fn get(memory_id: u8) -> Option<MapMigrationCursorRecord> {
+        MapMigrationCursorStore::get(memory_id)
+    }
+
+    pub fn upsert(memory_id: u8, cursor: MapMigrationCursorRecord) {
+        MapMigrationCursorStore::upsert(memory_id, cursor);
+    }
+
+    #[must_use]
+    pub fn status(memory_id: u8) -> Option<MapMigrationStatus> {
+        Self::get(memory_id).map(|cursor| MapMigrationStatus {
+            memory_id,
+            target: cursor.target,
+            migrated: cursor.migrated,
+            batches: cursor.batches,
+            completed: cursor.completed,
+            started_at: cursor.started_at,
+            updated_at: cursor.updated_at,
+        })
+    }
+}
@@ -4,17 +4,25 @@
 //! Does not own: stable record schemas, workflow orchestration, or endpoint DTOs.
 //! Boundary: ops layer between workflows and stable storage facades.
 
+pub mod acl;
 pub mod auth;
+pub mod blue_green;
 pub mod children;
 pub mod cycles;
+pub mod event_bus;
 pub mod fleet_activation;
+pub mod fsm;
 pub mod icp_refill;
 pub mod index;
 pub mod intent;
+pub mod migration;
+pub mod outbox;
 pub mod placement;
 pub mod pool;
+pub mod query_cache;
 pub mod registry;
 pub mod replay;
+pub mod session;
 pub mod state;
 
 use crate::{InternalError, ops::OpsError};
@@ -0,0 +1,254 @@
+//! Module: ops::storage::outbox
+//!
+//! Responsibility: mutate and project the per-canister call outbox.
+//! Does not own: descriptor validation, retry policy, or call dispatch.
+//! Boundary: storage ops convert stable records into workflow views and DTOs.
+
+use crate::{
+    dto::outbox::OutboxFailedEntry,
+    ops::prelude::*,
+    storage::stable::outbox::{OutboxEntryRecord, OutboxKeyRecord, OutboxStore},
+};
+
+const OUTBOX_ERROR_MAX_CHARS: usize = 512;
+
+///
+/// OutboxEntry
+///
+/// Workflow view of one pending outbox call, keyed by its current due time.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutboxEntry {
+    key: OutboxKeyRecord,
+    record: OutboxEntryRecord,
+}
+
+impl OutboxEntry {
+    #[must_use]
+    pub const fn entry_id(&self) -> u64 {
+        self.record.entry_id
+    }
+
+    #[must_use]
+    pub const fn target(&self) -> Principal {
+        self.record.target
+    }
+
+    #[must_use]
+    pub fn method(&self) -> &str {
+        &self.record.method
+    }
+
+    #[must_use]
+    pub fn args(&self) -> &[u8] {
+        &self.record.args
+    }
+
+    #[must_use]
+    pub const fn cycles(&self) -> u128 {
+        self.record.cycles
+    }
+
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.record.attempts
+    }
+}
+
+///
+/// OutboxStoreOps
+///
+/// Per-canister outbox storage operations.
+///
+
+pub struct OutboxStoreOps;
+
+impl OutboxStoreOps {
+    /// Persist one call descriptor as due now and return its entry id.
+    pub fn enqueue(
+        target: Principal,
+        method: &str,
+        args: Vec<u8>,
+        cycles: u128,
+        now_ns: u64,
+    ) -> u64 {
+        let mut meta = OutboxStore::meta();
+        let entry_id = meta.next_entry_id;
+        meta.next_entry_id = meta.next_entry_id.saturating_add(1);
+        OutboxStore::set_meta(meta);
+
+        OutboxStore::insert_entry(
+            OutboxKeyRecord {
+                due_at_ns: now_ns,
+                entry_id,
+            },
+            OutboxEntryRecord {
+                entry_id,
+                target,
+                method: method.to_string(),
+                args,
+                cycles,
+                enqueued_at_ns: now_ns,
+                attempts: 0,
+                last_error: None,
+            },
+        );
+        entry_id
+    }
+
+    #[must_use]
+    pub fn due_entries(now_ns: u64, limit: usize) -> Vec<OutboxEntry> {
+        OutboxStore::due_entries(now_ns, limit)
+            .into_iter()
+            .map(|(key, record)| OutboxEntry { key, record })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn next_due_at_ns() -> Option<u64> {
+        OutboxStore::next_due_at_ns()
+    }
+
+    #[must_use]
+    pub fn pending_count() -> u64 {
+        OutboxStore::entry_count()
+    }
+
+    /// Drop an entry whose call completed.
+    pub fn complete(entry: &OutboxEntry) {
+        OutboxStore::remove_entry(&entry.key);
+    }
+
+    /// Record a failed dispatch and move the entry to `retry_at_ns`.
+    pub fn reschedule(entry: OutboxEntry, error: String, retry_at_ns: u64) {
+        let OutboxEntry { key, mut record } = entry;
+        OutboxStore::remove_entry(&key);
+
+        record.attempts = record.attempts.saturating_add(1);
+        record.last_error = Some(truncate_error(error));
+        OutboxStore::insert_entry(
+            OutboxKeyRecord {
+                due_at_ns: retry_at_ns,
+                entry_id: record.entry_id,
+            },
+            record,
+        );
+    }
+
+    /// Record the final failed dispatch and keep the entry for inspection.
+    pub fn fail(entry: OutboxEntry, error: String) {
+        let OutboxEntry { key, mut record } = entry;
+        OutboxStore::remove_entry(&key);
+
+        record.attempts = record.attempts.saturating_add(1);
+        record.last_error = Some(truncate_error(error));
+        OutboxStore::insert_failed(record);
+    }
+
+    #[must_use]
+    pub fn failed() -> Vec<OutboxFailedEntry> {
+        OutboxStore::failed_entries()
+            .into_iter()
+            .map(failed_to_dto)
+            .collect()
+    }
+
+    /// Requeue a failed entry as due now with a fresh attempt budget.
+    pub fn retry_failed(entry_id: u64, now_ns: u64) -> bool {
+        let Some(mut record) = OutboxStore::remove_failed(entry_id) else {
+            return false;
+        };
+
+        record.attempts = 0;
+        OutboxStore::insert_entry(
+            OutboxKeyRecord {
+                due_at_ns: now_ns,
+                entry_id,
+            },
+            record,
+        );
+        true
+    }
+
+    pub fn discard_failed(entry_id: u64) -> bool {
+        OutboxStore::remove_failed(entry_id).is_some()
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        OutboxStore::clear_for_tests();
+    }
+}
+
+fn failed_to_dto(record: OutboxEntryRecord) -> OutboxFailedEntry {
+    OutboxFailedEntry {
+        entry_id: record.entry_id,
+        target: record.target,
+        method: record.method,
+        args_len: record.args.len() as u64,
+        cycles: record.cycles,
+        enqueued_at_ns: record.enqueued_at_ns,
+        attempts: record.attempts,
+        last_error: record.last_error,
+    }
+}
+
+fn truncate_error(error: String) -> String {
+    error.chars().take(OUTBOX_ERROR_MAX_CHARS).collect()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::policy::pure::outbox as policy, test::seams};
+
+    #[test]
+    fn entries_drain_in_due_order_and_failures_retry_with_fresh_budget() {
+        let _guard = seams::lock();
+        OutboxStoreOps::reset_for_tests();
+        let first = OutboxStoreOps::enqueue(seams::p(1), "notify", vec![1], 0, 10);
+        let second = OutboxStoreOps::enqueue(seams::p(2), "notify", vec![2], 0, 10);
+        assert_eq!((first, second), (1, 2));
+
+        let entry = OutboxStoreOps::due_entries(10, 1).remove(0);
+        assert_eq!(entry.entry_id(), first);
+        OutboxStoreOps::reschedule(entry, "busy".to_string(), 40);
+        assert_eq!(OutboxStoreOps::due_entries(10, 4)[0].entry_id(), second);
+        assert_eq!(OutboxStoreOps::pending_count(), 2);
+
+        let entry = OutboxStoreOps::due_entries(40, 4).remove(1);
+        assert_eq!(entry.attempts(), 1);
+        OutboxStoreOps::fail(entry, "rejected".to_string());
+        assert_eq!(OutboxStoreOps::pending_count(), 1);
+
+        let failed = OutboxStoreOps::failed();
+        assert_eq!(failed.len(), 1);
+        assert_eq!((failed[0].entry_id, failed[0].attempts), (first, 2));
+        assert_eq!(failed[0].args_len, 1);
+
+        assert!(OutboxStoreOps::retry_failed(first, 50));
+        assert!(OutboxStoreOps::failed().is_empty());
+        assert!(!OutboxStoreOps::discard_failed(first));
+        OutboxStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn largest_allowed_args_fit_the_stable_record() {
+        let _guard = seams::lock();
+        OutboxStoreOps::reset_for_tests();
+        let method = "m".repeat(policy::OUTBOX_METHOD_MAX_BYTES);
+        let args = vec![0xff; policy::OUTBOX_ARGS_MAX_BYTES];
+        OutboxStoreOps::enqueue(seams::p(1), &method, args.clone(), u128::MAX, u64::MAX);
+
+        let entry = OutboxStoreOps::due_entries(u64::MAX, 1).remove(0);
+        assert_eq!(entry.args(), args.as_slice());
+        OutboxStoreOps::fail(entry, "x".repeat(OUTBOX_ERROR_MAX_CHARS * 4));
+        assert_eq!(OutboxStoreOps::failed()[0].args_len, args.len() as u64);
+        OutboxStoreOps::reset_for_tests();
+    }
+}
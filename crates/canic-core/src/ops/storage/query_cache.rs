@@ -0,0 +1,162 @@
+//! Module: ops::storage::query_cache
+//!
+//! Responsibility: read and mutate the stable spillover tier of the query response cache.
+//! Does not own: freshness decisions, heap-tier eviction, or response encoding.
+//! Boundary: the query-cache workflow spills heap evictions here and falls back to it on lookup.
+
+use crate::{
+    domain::policy::pure::query_cache::QUERY_CACHE_STABLE_MAX_ENTRIES,
+    storage::stable::query_cache::{QueryCacheEntryRecord, QueryCacheKey, QueryCacheStore},
+};
+
+///
+/// CachedResponse
+///
+/// One cached Candid-encoded query response and its absolute expiry.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachedResponse {
+    pub endpoint: String,
+    pub response: Vec<u8>,
+    pub expires_at_ns: u64,
+}
+
+///
+/// QueryCacheStoreOps
+///
+/// Stable spillover storage for cached query responses.
+///
+
+pub struct QueryCacheStoreOps;
+
+impl QueryCacheStoreOps {
+    #[must_use]
+    pub fn get(digest: [u8; 32]) -> Option<CachedResponse> {
+        QueryCacheStore::get(QueryCacheKey(digest)).map(|record| CachedResponse {
+            endpoint: record.endpoint,
+            response: record.response,
+            expires_at_ns: record.expires_at_ns,
+        })
+    }
+
+    /// Store one response, first dropping expired entries and then the
+    /// earliest-expiring ones once the tier is full.
+    pub fn insert(digest: [u8; 32], entry: CachedResponse, now_ns: u64) {
+        let key = QueryCacheKey(digest);
+        if QueryCacheStore::get(key).is_none() {
+            make_room(now_ns);
+        }
+
+        QueryCacheStore::insert(
+            key,
+            QueryCacheEntryRecord {
+                endpoint: entry.endpoint,
+                response: entry.response,
+                expires_at_ns: entry.expires_at_ns,
+            },
+        );
+    }
+
+    /// Drop every spilled response of one endpoint and return how many were removed.
+    pub fn remove_endpoint(endpoint: &str) -> usize {
+        let stale: Vec<_> = QueryCacheStore::entries()
+            .into_iter()
+            .filter(|(_, record)| record.endpoint == endpoint)
+            .map(|(key, _)| key)
+            .collect();
+        for key in &stale {
+            QueryCacheStore::remove(*key);
+        }
+
+        stale.len()
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        QueryCacheStore::clear_for_tests();
+    }
+}
+
+fn make_room(now_ns: u64) {
+    let max_entries = QUERY_CACHE_STABLE_MAX_ENTRIES as u64;
+    if QueryCacheStore::len() < max_entries {
+        return;
+    }
+
+    let mut entries = QueryCacheStore::entries();
+    entries.sort_by_key(|(key, record)| (record.expires_at_ns, *key));
+    for (key, record) in entries {
+        if record.expires_at_ns > now_ns && QueryCacheStore::len() < max_entries {
+            break;
+        }
+        QueryCacheStore::remove(key);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::policy::pure::query_cache::QUERY_CACHE_ENTRY_MAX_BYTES, test::seams};
+
+    fn entry(endpoint: &str, expires_at_ns: u64) -> CachedResponse {
+        CachedResponse {
+            endpoint: endpoint.to_string(),
+            response: vec![7],
+            expires_at_ns,
+        }
+    }
+
+    #[test]
+    fn endpoints_are_invalidated_independently() {
+        let _guard = seams::lock();
+        QueryCacheStoreOps::reset_for_tests();
+        QueryCacheStoreOps::insert([1; 32], entry("dashboard", 10), 0);
+        QueryCacheStoreOps::insert([2; 32], entry("dashboard", 10), 0);
+        QueryCacheStoreOps::insert([3; 32], entry("stats", 10), 0);
+
+        assert_eq!(QueryCacheStoreOps::remove_endpoint("dashboard"), 2);
+        assert_eq!(QueryCacheStoreOps::get([1; 32]), None);
+        assert_eq!(QueryCacheStoreOps::get([3; 32]), Some(entry("stats", 10)));
+        QueryCacheStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn full_tier_drops_expired_then_earliest_expiring_entries() {
+        let _guard = seams::lock();
+        QueryCacheStoreOps::reset_for_tests();
+        for i in 0..QUERY_CACHE_STABLE_MAX_ENTRIES {
+            let mut digest = [0; 32];
+            digest[..8].copy_from_slice(&(i as u64).to_be_bytes());
+            QueryCacheStoreOps::insert(digest, entry("dashboard", 100 + i as u64), 0);
+        }
+
+        QueryCacheStoreOps::insert([0xff; 32], entry("stats", 50), 101);
+        assert_eq!(
+            QueryCacheStore::len(),
+            QUERY_CACHE_STABLE_MAX_ENTRIES as u64 - 1
+        );
+        assert_eq!(QueryCacheStoreOps::get([0; 32]), None);
+        assert!(QueryCacheStoreOps::get([0xff; 32]).is_some());
+        QueryCacheStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn largest_allowed_response_fits_the_stable_record() {
+        let _guard = seams::lock();
+        QueryCacheStoreOps::reset_for_tests();
+        let response = CachedResponse {
+            endpoint: "e".repeat(256),
+            response: vec![0xff; QUERY_CACHE_ENTRY_MAX_BYTES],
+            expires_at_ns: u64::MAX,
+        };
+        QueryCacheStoreOps::insert([9; 32], response.clone(), 0);
+
+        assert_eq!(QueryCacheStoreOps::get([9; 32]), Some(response));
+        QueryCacheStoreOps::reset_for_tests();
+    }
+}
@@ -5,7 +5,13 @@
 //! Boundary: storage ops conversion layer for topology registry records.
 
 use crate::{
-    cdk::types::Principal, dto::canister::CanisterInfo, dto::topology::SubnetRegistryEntry,
+    cdk::types::{Cycles, Principal},
+    dto::canister::CanisterInfo,
+    dto::topology::{
+        CanisterCyclesSnapshot, CanisterRegistryStatus, CanisterSummary, SubnetRegistryEntry,
+    },
+    model::cycles_funding::FundingLedgerSnapshot,
+    storage::canister::CanisterRecord,
 };
 
 ///
@@ -18,10 +24,7 @@ pub struct SubnetRegistryResponseMapper;
 
 impl SubnetRegistryResponseMapper {
     #[must_use]
-    pub fn record_to_response(
-        pid: Principal,
-        record: crate::storage::canister::CanisterRecord,
-    ) -> SubnetRegistryEntry {
+    pub fn record_to_response(pid: Principal, record: CanisterRecord) -> SubnetRegistryEntry {
         let record_view = CanisterInfo {
             pid,
             role: record.role.clone(),
@@ -37,3 +40,39 @@ impl SubnetRegistryResponseMapper {
         }
     }
 }
+
+///
+/// CanisterSummaryMapper
+///
+/// Storage-ops mapper for registry listing summaries.
+///
+
+pub struct CanisterSummaryMapper;
+
+impl CanisterSummaryMapper {
+    #[must_use]
+    pub fn record_to_summary(
+        pid: Principal,
+        record: CanisterRecord,
+        funding: Option<FundingLedgerSnapshot>,
+    ) -> CanisterSummary {
+        let status = if record.module_hash.is_some() {
+            CanisterRegistryStatus::Installed
+        } else {
+            CanisterRegistryStatus::Created
+        };
+
+        CanisterSummary {
+            pid,
+            role: record.role,
+            parent_pid: record.parent_pid,
+            created_at: record.created_at,
+            module_hash: record.module_hash,
+            cycles: funding.map(|snapshot| CanisterCyclesSnapshot {
+                granted_total: Cycles::new(snapshot.granted_total),
+                last_granted_at: snapshot.last_granted_at,
+            }),
+            status,
+        }
+    }
+}
@@ -6,8 +6,13 @@
 
 use crate::{
     InternalError,
-    dto::topology::SubnetRegistryResponse,
-    ops::{prelude::*, storage::StorageOpsError},
+    dto::topology::{CanisterFilter, CanisterSummary, SubnetRegistryResponse},
+    ops::{
+        prelude::*,
+        storage::{
+            StorageOpsError, blue_green::BlueGreenStoreOps, cycles::CyclesFundingLedgerStoreOps,
+        },
+    },
     storage::{
         canister::{CanisterEntryRecord, CanisterRecord},
         stable::registry::subnet::{SubnetRegistry, SubnetRegistryData},
@@ -241,6 +246,13 @@ impl SubnetRegistryOps {
         SubnetRegistry::export()
     }
 
+    /// Registry as seen by root-derived indexes: while a role is mid blue/green
+    /// deployment, only its routed instance is listed.
+    #[must_use]
+    pub fn routed_data() -> SubnetRegistryData {
+        BlueGreenStoreOps::route(SubnetRegistry::export())
+    }
+
     #[must_use]
     pub fn response() -> SubnetRegistryResponse {
         let mut entries = Vec::with_capacity(SubnetRegistry::len());
@@ -253,6 +265,25 @@ impl SubnetRegistryOps {
         SubnetRegistryResponse(entries)
     }
 
+    /// Summarize registered canisters matching `filter` in canister-id order.
+    #[must_use]
+    pub fn summaries(filter: &CanisterFilter) -> Vec<CanisterSummary> {
+        let mut summaries = Vec::new();
+
+        SubnetRegistry::for_each(|pid, record| {
+            let summary = super::mapper::CanisterSummaryMapper::record_to_summary(
+                pid,
+                record,
+                CyclesFundingLedgerStoreOps::snapshot(pid),
+            );
+            if summary_matches(&summary, filter) {
+                summaries.push(summary);
+            }
+        });
+
+        summaries
+    }
+
     /// Group direct root children by role for root-owned index validation.
     #[must_use]
     pub fn direct_root_role_index() -> BTreeMap<CanisterRole, Vec<Principal>> {
@@ -299,6 +330,18 @@ impl SubnetRegistryOps {
     }
 }
 
+// Apply the optional registry listing filter to one summary.
+fn summary_matches(summary: &CanisterSummary, filter: &CanisterFilter) -> bool {
+    filter
+        .role
+        .as_ref()
+        .is_none_or(|role| &summary.role == role)
+        && filter
+            .parent
+            .is_none_or(|parent| summary.parent_pid == Some(parent))
+        && filter.status.is_none_or(|status| summary.status == status)
+}
+
 // -----------------------------------------------------------------------------
 // Tests
 // -----------------------------------------------------------------------------
@@ -306,6 +349,7 @@ impl SubnetRegistryOps {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dto::topology::CanisterRegistryStatus;
 
     fn p(id: u8) -> Principal {
         Principal::from_slice(&[id; 29])
@@ -364,6 +408,33 @@ mod tests {
         assert_eq!(beta.record.module_hash, Some(vec![3]));
     }
 
+    #[test]
+    fn summaries_apply_role_and_parent_filters() {
+        seed_registry();
+
+        let alpha = SubnetRegistryOps::summaries(&CanisterFilter {
+            role: Some(CanisterRole::new("alpha_registry_test")),
+            parent: Some(p(91)),
+            status: None,
+        });
+        let pids: Vec<Principal> = alpha.iter().map(|summary| summary.pid).collect();
+
+        assert_eq!(pids, vec![p(90), p(92)]);
+        assert!(
+            alpha
+                .iter()
+                .all(|summary| summary.status == CanisterRegistryStatus::Installed)
+        );
+        assert!(
+            SubnetRegistryOps::summaries(&CanisterFilter {
+                role: Some(CanisterRole::new("alpha_registry_test")),
+                parent: Some(p(93)),
+                status: None,
+            })
+            .is_empty()
+        );
+    }
+
     #[test]
     fn registrations_for_role_returns_empty_for_absent_role() {
         seed_registry();
@@ -0,0 +1,261 @@
+//! Module: ops::storage::session
+//!
+//! Responsibility: open, refresh, and close sessions of verified delegated-token subjects.
+//! Does not own: token verification, idle-timeout decisions, or endpoint authorization.
+//! Boundary: auth predicates record and check activity here; the session workflow reads it.
+
+use crate::{
+    cdk::types::Principal,
+    domain::policy::pure::session as policy,
+    dto::session::SessionEntry,
+    storage::stable::session::{SessionRecord, SessionStore},
+};
+
+///
+/// VerifiedSession
+///
+/// Identity and lifetime taken from one successfully verified delegated token.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerifiedSession {
+    pub subject: Principal,
+    pub issuer_pid: Principal,
+    pub scopes: Vec<String>,
+    pub token_issued_at_ns: u64,
+    pub expires_at_ns: u64,
+}
+
+///
+/// Session
+///
+/// Stored session of one token subject.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub subject: Principal,
+    pub issuer_pid: Principal,
+    pub scopes: Vec<String>,
+    pub created_at_ns: u64,
+    pub expires_at_ns: u64,
+    pub last_seen_ns: u64,
+    pub previous_seen_ns: u64,
+    pub idle_closed: bool,
+}
+
+impl Session {
+    #[must_use]
+    pub fn into_entry(self) -> SessionEntry {
+        SessionEntry {
+            principal: self.subject,
+            issuer_pid: self.issuer_pid,
+            scopes: self.scopes,
+            created_at_ns: self.created_at_ns,
+            expires_at_ns: self.expires_at_ns,
+            last_seen_ns: self.last_seen_ns,
+            idle_closed: self.idle_closed,
+        }
+    }
+}
+
+///
+/// SessionStoreOps
+///
+/// Stable sessions keyed by delegated-token subject.
+///
+
+pub struct SessionStoreOps;
+
+impl SessionStoreOps {
+    #[must_use]
+    pub fn get(subject: Principal) -> Option<Session> {
+        SessionStore::get(subject).map(|record| session_from_record(subject, record))
+    }
+
+    /// Record a verified token: open a session on first use or for a newer
+    /// token, otherwise mark the open session as seen.
+    ///
+    /// A session closed for idling stays closed until a newer token arrives.
+    pub fn observe(verified: VerifiedSession, now_ns: u64) {
+        let existing = SessionStore::get(verified.subject);
+        if let Some(record) = &existing
+            && !policy::is_expired(record.expires_at_ns, now_ns)
+            && !policy::token_replaces_session(
+                record.token_issued_at_ns,
+                verified.token_issued_at_ns,
+            )
+        {
+            if !record.idle_closed {
+                Self::touch(verified.subject, now_ns);
+            }
+            return;
+        }
+
+        if existing.is_none() {
+            make_room(now_ns);
+        }
+        SessionStore::insert(
+            verified.subject,
+            SessionRecord {
+                issuer_pid: verified.issuer_pid,
+                scopes: verified.scopes,
+                token_issued_at_ns: verified.token_issued_at_ns,
+                expires_at_ns: verified.expires_at_ns,
+                created_at_ns: now_ns,
+                last_seen_ns: now_ns,
+                previous_seen_ns: now_ns,
+                idle_closed: false,
+            },
+        );
+    }
+
+    /// Mark a session as seen at `now_ns`; repeated touches in one call keep
+    /// the activity from before the call.
+    pub fn touch(subject: Principal, now_ns: u64) {
+        let Some(mut record) = SessionStore::get(subject) else {
+            return;
+        };
+        if record.last_seen_ns == now_ns {
+            return;
+        }
+
+        record.previous_seen_ns = record.last_seen_ns;
+        record.last_seen_ns = now_ns;
+        SessionStore::insert(subject, record);
+    }
+
+    /// Close a session that exceeded its idle timeout.
+    pub fn close_idle(subject: Principal) {
+        let Some(mut record) = SessionStore::get(subject) else {
+            return;
+        };
+
+        record.idle_closed = true;
+        SessionStore::insert(subject, record);
+    }
+
+    /// Every stored session, in subject order.
+    #[must_use]
+    pub fn entries() -> Vec<SessionEntry> {
+        SessionStore::export()
+            .entries
+            .into_iter()
+            .map(|(subject, record)| session_from_record(subject, record).into_entry())
+            .collect()
+    }
+
+    #[cfg(test)]
+    pub fn reset_for_tests() {
+        SessionStore::clear_for_tests();
+    }
+}
+
+fn session_from_record(subject: Principal, record: SessionRecord) -> Session {
+    Session {
+        subject,
+        issuer_pid: record.issuer_pid,
+        scopes: record.scopes,
+        created_at_ns: record.created_at_ns,
+        expires_at_ns: record.expires_at_ns,
+        last_seen_ns: record.last_seen_ns,
+        previous_seen_ns: record.previous_seen_ns,
+        idle_closed: record.idle_closed,
+    }
+}
+
+// Drop expired sessions, then the least recently seen ones, once the store is full.
+fn make_room(now_ns: u64) {
+    let max_entries = policy::SESSION_MAX_ENTRIES as u64;
+    if SessionStore::len() < max_entries {
+        return;
+    }
+
+    let mut entries = SessionStore::export().entries;
+    entries.sort_by_key(|(subject, record)| {
+        (
+            !policy::is_expired(record.expires_at_ns, now_ns),
+            record.last_seen_ns,
+            *subject,
+        )
+    });
+    for (subject, record) in entries {
+        if !policy::is_expired(record.expires_at_ns, now_ns) && SessionStore::len() < max_entries {
+            break;
+        }
+        SessionStore::remove(subject);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::seams::{self, p};
+
+    fn verified(subject: Principal, token_issued_at_ns: u64) -> VerifiedSession {
+        VerifiedSession {
+            subject,
+            issuer_pid: p(0xee),
+            scopes: vec!["read".to_string()],
+            token_issued_at_ns,
+            expires_at_ns: 1_000,
+        }
+    }
+
+    #[test]
+    fn first_verification_opens_and_later_ones_touch_the_session() {
+        let _guard = seams::lock();
+        SessionStoreOps::reset_for_tests();
+
+        SessionStoreOps::observe(verified(p(1), 5), 10);
+        SessionStoreOps::observe(verified(p(1), 5), 20);
+        SessionStoreOps::touch(p(1), 20);
+
+        let session = SessionStoreOps::get(p(1)).expect("session");
+        assert_eq!(session.created_at_ns, 10);
+        assert_eq!(session.last_seen_ns, 20);
+        assert_eq!(session.previous_seen_ns, 10);
+        assert_eq!(session.scopes, vec!["read"]);
+        SessionStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn idle_closed_sessions_reopen_only_for_a_newer_token() {
+        let _guard = seams::lock();
+        SessionStoreOps::reset_for_tests();
+        SessionStoreOps::observe(verified(p(1), 5), 10);
+        SessionStoreOps::close_idle(p(1));
+
+        SessionStoreOps::observe(verified(p(1), 5), 20);
+        let closed = SessionStoreOps::get(p(1)).expect("session");
+        assert!(closed.idle_closed);
+        assert_eq!(closed.last_seen_ns, 10);
+
+        SessionStoreOps::observe(verified(p(1), 15), 30);
+        let reopened = SessionStoreOps::get(p(1)).expect("session");
+        assert!(!reopened.idle_closed);
+        assert_eq!(reopened.created_at_ns, 30);
+        SessionStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn full_store_drops_expired_then_least_recently_seen_sessions() {
+        let _guard = seams::lock();
+        SessionStoreOps::reset_for_tests();
+        for i in 0..policy::SESSION_MAX_ENTRIES {
+            let mut bytes = [0_u8; 29];
+            bytes[..8].copy_from_slice(&(i as u64).to_be_bytes());
+            SessionStoreOps::observe(verified(Principal::from_slice(&bytes), 1), 100 + i as u64);
+        }
+
+        SessionStoreOps::observe(verified(p(0xff), 1), 900);
+        assert_eq!(SessionStore::len(), policy::SESSION_MAX_ENTRIES as u64);
+        assert!(SessionStoreOps::get(Principal::from_slice(&[0; 29])).is_none());
+        assert!(SessionStoreOps::get(p(0xff)).is_some());
+        SessionStoreOps::reset_for_tests();
+    }
+}
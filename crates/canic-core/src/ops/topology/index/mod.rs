@@ -37,7 +37,7 @@ pub struct AppIndexResolver;
 impl AppIndexResolver {
     pub fn resolve() -> Result<AppIndexData, InternalError> {
         if EnvOps::is_root() {
-            let registry = SubnetRegistryOps::data();
+            let registry = SubnetRegistryOps::routed_data();
             let cfg = ConfigOps::get()?;
 
             RootAppIndexBuilder::build(&registry, &cfg.services.fleet.roles)
@@ -62,7 +62,7 @@ pub struct SubnetIndexResolver;
 impl SubnetIndexResolver {
     pub fn resolve() -> Result<SubnetIndexData, InternalError> {
         if EnvOps::is_root() {
-            let registry = SubnetRegistryOps::data();
+            let registry = SubnetRegistryOps::routed_data();
             let cfg = ConfigOps::current_subnet()?;
 
             RootSubnetIndexBuilder::build(&registry, &cfg.subnet_index_roles())
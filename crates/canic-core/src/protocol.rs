@@ -16,10 +16,19 @@ pub const CANIC_ACTIVE_DELEGATION_PROOF_STATUS: &str = "canic_active_delegation_
 pub const CANIC_PREPARE_ROLE_ATTESTATION: &str = "canic_prepare_role_attestation";
 pub const CANIC_GET_ROLE_ATTESTATION: &str = "canic_get_role_attestation";
 pub const CANIC_INSTALL_ACTIVE_DELEGATION_PROOF: &str = "canic_install_active_delegation_proof";
+pub const CANIC_EVENT_PUBLISH: &str = "canic_event_publish";
+pub const CANIC_EVENT_SUBSCRIBE: &str = "canic_event_subscribe";
+pub const CANIC_EVENT_UNSUBSCRIBE: &str = "canic_event_unsubscribe";
+pub const CANIC_HEALTH_REPORT: &str = "canic_health_report";
 pub const CANIC_BOOTSTRAP_STATUS: &str = "canic_bootstrap_status";
 pub const CANIC_HEALTH: &str = "canic_health";
 pub const CANIC_READINESS: &str = "canic_readiness";
 pub const CANIC_RUNTIME_STATUS: &str = "canic_runtime_status";
+pub const CANIC_WORKFLOW_STATUS: &str = "canic_workflow_status";
+pub const CANIC_ACL_GRANT: &str = "canic_acl_grant";
+pub const CANIC_ACL_REVOKE: &str = "canic_acl_revoke";
+pub const CANIC_ACL_LIST: &str = "canic_acl_list";
+pub const CANIC_SESSIONS_LIST: &str = "canic_sessions_list";
 pub const CANIC_CYCLE_BALANCE: &str = "canic_cycle_balance";
 pub const CANIC_CYCLE_TRACKER: &str = "canic_cycle_tracker";
 pub const CANIC_CYCLE_TOPUPS: &str = "canic_cycle_topups";
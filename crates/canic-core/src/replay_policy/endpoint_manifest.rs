@@ -46,6 +46,14 @@ pub const ENDPOINT_REPLAY_POLICY_MANIFEST: &[EndpointReplayPolicy] = &[
         Some(DEPLOYMENT_QUOTA_V1),
         Some(DEPLOYMENT_RESERVE_V1),
     ),
+    update_costed_snapshot_convergent(
+        "canic_blue_green_deploy",
+        command_kind("blue_green.deploy.v1"),
+        CostClass::ManagementDeployment,
+        Some(DEPLOYMENT_QUOTA_V1),
+        Some(DEPLOYMENT_RESERVE_V1),
+    ),
+    query_read_only("canic_blue_green_status"),
     update_snapshot_convergent(
         "canic_upsert_root_issuer_policy",
         command_kind("auth.upsert_root_issuer_policy.v1"),
@@ -79,6 +87,24 @@ pub const ENDPOINT_REPLAY_POLICY_MANIFEST: &[EndpointReplayPolicy] = &[
         Some(DEPLOYMENT_QUOTA_V1),
         Some(DEPLOYMENT_RESERVE_V1),
     ),
+    update_snapshot_convergent("canic_config_patch", command_kind("config.patch.v1")),
+    update_intentionally_non_idempotent(
+        "canic_event_publish",
+        command_kind("event_bus.publish.v1"),
+        "each publish appends a new event; subscribers deduplicate deliveries by event_id",
+    ),
+    update_snapshot_convergent(
+        "canic_event_subscribe",
+        command_kind("event_bus.subscribe.v1"),
+    ),
+    update_snapshot_convergent(
+        "canic_event_unsubscribe",
+        command_kind("event_bus.unsubscribe.v1"),
+    ),
+    query_read_only("canic_event_dead_letters"),
+    update_monotonic_transition("canic_event_bus_admin", command_kind("event_bus.admin.v1")),
+    update_snapshot_convergent("canic_health_report", command_kind("health.report.v1")),
+    query_read_only("canic_fleet_health"),
     update_snapshot_convergent("canic_sync_state", command_kind("cascade.sync_state.v1")),
     update_snapshot_convergent(
         "canic_sync_topology",
@@ -102,6 +128,11 @@ pub const ENDPOINT_REPLAY_POLICY_MANIFEST: &[EndpointReplayPolicy] = &[
     query_read_only("canic_health"),
     query_read_only("canic_readiness"),
     query_read_only("canic_runtime_status"),
+    query_read_only("canic_workflow_status"),
+    update_snapshot_convergent("canic_acl_grant", command_kind("acl.grant.v1")),
+    update_snapshot_convergent("canic_acl_revoke", command_kind("acl.revoke.v1")),
+    query_read_only("canic_acl_list"),
+    query_read_only("canic_sessions_list"),
     update_monotonic_transition(
         "canic_template_prepare_admin",
         command_kind("wasm_store.template_prepare_admin.v1"),
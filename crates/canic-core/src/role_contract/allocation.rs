@@ -35,10 +35,41 @@ pub mod memory {
         pub const REPLAY_RECEIPTS_ID: u8 = 20;
     }
 
+    pub mod outbox {
+        pub const OUTBOX_META_ID: u8 = 23;
+        pub const OUTBOX_ENTRIES_ID: u8 = 24;
+        pub const OUTBOX_FAILED_ID: u8 = 25;
+    }
+
+    pub mod fsm {
+        pub const FSM_META_ID: u8 = 26;
+        pub const FSM_INSTANCES_ID: u8 = 27;
+    }
+
+    pub mod query_cache {
+        pub const QUERY_CACHE_SPILL_ID: u8 = 28;
+    }
+
+    pub mod acl {
+        pub const ACL_ENTRIES_ID: u8 = 31;
+    }
+
+    pub mod session {
+        pub const SESSIONS_ID: u8 = 32;
+    }
+
+    pub mod blue_green {
+        pub const BLUE_GREEN_DEPLOYMENTS_ID: u8 = 36;
+    }
+
     pub mod activation {
         pub const FLEET_ACTIVATION_ID: u8 = 21;
     }
 
+    pub mod migration {
+        pub const MAP_MIGRATION_CURSORS_ID: u8 = 22;
+    }
+
     pub mod observability {
         pub const CYCLE_TRACKER_ID: u8 = 29;
         pub const CYCLE_TOPUP_EVENTS_ID: u8 = 30;
@@ -78,6 +109,13 @@ pub mod memory {
         pub const BLOB_STORAGE_BILLING_ID: u8 = 65;
     }
 
+    pub mod event_bus {
+        pub const EVENT_BUS_META_ID: u8 = 66;
+        pub const EVENT_BUS_SUBSCRIPTIONS_ID: u8 = 67;
+        pub const EVENT_BUS_DELIVERIES_ID: u8 = 68;
+        pub const EVENT_BUS_DEAD_LETTERS_ID: u8 = 69;
+    }
+
     pub mod template {
         pub const TEMPLATE_MANIFESTS_ID: u8 = 80;
         pub const TEMPLATE_CHUNK_SETS_ID: u8 = 81;
@@ -89,27 +127,38 @@ pub mod memory {
 }
 
 use memory::{
+    acl::ACL_ENTRIES_ID,
     activation::FLEET_ACTIVATION_ID,
     auth::{AUTH_STATE_ID, REPLAY_RECEIPTS_ID},
     blob_storage::{
         BLOB_DELETION_PENDING_ID, BLOB_STORAGE_BILLING_ID, STORAGE_GATEWAY_PRINCIPALS_ID,
         STORED_BLOBS_ID,
     },
+    blue_green::BLUE_GREEN_DEPLOYMENTS_ID,
     env::{ENV_ID, FLEET_STATE_ID, RETIRED_SUBNET_STATE_ID},
+    event_bus::{
+        EVENT_BUS_DEAD_LETTERS_ID, EVENT_BUS_DELIVERIES_ID, EVENT_BUS_META_ID,
+        EVENT_BUS_SUBSCRIPTIONS_ID,
+    },
+    fsm::{FSM_INSTANCES_ID, FSM_META_ID},
     intent::{
         APPLICATION_RECEIPT_ELIGIBILITY_ID, APPLICATION_RECEIPT_REPLAY_ID, INTENT_EXPIRY_INDEX_ID,
         INTENT_META_ID, INTENT_PENDING_ID, INTENT_RECORDS_ID, INTENT_TOTALS_ID,
         PLACEMENT_ACKNOWLEDGEMENT_INDEX_ID, RECEIPT_BACKED_INTENT_RECORDS_ID,
     },
+    migration::MAP_MIGRATION_CURSORS_ID,
     observability::{
         CYCLE_TOPUP_EVENTS_ID, CYCLE_TRACKER_ID, CYCLES_FUNDING_LEDGER_ID, ICP_REFILL_RECORDS_ID,
         LOG_ENTRIES_ID,
     },
+    outbox::{OUTBOX_ENTRIES_ID, OUTBOX_FAILED_ID, OUTBOX_META_ID},
     placement::{
         DIRECTORY_REGISTRY_ID, SCALING_REGISTRY_ID, SHARDING_ACTIVE_SET_ID, SHARDING_ASSIGNMENT_ID,
         SHARDING_REGISTRY_ID,
     },
     pool::CANISTER_POOL_ID,
+    query_cache::QUERY_CACHE_SPILL_ID,
+    session::SESSIONS_ID,
     template::{
         CONTROL_PLANE_SUBNET_STATE_ID, TEMPLATE_CHUNK_PAYLOADS_ID, TEMPLATE_CHUNK_REFS_ID,
         TEMPLATE_CHUNK_SETS_ID, TEMPLATE_MANIFESTS_ID, WASM_STORE_GC_STATE_ID,
@@ -135,13 +184,31 @@ const RETIRED_MEMORY_IDS: &[MemoryId] = &[
 const CORE_AUTH_STATE_IDS: &[MemoryId] = &[MemoryId::new(AUTH_STATE_ID)];
 const CORE_REPLAY_RECEIPTS_IDS: &[MemoryId] = &[MemoryId::new(REPLAY_RECEIPTS_ID)];
 const CORE_FLEET_ACTIVATION_IDS: &[MemoryId] = &[MemoryId::new(FLEET_ACTIVATION_ID)];
+const CORE_MAP_MIGRATION_IDS: &[MemoryId] = &[MemoryId::new(MAP_MIGRATION_CURSORS_ID)];
+const CORE_RUNTIME_OUTBOX_IDS: &[MemoryId] = &[
+    MemoryId::new(OUTBOX_META_ID),
+    MemoryId::new(OUTBOX_ENTRIES_ID),
+    MemoryId::new(OUTBOX_FAILED_ID),
+];
+const CORE_RUNTIME_FSM_IDS: &[MemoryId] =
+    &[MemoryId::new(FSM_META_ID), MemoryId::new(FSM_INSTANCES_ID)];
+const CORE_RUNTIME_QUERY_CACHE_IDS: &[MemoryId] = &[MemoryId::new(QUERY_CACHE_SPILL_ID)];
+const CORE_RUNTIME_ACL_IDS: &[MemoryId] = &[MemoryId::new(ACL_ENTRIES_ID)];
+const CORE_RUNTIME_SESSIONS_IDS: &[MemoryId] = &[MemoryId::new(SESSIONS_ID)];
 const CORE_RUNTIME_OBSERVABILITY_IDS: &[MemoryId] = &[
     MemoryId::new(CYCLE_TRACKER_ID),
     MemoryId::new(CYCLE_TOPUP_EVENTS_ID),
     MemoryId::new(CYCLES_FUNDING_LEDGER_ID),
     MemoryId::new(LOG_ENTRIES_ID),
 ];
+const CORE_BLUE_GREEN_DEPLOYMENTS_IDS: &[MemoryId] = &[MemoryId::new(BLUE_GREEN_DEPLOYMENTS_ID)];
 const CORE_ICP_REFILL_RECORDS_IDS: &[MemoryId] = &[MemoryId::new(ICP_REFILL_RECORDS_ID)];
+const CORE_EVENT_BUS_IDS: &[MemoryId] = &[
+    MemoryId::new(EVENT_BUS_META_ID),
+    MemoryId::new(EVENT_BUS_SUBSCRIPTIONS_ID),
+    MemoryId::new(EVENT_BUS_DELIVERIES_ID),
+    MemoryId::new(EVENT_BUS_DEAD_LETTERS_ID),
+];
 const CORE_RUNTIME_INTENT_IDS: &[MemoryId] = &[
     MemoryId::new(INTENT_META_ID),
     MemoryId::new(INTENT_RECORDS_ID),
@@ -196,6 +263,36 @@ const ALLOCATION_DEFINITIONS: &[AllocationDefinition] = &[
         AllocationOwner::CanicCore,
         CORE_FLEET_ACTIVATION_IDS,
     ),
+    definition(
+        StateAllocationKey::CoreMapMigrations,
+        AllocationOwner::CanicCore,
+        CORE_MAP_MIGRATION_IDS,
+    ),
+    definition(
+        StateAllocationKey::CoreRuntimeOutbox,
+        AllocationOwner::CanicCore,
+        CORE_RUNTIME_OUTBOX_IDS,
+    ),
+    definition(
+        StateAllocationKey::CoreRuntimeFsm,
+        AllocationOwner::CanicCore,
+        CORE_RUNTIME_FSM_IDS,
+    ),
+    definition(
+        StateAllocationKey::CoreRuntimeQueryCache,
+        AllocationOwner::CanicCore,
+        CORE_RUNTIME_QUERY_CACHE_IDS,
+    ),
+    definition(
+        StateAllocationKey::CoreRuntimeAcl,
+        AllocationOwner::CanicCore,
+        CORE_RUNTIME_ACL_IDS,
+    ),
+    definition(
+        StateAllocationKey::CoreRuntimeSessions,
+        AllocationOwner::CanicCore,
+        CORE_RUNTIME_SESSIONS_IDS,
+    ),
     definition(
         StateAllocationKey::CoreRuntimeObservability,
         AllocationOwner::CanicCore,
@@ -206,6 +303,16 @@ const ALLOCATION_DEFINITIONS: &[AllocationDefinition] = &[
         AllocationOwner::CanicCore,
         CORE_ICP_REFILL_RECORDS_IDS,
     ),
+    definition(
+        StateAllocationKey::CoreBlueGreenDeployments,
+        AllocationOwner::CanicCore,
+        CORE_BLUE_GREEN_DEPLOYMENTS_IDS,
+    ),
+    definition(
+        StateAllocationKey::CoreEventBus,
+        AllocationOwner::CanicCore,
+        CORE_EVENT_BUS_IDS,
+    ),
     definition(
         StateAllocationKey::CoreRuntimeIntent,
         AllocationOwner::CanicCore,
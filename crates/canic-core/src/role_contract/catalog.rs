@@ -221,6 +221,10 @@ const CAPABILITY_ALLOCATIONS: &[CapabilityAllocation] = &[
         RoleCapabilityKey::Runtime,
         StateAllocationKey::CoreFleetActivation,
     ),
+    capability_allocation(
+        RoleCapabilityKey::Runtime,
+        StateAllocationKey::CoreMapMigrations,
+    ),
     capability_allocation(
         RoleCapabilityKey::Runtime,
         StateAllocationKey::CoreRuntimeObservability,
@@ -229,6 +233,26 @@ const CAPABILITY_ALLOCATIONS: &[CapabilityAllocation] = &[
         RoleCapabilityKey::Runtime,
         StateAllocationKey::CoreRuntimeIntent,
     ),
+    capability_allocation(
+        RoleCapabilityKey::Runtime,
+        StateAllocationKey::CoreRuntimeOutbox,
+    ),
+    capability_allocation(
+        RoleCapabilityKey::Runtime,
+        StateAllocationKey::CoreRuntimeFsm,
+    ),
+    capability_allocation(
+        RoleCapabilityKey::Runtime,
+        StateAllocationKey::CoreRuntimeQueryCache,
+    ),
+    capability_allocation(
+        RoleCapabilityKey::Runtime,
+        StateAllocationKey::CoreRuntimeAcl,
+    ),
+    capability_allocation(
+        RoleCapabilityKey::Runtime,
+        StateAllocationKey::CoreRuntimeSessions,
+    ),
     capability_allocation(RoleCapabilityKey::Root, StateAllocationKey::CoreAuthState),
     capability_allocation(
         RoleCapabilityKey::DelegatedTokenIssuer,
@@ -250,6 +274,11 @@ const CAPABILITY_ALLOCATIONS: &[CapabilityAllocation] = &[
         RoleCapabilityKey::Root,
         StateAllocationKey::CoreIcpRefillRecords,
     ),
+    capability_allocation(RoleCapabilityKey::Root, StateAllocationKey::CoreEventBus),
+    capability_allocation(
+        RoleCapabilityKey::Root,
+        StateAllocationKey::CoreBlueGreenDeployments,
+    ),
     capability_allocation(RoleCapabilityKey::Root, StateAllocationKey::CanisterPool),
     capability_allocation(
         RoleCapabilityKey::Directory,
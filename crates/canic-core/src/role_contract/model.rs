@@ -77,12 +77,20 @@ pub enum StateAllocationKey {
     CanisterPool,
     ControlPlaneSubnetState,
     CoreAuthState,
+    CoreBlueGreenDeployments,
+    CoreEventBus,
     CoreFleetActivation,
     CoreIcpRefillRecords,
+    CoreMapMigrations,
     CoreReplayReceipts,
+    CoreRuntimeAcl,
     CoreRuntimeEnvironment,
+    CoreRuntimeFsm,
     CoreRuntimeIntent,
     CoreRuntimeObservability,
+    CoreRuntimeOutbox,
+    CoreRuntimeQueryCache,
+    CoreRuntimeSessions,
     CoreRuntimeTopology,
     DirectoryRegistry,
     ScalingRegistry,
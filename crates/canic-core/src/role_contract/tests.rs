@@ -100,11 +100,19 @@ fn canonical_allocations_match_the_active_memory_map() {
         (StateAllocationKey::CoreAuthState, vec![19]),
         (StateAllocationKey::CoreReplayReceipts, vec![20]),
         (StateAllocationKey::CoreFleetActivation, vec![21]),
+        (StateAllocationKey::CoreMapMigrations, vec![22]),
+        (StateAllocationKey::CoreRuntimeOutbox, vec![23, 24, 25]),
+        (StateAllocationKey::CoreRuntimeFsm, vec![26, 27]),
+        (StateAllocationKey::CoreRuntimeQueryCache, vec![28]),
+        (StateAllocationKey::CoreRuntimeAcl, vec![31]),
+        (StateAllocationKey::CoreRuntimeSessions, vec![32]),
         (
             StateAllocationKey::CoreRuntimeObservability,
             vec![29, 30, 34, 35],
         ),
         (StateAllocationKey::CoreIcpRefillRecords, vec![33]),
+        (StateAllocationKey::CoreEventBus, vec![66, 67, 68, 69]),
+        (StateAllocationKey::CoreBlueGreenDeployments, vec![36]),
         (
             StateAllocationKey::CoreRuntimeIntent,
             vec![39, 40, 41, 42, 43, 44, 45, 46, 47],
@@ -380,8 +388,8 @@ fn surplus_state_feature_allocates_normally() {
     assert_eq!(
         allocation_ids(&contract.allocations),
         vec![
-            11, 12, 13, 15, 16, 18, 20, 21, 29, 30, 34, 35, 39, 40, 41, 42, 43, 44, 45, 46, 47, 62,
-            63, 64, 65,
+            11, 12, 13, 15, 16, 18, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 34, 35, 39,
+            40, 41, 42, 43, 44, 45, 46, 47, 62, 63, 64, 65,
         ]
     );
 }
@@ -418,8 +426,8 @@ fn repeated_selection_merges_allocation_provenance() {
     assert_eq!(
         allocation_ids(&contract.allocations),
         vec![
-            11, 12, 13, 15, 16, 18, 19, 20, 21, 29, 30, 33, 34, 35, 39, 40, 41, 42, 43, 44, 45, 46,
-            47, 49, 80, 81, 82, 83, 84,
+            11, 12, 13, 15, 16, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34,
+            35, 36, 39, 40, 41, 42, 43, 44, 45, 46, 47, 49, 66, 67, 68, 69, 80, 81, 82, 83, 84,
         ]
     );
 }
@@ -438,8 +446,8 @@ fn built_in_wasm_store_keeps_template_and_gc_ids() {
     assert_eq!(
         allocation_ids(&contract.allocations),
         vec![
-            11, 12, 13, 15, 16, 18, 20, 21, 29, 30, 34, 35, 39, 40, 41, 42, 43, 44, 45, 46, 47, 80,
-            81, 82, 83, 85,
+            11, 12, 13, 15, 16, 18, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 34, 35, 39,
+            40, 41, 42, 43, 44, 45, 46, 47, 80, 81, 82, 83, 85,
         ]
     );
     assert_eq!(
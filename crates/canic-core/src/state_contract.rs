@@ -10,27 +10,38 @@
 use serde::Serialize;
 
 use crate::role_contract::allocation::memory::{
+    acl::ACL_ENTRIES_ID,
     activation::FLEET_ACTIVATION_ID,
     auth::{AUTH_STATE_ID, REPLAY_RECEIPTS_ID},
     blob_storage::{
         BLOB_DELETION_PENDING_ID, BLOB_STORAGE_BILLING_ID, STORAGE_GATEWAY_PRINCIPALS_ID,
         STORED_BLOBS_ID,
     },
+    blue_green::BLUE_GREEN_DEPLOYMENTS_ID,
     env::{ENV_ID, FLEET_STATE_ID},
+    event_bus::{
+        EVENT_BUS_DEAD_LETTERS_ID, EVENT_BUS_DELIVERIES_ID, EVENT_BUS_META_ID,
+        EVENT_BUS_SUBSCRIPTIONS_ID,
+    },
+    fsm::{FSM_INSTANCES_ID, FSM_META_ID},
     intent::{
         APPLICATION_RECEIPT_ELIGIBILITY_ID, APPLICATION_RECEIPT_REPLAY_ID, INTENT_EXPIRY_INDEX_ID,
         INTENT_META_ID, INTENT_PENDING_ID, INTENT_RECORDS_ID, INTENT_TOTALS_ID,
         PLACEMENT_ACKNOWLEDGEMENT_INDEX_ID, RECEIPT_BACKED_INTENT_RECORDS_ID,
     },
+    migration::MAP_MIGRATION_CURSORS_ID,
     observability::{
         CYCLE_TOPUP_EVENTS_ID, CYCLE_TRACKER_ID, CYCLES_FUNDING_LEDGER_ID, ICP_REFILL_RECORDS_ID,
         LOG_ENTRIES_ID,
     },
+    outbox::{OUTBOX_ENTRIES_ID, OUTBOX_FAILED_ID, OUTBOX_META_ID},
     placement::{
         DIRECTORY_REGISTRY_ID, SCALING_REGISTRY_ID, SHARDING_ACTIVE_SET_ID, SHARDING_ASSIGNMENT_ID,
         SHARDING_REGISTRY_ID,
     },
     pool::CANISTER_POOL_ID,
+    query_cache::QUERY_CACHE_SPILL_ID,
+    session::SESSIONS_ID,
     topology::{APP_INDEX_ID, CANISTER_CHILDREN_ID, SUBNET_INDEX_ID, SUBNET_REGISTRY_ID},
 };
 use crate::role_contract::{AllocationOwner, StateAllocationKey};
@@ -168,6 +179,18 @@ pub struct ReservedMemoryManifest {
     pub reason: String,
 }
 
+///
+/// StoreIndexManifest
+///
+/// Declared secondary index kept in step with one primary state domain.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct StoreIndexManifest {
+    pub domain: String,
+    pub index_domain: String,
+}
+
 ///
 /// StateAllocationDescriptor
 ///
@@ -191,6 +214,14 @@ pub fn canic_state_descriptors() -> Vec<StateAllocationDescriptor> {
     descriptors
 }
 
+#[must_use]
+pub fn canic_store_indexes() -> Vec<StoreIndexManifest> {
+    vec![
+        store_index("intent_records", "intent_expiry_index"),
+        store_index("intent_records", "placement_acknowledgement_index"),
+    ]
+}
+
 fn core_runtime_descriptors() -> Vec<StateAllocationDescriptor> {
     vec![
         descriptor(
@@ -208,6 +239,11 @@ fn core_runtime_descriptors() -> Vec<StateAllocationDescriptor> {
             fleet_activation_domains(),
             Vec::new(),
         ),
+        descriptor(
+            StateAllocationKey::CoreMapMigrations,
+            map_migration_domains(),
+            Vec::new(),
+        ),
         descriptor(
             StateAllocationKey::CoreAuthState,
             auth_state_domains(),
@@ -228,11 +264,46 @@ fn core_runtime_descriptors() -> Vec<StateAllocationDescriptor> {
             icp_refill_domains(),
             Vec::new(),
         ),
+        descriptor(
+            StateAllocationKey::CoreEventBus,
+            event_bus_domains(),
+            Vec::new(),
+        ),
+        descriptor(
+            StateAllocationKey::CoreBlueGreenDeployments,
+            blue_green_domains(),
+            Vec::new(),
+        ),
         descriptor(
             StateAllocationKey::CoreRuntimeIntent,
             runtime_intent_domains(),
             Vec::new(),
         ),
+        descriptor(
+            StateAllocationKey::CoreRuntimeOutbox,
+            runtime_outbox_domains(),
+            Vec::new(),
+        ),
+        descriptor(
+            StateAllocationKey::CoreRuntimeFsm,
+            runtime_fsm_domains(),
+            Vec::new(),
+        ),
+        descriptor(
+            StateAllocationKey::CoreRuntimeQueryCache,
+            runtime_query_cache_domains(),
+            Vec::new(),
+        ),
+        descriptor(
+            StateAllocationKey::CoreRuntimeAcl,
+            runtime_acl_domains(),
+            Vec::new(),
+        ),
+        descriptor(
+            StateAllocationKey::CoreRuntimeSessions,
+            runtime_session_domains(),
+            Vec::new(),
+        ),
     ]
 }
 
@@ -514,6 +585,19 @@ fn fleet_activation_domains() -> Vec<StateDomainManifest> {
     )]
 }
 
+fn map_migration_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::migration::{MapMigrationCursorRecord, MapMigrationCursorsData};
+
+    vec![state_domain(
+        "map_migration_cursors",
+        MAP_MIGRATION_CURSORS_ID,
+        MapMigrationCursorRecord::STATE_CONTRACT_NAME,
+        MapMigrationCursorsData::STATE_CONTRACT_NAME,
+        57,
+        "map_migration_cursors_resume_after_last_migrated_key",
+    )]
+}
+
 fn runtime_observability_domains() -> Vec<StateDomainManifest> {
     use crate::storage::stable::cycles::{
         CycleTopupEventRecord, CycleTopupEventsData, CycleTrackerData, CycleTrackerEntryRecord,
@@ -570,6 +654,158 @@ fn icp_refill_domains() -> Vec<StateDomainManifest> {
     )]
 }
 
+fn blue_green_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::blue_green::{BlueGreenData, BlueGreenRecord};
+
+    vec![state_domain(
+        "blue_green_deployments",
+        BLUE_GREEN_DEPLOYMENTS_ID,
+        BlueGreenRecord::STATE_CONTRACT_NAME,
+        BlueGreenData::STATE_CONTRACT_NAME,
+        104,
+        "blue_green_deployments_are_keyed_by_role",
+    )]
+}
+
+fn event_bus_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::event_bus::{
+        EventBusMetaData, EventBusMetaRecord, EventDeadLettersData, EventDeliveriesData,
+        EventDeliveryRecord, EventSubscriptionRecord, EventSubscriptionsData,
+    };
+
+    vec![
+        state_domain(
+            "event_bus_meta",
+            EVENT_BUS_META_ID,
+            EventBusMetaRecord::STATE_CONTRACT_NAME,
+            EventBusMetaData::STATE_CONTRACT_NAME,
+            105,
+            "event_bus_meta_restores_monotonic_sequences",
+        ),
+        state_domain(
+            "event_bus_subscriptions",
+            EVENT_BUS_SUBSCRIPTIONS_ID,
+            EventSubscriptionRecord::STATE_CONTRACT_NAME,
+            EventSubscriptionsData::STATE_CONTRACT_NAME,
+            106,
+            "event_bus_subscriptions_restore_topic_callbacks",
+        ),
+        state_domain(
+            "event_bus_deliveries",
+            EVENT_BUS_DELIVERIES_ID,
+            EventDeliveryRecord::STATE_CONTRACT_NAME,
+            EventDeliveriesData::STATE_CONTRACT_NAME,
+            107,
+            "event_bus_deliveries_restore_due_order",
+        ),
+        state_domain(
+            "event_bus_dead_letters",
+            EVENT_BUS_DEAD_LETTERS_ID,
+            EventDeliveryRecord::STATE_CONTRACT_NAME,
+            EventDeadLettersData::STATE_CONTRACT_NAME,
+            108,
+            "event_bus_dead_letters_restore_attempt_history",
+        ),
+    ]
+}
+
+fn runtime_outbox_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::outbox::{
+        OutboxEntriesData, OutboxEntryRecord, OutboxFailedData, OutboxMetaData, OutboxMetaRecord,
+    };
+
+    vec![
+        state_domain(
+            "outbox_meta",
+            OUTBOX_META_ID,
+            OutboxMetaRecord::STATE_CONTRACT_NAME,
+            OutboxMetaData::STATE_CONTRACT_NAME,
+            95,
+            "outbox_meta_restores_monotonic_sequence",
+        ),
+        state_domain(
+            "outbox_entries",
+            OUTBOX_ENTRIES_ID,
+            OutboxEntryRecord::STATE_CONTRACT_NAME,
+            OutboxEntriesData::STATE_CONTRACT_NAME,
+            96,
+            "outbox_entries_restore_due_order",
+        ),
+        state_domain(
+            "outbox_failed",
+            OUTBOX_FAILED_ID,
+            OutboxEntryRecord::STATE_CONTRACT_NAME,
+            OutboxFailedData::STATE_CONTRACT_NAME,
+            97,
+            "outbox_failed_entries_restore_attempt_history",
+        ),
+    ]
+}
+
+fn runtime_fsm_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::fsm::{
+        FsmInstanceRecord, FsmInstancesData, FsmMetaData, FsmMetaRecord,
+    };
+
+    vec![
+        state_domain(
+            "fsm_meta",
+            FSM_META_ID,
+            FsmMetaRecord::STATE_CONTRACT_NAME,
+            FsmMetaData::STATE_CONTRACT_NAME,
+            98,
+            "fsm_meta_restores_monotonic_sequence",
+        ),
+        state_domain(
+            "fsm_instances",
+            FSM_INSTANCES_ID,
+            FsmInstanceRecord::STATE_CONTRACT_NAME,
+            FsmInstancesData::STATE_CONTRACT_NAME,
+            99,
+            "fsm_instances_resume_from_persisted_state",
+        ),
+    ]
+}
+
+fn runtime_query_cache_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::query_cache::{QueryCacheEntryRecord, QueryCacheSpillData};
+
+    vec![state_domain(
+        "query_cache_spill",
+        QUERY_CACHE_SPILL_ID,
+        QueryCacheEntryRecord::STATE_CONTRACT_NAME,
+        QueryCacheSpillData::STATE_CONTRACT_NAME,
+        101,
+        "query_cache_spill_serves_only_unexpired_entries",
+    )]
+}
+
+fn runtime_acl_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::acl::{AclData, AclRecord};
+
+    vec![state_domain(
+        "acl_entries",
+        ACL_ENTRIES_ID,
+        AclRecord::STATE_CONTRACT_NAME,
+        AclData::STATE_CONTRACT_NAME,
+        102,
+        "acl_entries_hold_only_principals_with_roles",
+    )]
+}
+
+fn runtime_session_domains() -> Vec<StateDomainManifest> {
+    use crate::storage::stable::session::{SessionData, SessionRecord};
+
+    vec![state_domain(
+        "sessions",
+        SESSIONS_ID,
+        SessionRecord::STATE_CONTRACT_NAME,
+        SessionData::STATE_CONTRACT_NAME,
+        103,
+        "sessions_are_keyed_by_verified_token_subject",
+    )]
+}
+
 fn runtime_intent_domains() -> Vec<StateDomainManifest> {
     use crate::storage::stable::intent::{
         ApplicationReceiptEligibilityData, ApplicationReceiptEligibilityRecord,
@@ -680,10 +916,35 @@ fn state_domain(
     }
 }
 
+fn store_index(domain: &str, index_domain: &str) -> StoreIndexManifest {
+    StoreIndexManifest {
+        domain: domain.to_string(),
+        index_domain: index_domain.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn store_indexes_reference_declared_domains() {
+        let domains: Vec<String> = canic_state_descriptors()
+            .into_iter()
+            .flat_map(|descriptor| descriptor.state)
+            .map(|domain| domain.domain)
+            .collect();
+
+        for link in canic_store_indexes() {
+            assert!(domains.contains(&link.domain), "{} undeclared", link.domain);
+            assert!(
+                domains.contains(&link.index_domain),
+                "{} undeclared",
+                link.index_domain
+            );
+        }
+    }
+
     #[test]
     fn descriptors_use_unique_memory_ids() {
         let descriptors = canic_state_descriptors();
@@ -746,10 +1007,24 @@ mod tests {
             AUTH_STATE_ID,
             REPLAY_RECEIPTS_ID,
             FLEET_ACTIVATION_ID,
+            MAP_MIGRATION_CURSORS_ID,
             CYCLE_TOPUP_EVENTS_ID,
             LOG_ENTRIES_ID,
             ICP_REFILL_RECORDS_ID,
             CYCLES_FUNDING_LEDGER_ID,
+            EVENT_BUS_META_ID,
+            EVENT_BUS_SUBSCRIPTIONS_ID,
+            EVENT_BUS_DELIVERIES_ID,
+            EVENT_BUS_DEAD_LETTERS_ID,
+            BLUE_GREEN_DEPLOYMENTS_ID,
+            OUTBOX_META_ID,
+            OUTBOX_ENTRIES_ID,
+            OUTBOX_FAILED_ID,
+            FSM_META_ID,
+            FSM_INSTANCES_ID,
+            QUERY_CACHE_SPILL_ID,
+            ACL_ENTRIES_ID,
+            SESSIONS_ID,
             INTENT_META_ID,
             INTENT_RECORDS_ID,
             INTENT_TOTALS_ID,
@@ -1051,6 +1326,52 @@ mod tests {
         assert!(runtime_intents.reserved_memory.is_empty());
     }
 
+    #[test]
+    fn event_bus_descriptors_reference_canonical_data_types() {
+        use crate::storage::stable::event_bus::{
+            EventBusMetaData, EventBusMetaRecord, EventDeadLettersData, EventDeliveriesData,
+            EventDeliveryRecord, EventSubscriptionRecord, EventSubscriptionsData,
+        };
+
+        let descriptors = canic_state_descriptors();
+        let event_bus = descriptors
+            .iter()
+            .find(|descriptor| descriptor.allocation == StateAllocationKey::CoreEventBus)
+            .expect("event bus descriptor");
+
+        for (domain, record, snapshot) in [
+            (
+                "event_bus_meta",
+                EventBusMetaRecord::STATE_CONTRACT_NAME,
+                EventBusMetaData::STATE_CONTRACT_NAME,
+            ),
+            (
+                "event_bus_subscriptions",
+                EventSubscriptionRecord::STATE_CONTRACT_NAME,
+                EventSubscriptionsData::STATE_CONTRACT_NAME,
+            ),
+            (
+                "event_bus_deliveries",
+                EventDeliveryRecord::STATE_CONTRACT_NAME,
+                EventDeliveriesData::STATE_CONTRACT_NAME,
+            ),
+            (
+                "event_bus_dead_letters",
+                EventDeliveryRecord::STATE_CONTRACT_NAME,
+                EventDeadLettersData::STATE_CONTRACT_NAME,
+            ),
+        ] {
+            let declaration = event_bus
+                .state
+                .iter()
+                .find(|declaration| declaration.domain == domain)
+                .expect("event bus state declaration");
+
+            assert_eq!(declaration.record, record);
+            assert_eq!(declaration.snapshot, snapshot);
+        }
+    }
+
     #[test]
     fn placement_descriptors_reference_canonical_data_types() {
         use crate::storage::stable::{
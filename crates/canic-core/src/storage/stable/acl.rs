@@ -0,0 +1,96 @@
+//! Module: storage::stable::acl
+//!
+//! Responsibility: define the stable principal-to-roles access control list.
+//! Does not own: role-name validation, grant limits, or endpoint access evaluation.
+//! Boundary: ACL storage ops wrap these records for the ACL workflow and auth predicates.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, memory::VirtualMemory},
+    role_contract::allocation::memory::acl::ACL_ENTRIES_ID,
+    storage::prelude::*,
+};
+use std::cell::RefCell;
+
+eager_static! {
+    static ACL_ENTRIES: RefCell<
+        StableBtreeMap<Principal, AclRecord, VirtualMemory<DefaultMemoryImpl>>
+    > = RefCell::new(
+        StableBtreeMap::init(crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.acl.v1", ty = AclRecord, id = ACL_ENTRIES_ID)),
+    );
+}
+
+///
+/// AclRecord
+///
+/// Sorted, deduplicated roles granted to one principal.
+///
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AclRecord {
+    pub roles: Vec<String>,
+}
+
+impl AclRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "AclRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 4 * 1024;
+}
+
+impl_storable_bounded!(AclRecord, AclRecord::STORABLE_MAX_SIZE, false);
+
+///
+/// AclData
+///
+/// Canonical access control list snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AclData {
+    pub entries: Vec<(Principal, AclRecord)>,
+}
+
+impl AclData {
+    pub const STATE_CONTRACT_NAME: &'static str = "AclData";
+}
+
+///
+/// AclStore
+///
+/// Stable facade for the access control list allocation.
+/// Owned by stable storage and wrapped by ACL storage ops.
+///
+
+pub struct AclStore;
+
+impl AclStore {
+    #[must_use]
+    pub(crate) fn get(principal: Principal) -> Option<AclRecord> {
+        ACL_ENTRIES.with_borrow(|map| map.get(&principal))
+    }
+
+    pub(crate) fn insert(principal: Principal, record: AclRecord) {
+        ACL_ENTRIES.with_borrow_mut(|map| {
+            map.insert(principal, record);
+        });
+    }
+
+    pub(crate) fn remove(principal: Principal) -> Option<AclRecord> {
+        ACL_ENTRIES.with_borrow_mut(|map| map.remove(&principal))
+    }
+
+    #[must_use]
+    pub(crate) fn export() -> AclData {
+        AclData {
+            entries: ACL_ENTRIES.with_borrow(|map| {
+                map.iter()
+                    .map(|entry| (*entry.key(), entry.value()))
+                    .collect()
+            }),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear_for_tests() {
+        ACL_ENTRIES.with_borrow_mut(StableBtreeMap::clear_new);
+    }
+}
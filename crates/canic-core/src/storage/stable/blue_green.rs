@@ -0,0 +1,116 @@
+//! Module: storage::stable::blue_green
+//!
+//! Responsibility: define the stable blue/green deployment records kept by root.
+//! Does not own: smoke checks, routing decisions, or canister provisioning.
+//! Boundary: blue/green storage ops wrap these records for index rebuilds and the deployment workflow.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, memory::VirtualMemory},
+    impl_storable_unbounded,
+    role_contract::allocation::memory::blue_green::BLUE_GREEN_DEPLOYMENTS_ID,
+    storage::prelude::*,
+};
+use std::cell::RefCell;
+
+eager_static! {
+    static BLUE_GREEN_DEPLOYMENTS: RefCell<
+        StableBtreeMap<CanisterRole, BlueGreenRecord, VirtualMemory<DefaultMemoryImpl>>
+    > = RefCell::new(
+        StableBtreeMap::init(crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.blue_green.v1", ty = BlueGreenRecord, id = BLUE_GREEN_DEPLOYMENTS_ID)),
+    );
+}
+
+///
+/// BlueGreenPhaseRecord
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum BlueGreenPhaseRecord {
+    Provisioning,
+    SmokeChecking,
+    Draining,
+}
+
+///
+/// BlueGreenRecord
+///
+/// In-flight blue/green deployment of one role: the old (blue) instance,
+/// the new (green) one, and which of them the role's routing entry points at.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BlueGreenRecord {
+    pub blue_pid: Principal,
+    pub green_pid: Option<Principal>,
+    pub routed_pid: Principal,
+    pub phase: BlueGreenPhaseRecord,
+    pub smoke_check_method: String,
+    pub grace_period_secs: u64,
+    pub started_at_ns: u64,
+    pub flipped_at_ns: Option<u64>,
+    pub workflow_id: u64,
+}
+
+impl BlueGreenRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "BlueGreenRecord";
+}
+
+impl_storable_unbounded!(BlueGreenRecord);
+
+///
+/// BlueGreenData
+///
+/// Canonical blue/green allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BlueGreenData {
+    pub entries: Vec<(CanisterRole, BlueGreenRecord)>,
+}
+
+impl BlueGreenData {
+    pub const STATE_CONTRACT_NAME: &'static str = "BlueGreenData";
+}
+
+///
+/// BlueGreenStore
+///
+/// Stable facade for the blue/green allocation.
+/// Owned by stable storage and wrapped by blue/green storage ops.
+///
+
+pub struct BlueGreenStore;
+
+impl BlueGreenStore {
+    #[must_use]
+    pub(crate) fn get(role: &CanisterRole) -> Option<BlueGreenRecord> {
+        BLUE_GREEN_DEPLOYMENTS.with_borrow(|map| map.get(role))
+    }
+
+    pub(crate) fn insert(role: CanisterRole, record: BlueGreenRecord) {
+        BLUE_GREEN_DEPLOYMENTS.with_borrow_mut(|map| {
+            map.insert(role, record);
+        });
+    }
+
+    pub(crate) fn remove(role: &CanisterRole) -> Option<BlueGreenRecord> {
+        BLUE_GREEN_DEPLOYMENTS.with_borrow_mut(|map| map.remove(role))
+    }
+
+    #[must_use]
+    pub(crate) fn export() -> BlueGreenData {
+        BlueGreenData {
+            entries: BLUE_GREEN_DEPLOYMENTS.with_borrow(|map| {
+                map.iter()
+                    .map(|entry| (entry.key().clone(), entry.value()))
+                    .collect()
+            }),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear_for_tests() {
+        BLUE_GREEN_DEPLOYMENTS.with_borrow_mut(StableBtreeMap::clear_new);
+    }
+}
@@ -0,0 +1,342 @@
+//! Module: storage::stable::event_bus
+//!
+//! Responsibility: define stable-memory schemas for root event-bus subscriptions and queues.
+//! Does not own: topic validation, retry policy, delivery calls, or DTO projection.
+//! Boundary: event-bus storage ops wrap these records for the delivery workflow.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, cell::Cell, memory::VirtualMemory},
+    impl_storable_bounded,
+    role_contract::allocation::memory::event_bus::{
+        EVENT_BUS_DEAD_LETTERS_ID, EVENT_BUS_DELIVERIES_ID, EVENT_BUS_META_ID,
+        EVENT_BUS_SUBSCRIPTIONS_ID,
+    },
+    storage::prelude::*,
+};
+use std::cell::RefCell;
+
+type EventBusMemory = VirtualMemory<DefaultMemoryImpl>;
+
+thread_local! {
+    //
+    // EVENT_BUS_*
+    //
+    // Root lifecycle restoration initializes this root-owned state explicitly.
+    // Keeping it lazy prevents non-root canisters from opening memory IDs 66-69.
+    static EVENT_BUS_META: RefCell<Cell<EventBusMetaRecord, EventBusMemory>> =
+        RefCell::new(Cell::init(
+            crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.event_bus_meta.v1", ty = EventBusMetaRecord, id = EVENT_BUS_META_ID),
+            EventBusMetaRecord::default(),
+        ));
+
+    static EVENT_BUS_SUBSCRIPTIONS: RefCell<
+        StableBtreeMap<EventSubscriptionKeyRecord, EventSubscriptionRecord, EventBusMemory>
+    > = RefCell::new(StableBtreeMap::init(
+        crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.event_bus_subscriptions.v1", ty = EventSubscriptionRecord, id = EVENT_BUS_SUBSCRIPTIONS_ID),
+    ));
+
+    static EVENT_BUS_DELIVERIES: RefCell<
+        StableBtreeMap<EventDeliveryKeyRecord, EventDeliveryRecord, EventBusMemory>
+    > = RefCell::new(StableBtreeMap::init(
+        crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.event_bus_deliveries.v1", ty = EventDeliveryRecord, id = EVENT_BUS_DELIVERIES_ID),
+    ));
+
+    static EVENT_BUS_DEAD_LETTERS: RefCell<
+        StableBtreeMap<u64, EventDeliveryRecord, EventBusMemory>
+    > = RefCell::new(StableBtreeMap::init(
+        crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.event_bus_dead_letters.v1", ty = EventDeliveryRecord, id = EVENT_BUS_DEAD_LETTERS_ID),
+    ));
+}
+
+///
+/// EventBusMetaRecord
+///
+/// Monotonic event and delivery sequence counters.
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EventBusMetaRecord {
+    pub next_event_id: u64,
+    pub next_delivery_id: u64,
+}
+
+impl EventBusMetaRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "EventBusMetaRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 64;
+}
+
+impl Default for EventBusMetaRecord {
+    fn default() -> Self {
+        Self {
+            next_event_id: 1,
+            next_delivery_id: 1,
+        }
+    }
+}
+
+impl_storable_bounded!(
+    EventBusMetaRecord,
+    EventBusMetaRecord::STORABLE_MAX_SIZE,
+    false
+);
+
+///
+/// EventSubscriptionKeyRecord
+///
+/// Topic-major key so one range scan yields every subscriber of a topic.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct EventSubscriptionKeyRecord {
+    pub topic: String,
+    pub subscriber: Principal,
+}
+
+impl_storable_bounded!(EventSubscriptionKeyRecord, 256, false);
+
+///
+/// EventSubscriptionRecord
+///
+/// Callback endpoint registered by one subscriber for one topic.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EventSubscriptionRecord {
+    pub method: String,
+    pub subscribed_at_ns: u64,
+}
+
+impl EventSubscriptionRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "EventSubscriptionRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 160;
+}
+
+impl_storable_bounded!(
+    EventSubscriptionRecord,
+    EventSubscriptionRecord::STORABLE_MAX_SIZE,
+    false
+);
+
+///
+/// EventDeliveryKeyRecord
+///
+/// Due-time-major key so the delivery worker reads the next due entries first.
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct EventDeliveryKeyRecord {
+    pub due_at_ns: u64,
+    pub delivery_id: u64,
+}
+
+impl_storable_bounded!(EventDeliveryKeyRecord, 64, false);
+
+///
+/// EventDeliveryRecord
+///
+/// One event fanned out to one subscriber, pending or dead-lettered.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct EventDeliveryRecord {
+    pub delivery_id: u64,
+    pub event_id: u64,
+    pub topic: String,
+    pub publisher: Principal,
+    pub published_at_ns: u64,
+    pub subscriber: Principal,
+    pub method: String,
+    #[serde(with = "serde_bytes")]
+    pub payload: Vec<u8>,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+impl EventDeliveryRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "EventDeliveryRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 20 * 1024;
+}
+
+impl_storable_bounded!(
+    EventDeliveryRecord,
+    EventDeliveryRecord::STORABLE_MAX_SIZE,
+    false
+);
+
+///
+/// EventBusMetaData
+///
+/// Canonical event-bus counter snapshot.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventBusMetaData {
+    pub record: EventBusMetaRecord,
+}
+
+impl EventBusMetaData {
+    pub const STATE_CONTRACT_NAME: &'static str = "EventBusMetaData";
+}
+
+///
+/// EventSubscriptionsData
+///
+/// Canonical subscription allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventSubscriptionsData {
+    pub entries: Vec<(EventSubscriptionKeyRecord, EventSubscriptionRecord)>,
+}
+
+impl EventSubscriptionsData {
+    pub const STATE_CONTRACT_NAME: &'static str = "EventSubscriptionsData";
+}
+
+///
+/// EventDeliveriesData
+///
+/// Canonical pending-delivery allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventDeliveriesData {
+    pub entries: Vec<(EventDeliveryKeyRecord, EventDeliveryRecord)>,
+}
+
+impl EventDeliveriesData {
+    pub const STATE_CONTRACT_NAME: &'static str = "EventDeliveriesData";
+}
+
+///
+/// EventDeadLettersData
+///
+/// Canonical dead-letter allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EventDeadLettersData {
+    pub entries: Vec<EventDeliveryRecord>,
+}
+
+impl EventDeadLettersData {
+    pub const STATE_CONTRACT_NAME: &'static str = "EventDeadLettersData";
+}
+
+///
+/// EventBusStore
+///
+/// Stable facade for the root event-bus allocation.
+/// Owned by stable storage and wrapped by event-bus storage ops.
+///
+
+pub struct EventBusStore;
+
+impl EventBusStore {
+    #[must_use]
+    pub(crate) fn meta() -> EventBusMetaRecord {
+        EVENT_BUS_META.with_borrow(|cell| *cell.get())
+    }
+
+    pub(crate) fn set_meta(record: EventBusMetaRecord) {
+        EVENT_BUS_META.with_borrow_mut(|cell| {
+            cell.set(record);
+        });
+    }
+
+    pub(crate) fn insert_subscription(
+        key: EventSubscriptionKeyRecord,
+        record: EventSubscriptionRecord,
+    ) -> Option<EventSubscriptionRecord> {
+        EVENT_BUS_SUBSCRIPTIONS.with_borrow_mut(|map| map.insert(key, record))
+    }
+
+    pub(crate) fn remove_subscription(
+        key: &EventSubscriptionKeyRecord,
+    ) -> Option<EventSubscriptionRecord> {
+        EVENT_BUS_SUBSCRIPTIONS.with_borrow_mut(|map| map.remove(key))
+    }
+
+    /// Return every subscription registered for `topic`, in subscriber order.
+    #[must_use]
+    pub(crate) fn topic_subscriptions(topic: &str) -> Vec<(Principal, EventSubscriptionRecord)> {
+        let start = EventSubscriptionKeyRecord {
+            topic: topic.to_string(),
+            subscriber: Principal::management_canister(),
+        };
+
+        EVENT_BUS_SUBSCRIPTIONS.with_borrow(|map| {
+            map.range(start..)
+                .take_while(|entry| entry.key().topic == topic)
+                .map(|entry| (entry.key().subscriber, entry.value()))
+                .collect()
+        })
+    }
+
+    pub(crate) fn insert_delivery(key: EventDeliveryKeyRecord, record: EventDeliveryRecord) {
+        EVENT_BUS_DELIVERIES.with_borrow_mut(|map| {
+            map.insert(key, record);
+        });
+    }
+
+    pub(crate) fn remove_delivery(key: &EventDeliveryKeyRecord) -> Option<EventDeliveryRecord> {
+        EVENT_BUS_DELIVERIES.with_borrow_mut(|map| map.remove(key))
+    }
+
+    /// Return up to `limit` deliveries due at or before `now_ns`, oldest first.
+    #[must_use]
+    pub(crate) fn due_deliveries(
+        now_ns: u64,
+        limit: usize,
+    ) -> Vec<(EventDeliveryKeyRecord, EventDeliveryRecord)> {
+        EVENT_BUS_DELIVERIES.with_borrow(|map| {
+            map.iter()
+                .take_while(|entry| entry.key().due_at_ns <= now_ns)
+                .take(limit)
+                .map(|entry| (*entry.key(), entry.value()))
+                .collect()
+        })
+    }
+
+    #[must_use]
+    pub(crate) fn next_due_at_ns() -> Option<u64> {
+        EVENT_BUS_DELIVERIES.with_borrow(|map| map.first_key_value().map(|(key, _)| key.due_at_ns))
+    }
+
+    pub(crate) fn insert_dead_letter(record: EventDeliveryRecord) {
+        EVENT_BUS_DEAD_LETTERS.with_borrow_mut(|map| {
+            map.insert(record.delivery_id, record);
+        });
+    }
+
+    pub(crate) fn remove_dead_letter(delivery_id: u64) -> Option<EventDeliveryRecord> {
+        EVENT_BUS_DEAD_LETTERS.with_borrow_mut(|map| map.remove(&delivery_id))
+    }
+
+    #[must_use]
+    pub(crate) fn dead_letters(offset: usize, limit: usize) -> Vec<EventDeliveryRecord> {
+        EVENT_BUS_DEAD_LETTERS.with_borrow(|map| {
+            map.iter()
+                .skip(offset)
+                .take(limit)
+                .map(|entry| entry.value())
+                .collect()
+        })
+    }
+
+    #[must_use]
+    pub(crate) fn dead_letter_count() -> u64 {
+        EVENT_BUS_DEAD_LETTERS.with_borrow(StableBtreeMap::len)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear_for_tests() {
+        EVENT_BUS_META.with_borrow_mut(|cell| {
+            cell.set(EventBusMetaRecord::default());
+        });
+        EVENT_BUS_SUBSCRIPTIONS.with_borrow_mut(StableBtreeMap::clear_new);
+        EVENT_BUS_DELIVERIES.with_borrow_mut(StableBtreeMap::clear_new);
+        EVENT_BUS_DEAD_LETTERS.with_borrow_mut(StableBtreeMap::clear_new);
+    }
+}
@@ -0,0 +1,183 @@
+//! Module: storage::stable::fsm
+//!
+//! Responsibility: define stable-memory schemas for persisted workflow state-machine instances.
+//! Does not own: transition rules, step execution, or DTO projection.
+//! Boundary: fsm storage ops wrap these records for the fsm workflow driver.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, cell::Cell, memory::VirtualMemory},
+    eager_static, impl_storable_bounded,
+    role_contract::allocation::memory::fsm::{FSM_INSTANCES_ID, FSM_META_ID},
+    storage::prelude::*,
+};
+use std::cell::RefCell;
+
+type FsmMemory = VirtualMemory<DefaultMemoryImpl>;
+
+eager_static! {
+    static FSM_META: RefCell<Cell<FsmMetaRecord, FsmMemory>> =
+        RefCell::new(Cell::init(
+            crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.fsm_meta.v1", ty = FsmMetaRecord, id = FSM_META_ID),
+            FsmMetaRecord::default(),
+        ));
+}
+
+eager_static! {
+    static FSM_INSTANCES: RefCell<StableBtreeMap<u64, FsmInstanceRecord, FsmMemory>> =
+        RefCell::new(StableBtreeMap::init(
+            crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.fsm_instances.v1", ty = FsmInstanceRecord, id = FSM_INSTANCES_ID),
+        ));
+}
+
+///
+/// FsmMetaRecord
+///
+/// Monotonic state-machine instance sequence.
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FsmMetaRecord {
+    pub next_instance_id: u64,
+}
+
+impl FsmMetaRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "FsmMetaRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 32;
+}
+
+impl Default for FsmMetaRecord {
+    fn default() -> Self {
+        Self {
+            next_instance_id: 1,
+        }
+    }
+}
+
+impl_storable_bounded!(FsmMetaRecord, FsmMetaRecord::STORABLE_MAX_SIZE, false);
+
+///
+/// FsmStatusRecord
+///
+/// Stable run status for one state-machine instance.
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[remain::sorted]
+pub enum FsmStatusRecord {
+    Completed,
+    Failed,
+    Running,
+}
+
+///
+/// FsmInstanceRecord
+///
+/// One state-machine instance: its kind, Candid-encoded current state, and run bookkeeping.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FsmInstanceRecord {
+    pub instance_id: u64,
+    pub kind: String,
+    pub state_label: String,
+    #[serde(with = "serde_bytes")]
+    pub state: Vec<u8>,
+    pub status: FsmStatusRecord,
+    pub attempts: u32,
+    pub next_run_at_ns: u64,
+    pub created_at_ns: u64,
+    pub updated_at_ns: u64,
+    pub last_error: Option<String>,
+}
+
+impl FsmInstanceRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "FsmInstanceRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 20 * 1024;
+}
+
+impl_storable_bounded!(
+    FsmInstanceRecord,
+    FsmInstanceRecord::STORABLE_MAX_SIZE,
+    false
+);
+
+///
+/// FsmMetaData
+///
+/// Canonical state-machine counter snapshot.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FsmMetaData {
+    pub record: FsmMetaRecord,
+}
+
+impl FsmMetaData {
+    pub const STATE_CONTRACT_NAME: &'static str = "FsmMetaData";
+}
+
+///
+/// FsmInstancesData
+///
+/// Canonical state-machine instance allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FsmInstancesData {
+    pub instances: Vec<FsmInstanceRecord>,
+}
+
+impl FsmInstancesData {
+    pub const STATE_CONTRACT_NAME: &'static str = "FsmInstancesData";
+}
+
+///
+/// FsmStore
+///
+/// Stable facade for the per-canister state-machine allocation.
+/// Owned by stable storage and wrapped by fsm storage ops.
+///
+
+pub struct FsmStore;
+
+impl FsmStore {
+    #[must_use]
+    pub(crate) fn meta() -> FsmMetaRecord {
+        FSM_META.with_borrow(|cell| *cell.get())
+    }
+
+    pub(crate) fn set_meta(record: FsmMetaRecord) {
+        FSM_META.with_borrow_mut(|cell| {
+            cell.set(record);
+        });
+    }
+
+    #[must_use]
+    pub(crate) fn get(instance_id: u64) -> Option<FsmInstanceRecord> {
+        FSM_INSTANCES.with_borrow(|map| map.get(&instance_id))
+    }
+
+    pub(crate) fn insert(record: FsmInstanceRecord) {
+        FSM_INSTANCES.with_borrow_mut(|map| {
+            map.insert(record.instance_id, record);
+        });
+    }
+
+    pub(crate) fn remove(instance_id: u64) -> Option<FsmInstanceRecord> {
+        FSM_INSTANCES.with_borrow_mut(|map| map.remove(&instance_id))
+    }
+
+    #[must_use]
+    pub(crate) fn instances() -> Vec<FsmInstanceRecord> {
+        FSM_INSTANCES.with_borrow(|map| map.iter().map(|entry| entry.value()).collect())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear_for_tests() {
+        FSM_META.with_borrow_mut(|cell| {
+            cell.set(FsmMetaRecord::default());
+        });
+        FSM_INSTANCES.with_borrow_mut(StableBtreeMap::clear_new);
+    }
+}
@@ -0,0 +1,128 @@
+//! Module: storage::stable::migration
+//!
+//! Responsibility: define stable-memory schemas for resumable map migration cursors.
+//! Does not own: migration batching, value conversion, or timer scheduling.
+//! Boundary: migration storage ops convert between these records and workflow progress.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, memory::VirtualMemory},
+    eager_static, impl_storable_unbounded,
+    role_contract::allocation::memory::migration::MAP_MIGRATION_CURSORS_ID,
+    storage::prelude::*,
+};
+use std::cell::RefCell;
+
+eager_static! {
+    static MAP_MIGRATION_CURSORS: RefCell<
+        StableBtreeMap<u8, MapMigrationCursorRecord, VirtualMemory<DefaultMemoryImpl>>
+    > = RefCell::new(
+        StableBtreeMap::init(crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.map_migration_cursors.v1", ty = MapMigrationCursorStore, id = MAP_MIGRATION_CURSORS_ID)),
+    );
+}
+
+///
+/// MapMigrationCursorRecord
+///
+/// Durable progress for one stable map migration, keyed by the map's memory ID.
+/// `last_key` holds the encoded key of the last entry already rewritten.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct MapMigrationCursorRecord {
+    pub target: String,
+    pub last_key: Option<Vec<u8>>,
+    pub migrated: u64,
+    pub batches: u64,
+    pub completed: bool,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+impl MapMigrationCursorRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "MapMigrationCursorRecord";
+}
+
+impl_storable_unbounded!(MapMigrationCursorRecord);
+
+///
+/// MapMigrationCursorEntryRecord
+///
+/// One logical migration-cursor snapshot row.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MapMigrationCursorEntryRecord {
+    pub memory_id: u8,
+    pub cursor: MapMigrationCursorRecord,
+}
+
+///
+/// MapMigrationCursorsData
+///
+/// Canonical migration-cursor allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MapMigrationCursorsData {
+    pub entries: Vec<MapMigrationCursorEntryRecord>,
+}
+
+impl MapMigrationCursorsData {
+    pub const STATE_CONTRACT_NAME: &'static str = "MapMigrationCursorsData";
+}
+
+///
+/// MapMigrationCursorStore
+///
+/// Stable BTreeMap facade for map migration cursors.
+///
+
+pub struct MapMigrationCursorStore;
+
+impl MapMigrationCursorStore {
+    #[must_use]
+    pub(crate) fn get(memory_id: u8) -> Option<MapMigrationCursorRecord> {
+        MAP_MIGRATION_CURSORS.with_borrow(|map| map.get(&memory_id))
+    }
+
+    pub(crate) fn upsert(memory_id: u8, cursor: MapMigrationCursorRecord) {
+        MAP_MIGRATION_CURSORS.with_borrow_mut(|map| {
+            map.insert(memory_id, cursor);
+        });
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_upsert_replaces_progress_for_one_memory_id() {
+        let first = MapMigrationCursorRecord {
+            target: "v2".to_string(),
+            last_key: None,
+            migrated: 0,
+            batches: 0,
+            completed: false,
+            started_at: 10,
+            updated_at: 10,
+        };
+        MapMigrationCursorStore::upsert(200, first.clone());
+
+        let next = MapMigrationCursorRecord {
+            last_key: Some(vec![1, 2]),
+            migrated: 2,
+            batches: 1,
+            updated_at: 11,
+            ..first
+        };
+        MapMigrationCursorStore::upsert(200, next.clone());
+
+        assert_eq!(MapMigrationCursorStore::get(200), Some(next));
+    }
+}
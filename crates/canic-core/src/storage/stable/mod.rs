@@ -1,18 +1,26 @@
+pub mod acl;
 pub mod auth;
 pub mod blob_storage;
+pub mod blue_green;
 pub mod children;
 pub mod cycles;
 pub mod directory;
 pub mod env;
+pub mod event_bus;
 pub mod fleet_activation;
+pub mod fsm;
 pub mod icp_refill;
 pub mod index;
 pub mod intent;
 pub mod log;
+pub mod migration;
+pub mod outbox;
 pub mod pool;
+pub mod query_cache;
 pub mod registry;
 pub mod replay;
 pub mod scaling;
+pub mod session;
 pub mod sharding;
 pub mod state;
 
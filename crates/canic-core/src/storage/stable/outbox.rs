@@ -0,0 +1,235 @@
+//! Module: storage::stable::outbox
+//!
+//! Responsibility: define stable-memory schemas for the per-canister call outbox.
+//! Does not own: retry policy, call dispatch, or DTO projection.
+//! Boundary: outbox storage ops wrap these records for the outbox workflow.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, cell::Cell, memory::VirtualMemory},
+    eager_static, impl_storable_bounded,
+    role_contract::allocation::memory::outbox::{
+        OUTBOX_ENTRIES_ID, OUTBOX_FAILED_ID, OUTBOX_META_ID,
+    },
+    storage::prelude::*,
+};
+use std::cell::RefCell;
+
+type OutboxMemory = VirtualMemory<DefaultMemoryImpl>;
+
+eager_static! {
+    static OUTBOX_META: RefCell<Cell<OutboxMetaRecord, OutboxMemory>> =
+        RefCell::new(Cell::init(
+            crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.outbox_meta.v1", ty = OutboxMetaRecord, id = OUTBOX_META_ID),
+            OutboxMetaRecord::default(),
+        ));
+}
+
+eager_static! {
+    static OUTBOX_ENTRIES: RefCell<
+        StableBtreeMap<OutboxKeyRecord, OutboxEntryRecord, OutboxMemory>
+    > = RefCell::new(StableBtreeMap::init(
+        crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.outbox_entries.v1", ty = OutboxEntryRecord, id = OUTBOX_ENTRIES_ID),
+    ));
+}
+
+eager_static! {
+    static OUTBOX_FAILED: RefCell<StableBtreeMap<u64, OutboxEntryRecord, OutboxMemory>> =
+        RefCell::new(StableBtreeMap::init(
+            crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.outbox_failed.v1", ty = OutboxEntryRecord, id = OUTBOX_FAILED_ID),
+        ));
+}
+
+///
+/// OutboxMetaRecord
+///
+/// Monotonic outbox entry sequence.
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OutboxMetaRecord {
+    pub next_entry_id: u64,
+}
+
+impl OutboxMetaRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "OutboxMetaRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 32;
+}
+
+impl Default for OutboxMetaRecord {
+    fn default() -> Self {
+        Self { next_entry_id: 1 }
+    }
+}
+
+impl_storable_bounded!(OutboxMetaRecord, OutboxMetaRecord::STORABLE_MAX_SIZE, false);
+
+///
+/// OutboxKeyRecord
+///
+/// Due-time-major key so the dispatcher reads the next due entries first.
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct OutboxKeyRecord {
+    pub due_at_ns: u64,
+    pub entry_id: u64,
+}
+
+impl_storable_bounded!(OutboxKeyRecord, 64, false);
+
+///
+/// OutboxEntryRecord
+///
+/// One enqueued inter-canister call descriptor, pending or permanently failed.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OutboxEntryRecord {
+    pub entry_id: u64,
+    pub target: Principal,
+    pub method: String,
+    #[serde(with = "serde_bytes")]
+    pub args: Vec<u8>,
+    pub cycles: u128,
+    pub enqueued_at_ns: u64,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+impl OutboxEntryRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "OutboxEntryRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 20 * 1024;
+}
+
+impl_storable_bounded!(
+    OutboxEntryRecord,
+    OutboxEntryRecord::STORABLE_MAX_SIZE,
+    false
+);
+
+///
+/// OutboxMetaData
+///
+/// Canonical outbox counter snapshot.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutboxMetaData {
+    pub record: OutboxMetaRecord,
+}
+
+impl OutboxMetaData {
+    pub const STATE_CONTRACT_NAME: &'static str = "OutboxMetaData";
+}
+
+///
+/// OutboxEntriesData
+///
+/// Canonical pending-entry allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OutboxEntriesData {
+    pub entries: Vec<(OutboxKeyRecord, OutboxEntryRecord)>,
+}
+
+impl OutboxEntriesData {
+    pub const STATE_CONTRACT_NAME: &'static str = "OutboxEntriesData";
+}
+
+///
+/// OutboxFailedData
+///
+/// Canonical permanently-failed entry allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OutboxFailedData {
+    pub entries: Vec<OutboxEntryRecord>,
+}
+
+impl OutboxFailedData {
+    pub const STATE_CONTRACT_NAME: &'static str = "OutboxFailedData";
+}
+
+///
+/// OutboxStore
+///
+/// Stable facade for the per-canister outbox allocation.
+/// Owned by stable storage and wrapped by outbox storage ops.
+///
+
+pub struct OutboxStore;
+
+impl OutboxStore {
+    #[must_use]
+    pub(crate) fn meta() -> OutboxMetaRecord {
+        OUTBOX_META.with_borrow(|cell| *cell.get())
+    }
+
+    pub(crate) fn set_meta(record: OutboxMetaRecord) {
+        OUTBOX_META.with_borrow_mut(|cell| {
+            cell.set(record);
+        });
+    }
+
+    pub(crate) fn insert_entry(key: OutboxKeyRecord, record: OutboxEntryRecord) {
+        OUTBOX_ENTRIES.with_borrow_mut(|map| {
+            map.insert(key, record);
+        });
+    }
+
+    pub(crate) fn remove_entry(key: &OutboxKeyRecord) -> Option<OutboxEntryRecord> {
+        OUTBOX_ENTRIES.with_borrow_mut(|map| map.remove(key))
+    }
+
+    /// Return up to `limit` entries due at or before `now_ns`, oldest first.
+    #[must_use]
+    pub(crate) fn due_entries(
+        now_ns: u64,
+        limit: usize,
+    ) -> Vec<(OutboxKeyRecord, OutboxEntryRecord)> {
+        OUTBOX_ENTRIES.with_borrow(|map| {
+            map.iter()
+                .take_while(|entry| entry.key().due_at_ns <= now_ns)
+                .take(limit)
+                .map(|entry| (*entry.key(), entry.value()))
+                .collect()
+        })
+    }
+
+    #[must_use]
+    pub(crate) fn next_due_at_ns() -> Option<u64> {
+        OUTBOX_ENTRIES.with_borrow(|map| map.first_key_value().map(|(key, _)| key.due_at_ns))
+    }
+
+    #[must_use]
+    pub(crate) fn entry_count() -> u64 {
+        OUTBOX_ENTRIES.with_borrow(StableBtreeMap::len)
+    }
+
+    pub(crate) fn insert_failed(record: OutboxEntryRecord) {
+        OUTBOX_FAILED.with_borrow_mut(|map| {
+            map.insert(record.entry_id, record);
+        });
+    }
+
+    pub(crate) fn remove_failed(entry_id: u64) -> Option<OutboxEntryRecord> {
+        OUTBOX_FAILED.with_borrow_mut(|map| map.remove(&entry_id))
+    }
+
+    #[must_use]
+    pub(crate) fn failed_entries() -> Vec<OutboxEntryRecord> {
+        OUTBOX_FAILED.with_borrow(|map| map.iter().map(|entry| entry.value()).collect())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear_for_tests() {
+        OUTBOX_META.with_borrow_mut(|cell| {
+            cell.set(OutboxMetaRecord::default());
+        });
+        OUTBOX_ENTRIES.with_borrow_mut(StableBtreeMap::clear_new);
+        OUTBOX_FAILED.with_borrow_mut(StableBtreeMap::clear_new);
+    }
+}
@@ -0,0 +1,147 @@
+//! Module: storage::stable::query_cache
+//!
+//! Responsibility: define the stable-memory spillover tier for cached query responses.
+//! Does not own: cache keys, freshness decisions, or heap-tier eviction.
+//! Boundary: query-cache storage ops wrap these records for the query-cache workflow.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, Storable, memory::VirtualMemory, storable::Bound},
+    eager_static, impl_storable_bounded,
+    role_contract::allocation::memory::query_cache::QUERY_CACHE_SPILL_ID,
+    storage::prelude::*,
+};
+use std::{borrow::Cow, cell::RefCell};
+
+eager_static! {
+    static QUERY_CACHE_SPILL: RefCell<
+        StableBtreeMap<QueryCacheKey, QueryCacheEntryRecord, VirtualMemory<DefaultMemoryImpl>>
+    > = RefCell::new(
+        StableBtreeMap::init(crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.query_cache_spill.v1", ty = QueryCacheEntryRecord, id = QUERY_CACHE_SPILL_ID)),
+    );
+}
+
+///
+/// QueryCacheKey
+///
+/// Fixed-width digest of one cached (endpoint, argument key) pair.
+///
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct QueryCacheKey(pub [u8; 32]);
+
+impl Storable for QueryCacheKey {
+    const BOUND: Bound = Bound::Bounded {
+        max_size: 32,
+        is_fixed_size: true,
+    };
+
+    fn to_bytes(&self) -> Cow<'_, [u8]> {
+        Cow::Owned(self.0.to_vec())
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    /// Decode the exact fixed-width stable query cache key.
+    ///
+    /// # Panics
+    ///
+    /// Panics when stable memory contains a cache key that is not exactly 32 bytes.
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        let key = <[u8; 32]>::try_from(bytes.as_ref()).unwrap_or_else(|_| {
+            panic!(
+                "stable QueryCacheKey is {} bytes; expected 32",
+                bytes.as_ref().len()
+            )
+        });
+
+        Self(key)
+    }
+}
+
+///
+/// QueryCacheEntryRecord
+///
+/// One spilled query response: its endpoint, Candid-encoded response, and expiry.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct QueryCacheEntryRecord {
+    pub endpoint: String,
+    #[serde(with = "serde_bytes")]
+    pub response: Vec<u8>,
+    pub expires_at_ns: u64,
+}
+
+impl QueryCacheEntryRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "QueryCacheEntryRecord";
+    pub const STORABLE_MAX_SIZE: u32 = 34 * 1024;
+}
+
+impl_storable_bounded!(
+    QueryCacheEntryRecord,
+    QueryCacheEntryRecord::STORABLE_MAX_SIZE,
+    false
+);
+
+///
+/// QueryCacheSpillData
+///
+/// Canonical query-cache spillover allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QueryCacheSpillData {
+    pub entries: Vec<(QueryCacheKey, QueryCacheEntryRecord)>,
+}
+
+impl QueryCacheSpillData {
+    pub const STATE_CONTRACT_NAME: &'static str = "QueryCacheSpillData";
+}
+
+///
+/// QueryCacheStore
+///
+/// Stable facade for the query-cache spillover allocation.
+/// Owned by stable storage and wrapped by query-cache storage ops.
+///
+
+pub struct QueryCacheStore;
+
+impl QueryCacheStore {
+    #[must_use]
+    pub(crate) fn get(key: QueryCacheKey) -> Option<QueryCacheEntryRecord> {
+        QUERY_CACHE_SPILL.with_borrow(|map| map.get(&key))
+    }
+
+    pub(crate) fn insert(key: QueryCacheKey, record: QueryCacheEntryRecord) {
+        QUERY_CACHE_SPILL.with_borrow_mut(|map| {
+            map.insert(key, record);
+        });
+    }
+
+    pub(crate) fn remove(key: QueryCacheKey) -> Option<QueryCacheEntryRecord> {
+        QUERY_CACHE_SPILL.with_borrow_mut(|map| map.remove(&key))
+    }
+
+    #[must_use]
+    pub(crate) fn len() -> u64 {
+        QUERY_CACHE_SPILL.with_borrow(StableBtreeMap::len)
+    }
+
+    #[must_use]
+    pub(crate) fn entries() -> Vec<(QueryCacheKey, QueryCacheEntryRecord)> {
+        QUERY_CACHE_SPILL.with_borrow(|map| {
+            map.iter()
+                .map(|entry| (*entry.key(), entry.value()))
+                .collect()
+        })
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear_for_tests() {
+        QUERY_CACHE_SPILL.with_borrow_mut(StableBtreeMap::clear_new);
+    }
+}
@@ -0,0 +1,108 @@
+//! Module: storage::stable::session
+//!
+//! Responsibility: define the stable sessions opened by verified delegated tokens.
+//! Does not own: token verification, expiry or idle decisions, or capacity policy.
+//! Boundary: session storage ops wrap these records for auth predicates and the session workflow.
+
+use crate::cdk::structures::btreemap::BTreeMap as StableBtreeMap;
+use crate::{
+    cdk::structures::{DefaultMemoryImpl, memory::VirtualMemory},
+    impl_storable_unbounded,
+    role_contract::allocation::memory::session::SESSIONS_ID,
+    storage::prelude::*,
+};
+use std::cell::RefCell;
+
+eager_static! {
+    static SESSIONS: RefCell<
+        StableBtreeMap<Principal, SessionRecord, VirtualMemory<DefaultMemoryImpl>>
+    > = RefCell::new(
+        StableBtreeMap::init(crate::ic_memory_key!(authority = CANIC_CORE_MEMORY_AUTHORITY, key = "canic.core.sessions.v1", ty = SessionRecord, id = SESSIONS_ID)),
+    );
+}
+
+///
+/// SessionRecord
+///
+/// Session of one token subject: the token that opened it and its activity.
+///
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SessionRecord {
+    pub issuer_pid: Principal,
+    pub scopes: Vec<String>,
+    pub token_issued_at_ns: u64,
+    pub expires_at_ns: u64,
+    pub created_at_ns: u64,
+    pub last_seen_ns: u64,
+    pub previous_seen_ns: u64,
+    pub idle_closed: bool,
+}
+
+impl SessionRecord {
+    pub const STATE_CONTRACT_NAME: &'static str = "SessionRecord";
+}
+
+impl_storable_unbounded!(SessionRecord);
+
+///
+/// SessionData
+///
+/// Canonical session allocation snapshot.
+///
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SessionData {
+    pub entries: Vec<(Principal, SessionRecord)>,
+}
+
+impl SessionData {
+    pub const STATE_CONTRACT_NAME: &'static str = "SessionData";
+}
+
+///
+/// SessionStore
+///
+/// Stable facade for the session allocation.
+/// Owned by stable storage and wrapped by session storage ops.
+///
+
+pub struct SessionStore;
+
+impl SessionStore {
+    #[must_use]
+    pub(crate) fn get(subject: Principal) -> Option<SessionRecord> {
+        SESSIONS.with_borrow(|map| map.get(&subject))
+    }
+
+    pub(crate) fn insert(subject: Principal, record: SessionRecord) {
+        SESSIONS.with_borrow_mut(|map| {
+            map.insert(subject, record);
+        });
+    }
+
+    pub(crate) fn remove(subject: Principal) -> Option<SessionRecord> {
+        SESSIONS.with_borrow_mut(|map| map.remove(&subject))
+    }
+
+    #[must_use]
+    pub(crate) fn len() -> u64 {
+        SESSIONS.with_borrow(StableBtreeMap::len)
+    }
+
+    #[must_use]
+    pub(crate) fn export() -> SessionData {
+        SessionData {
+            entries: SESSIONS.with_borrow(|map| {
+                map.iter()
+                    .map(|entry| (*entry.key(), entry.value()))
+                    .collect()
+            }),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn clear_for_tests() {
+        SESSIONS.with_borrow_mut(StableBtreeMap::clear_new);
+    }
+}
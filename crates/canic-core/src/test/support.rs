@@ -5,8 +5,8 @@ use crate::{
     cdk::types::Cycles,
     config::schema::{
         CanisterAuthConfig, CanisterConfig, CanisterKind, CyclesFundingPolicyConfig,
-        DiagnosticsCanisterConfig, MetricsCanisterConfig, ShardPool, ShardPoolPolicy,
-        ShardingConfig, StandardsCanisterConfig,
+        DiagnosticsCanisterConfig, MetricsCanisterConfig, ShardPlacementStrategy, ShardPool,
+        ShardPoolPolicy, ShardingConfig, StandardsCanisterConfig,
     },
     ids::{CanisterRole, SubnetSlotId},
     ops::runtime::env::EnvOps,
@@ -24,6 +24,7 @@ pub fn init_sharding_test_config() {
                 capacity: 1,
                 initial_shards: 1,
                 max_shards: 2,
+                strategy: ShardPlacementStrategy::default(),
             },
         },
     );
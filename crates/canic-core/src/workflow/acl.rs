@@ -0,0 +1,126 @@
+//! Module: workflow::acl
+//!
+//! Responsibility: grant, revoke, and list application roles in the access control list.
+//! Does not own: stable schemas, role-name rules, or endpoint authorization.
+//! Boundary: controller ACL endpoints and the public ACL facade mutate roles through this workflow.
+
+use crate::{
+    InternalError,
+    cdk::types::Principal,
+    domain::policy::pure::acl as policy,
+    dto::{
+        acl::{AclEntry, AclRoleRequest},
+        page::{Page, PageRequest},
+    },
+    ops::storage::acl::AclStoreOps,
+    workflow::view::paginate::paginate_vec,
+};
+
+///
+/// AclWorkflow
+///
+/// Principal-to-roles grants checked by `auth::has_role(...)`.
+///
+/// Grant and revoke converge: granting a held role or revoking a missing
+/// one succeeds without changing anything.
+///
+
+pub struct AclWorkflow;
+
+impl AclWorkflow {
+    pub fn grant(request: &AclRoleRequest) -> Result<(), InternalError> {
+        validate_role(&request.role)?;
+        if AclStoreOps::has_role(request.principal, &request.role) {
+            return Ok(());
+        }
+
+        policy::validate_grant_capacity(AclStoreOps::roles(request.principal).len())
+            .map_err(|violation| InternalError::invalid_input(violation.to_string()))?;
+        AclStoreOps::grant(request.principal, &request.role);
+
+        Ok(())
+    }
+
+    pub fn revoke(request: &AclRoleRequest) -> Result<(), InternalError> {
+        validate_role(&request.role)?;
+        AclStoreOps::revoke(request.principal, &request.role);
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn has_role(principal: Principal, role: &str) -> bool {
+        AclStoreOps::has_role(principal, role)
+    }
+
+    #[must_use]
+    pub fn roles(principal: Principal) -> Vec<String> {
+        AclStoreOps::roles(principal)
+    }
+
+    #[must_use]
+    pub fn list(page: PageRequest) -> Page<AclEntry> {
+        paginate_vec(AclStoreOps::entries(), page)
+    }
+}
+
+fn validate_role(role: &str) -> Result<(), InternalError> {
+    policy::validate_role(role)
+        .map_err(|violation| InternalError::invalid_input(violation.to_string()))
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::seams;
+
+    fn request(role: &str) -> AclRoleRequest {
+        AclRoleRequest {
+            principal: Principal::from_slice(&[7; 29]),
+            role: role.to_string(),
+        }
+    }
+
+    #[test]
+    fn grants_converge_and_reject_invalid_roles() {
+        let _guard = seams::lock();
+        AclStoreOps::reset_for_tests();
+
+        AclWorkflow::grant(&request("admin")).expect("grant");
+        AclWorkflow::grant(&request("admin")).expect("repeat grant");
+        assert!(AclWorkflow::grant(&request("Admin")).is_err());
+        assert_eq!(
+            AclWorkflow::roles(request("admin").principal),
+            vec!["admin"]
+        );
+
+        AclWorkflow::revoke(&request("admin")).expect("revoke");
+        AclWorkflow::revoke(&request("admin")).expect("repeat revoke");
+        assert_eq!(
+            AclWorkflow::list(PageRequest {
+                limit: 10,
+                offset: 0
+            })
+            .total,
+            0
+        );
+        AclStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn grants_stop_at_the_per_principal_limit() {
+        let _guard = seams::lock();
+        AclStoreOps::reset_for_tests();
+        for i in 0..policy::ACL_MAX_ROLES_PER_PRINCIPAL {
+            AclWorkflow::grant(&request(&format!("role-{i}"))).expect("grant");
+        }
+
+        assert!(AclWorkflow::grant(&request("one-more")).is_err());
+        AclWorkflow::grant(&request("role-0")).expect("held roles still converge");
+        AclStoreOps::reset_for_tests();
+    }
+}
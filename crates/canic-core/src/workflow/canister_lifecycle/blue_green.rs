@@ -0,0 +1,354 @@
+//! Module: workflow::canister_lifecycle::blue_green
+//!
+//! Responsibility: replace a root child by provisioning a new instance beside it, smoke-checking it,
+//! flipping routing, and retiring the old instance after a grace period.
+//! Does not own: the routing overlay, registry schemas, or the fsm driver.
+//! Boundary: root admin endpoints start deployments here; each deployment runs as a durable fsm.
+
+use crate::{
+    InternalError, InternalErrorOrigin,
+    cdk::types::{Principal, TC},
+    domain::policy::pure::blue_green as policy,
+    dto::{
+        blue_green::{BlueGreenDeployRequest, BlueGreenStatus},
+        error::Error,
+    },
+    ids::CanisterRole,
+    log,
+    log::Topic,
+    model::replay::CommandKind,
+    ops::{
+        config::ConfigOps,
+        cost_guard::{CostGuardPermit, CostGuardRequest},
+        ic::IcOps,
+        runtime::env::EnvOps,
+        storage::{blue_green::BlueGreenStoreOps, registry::subnet::SubnetRegistryOps},
+    },
+    replay_policy::CostClass,
+    workflow::{
+        canister_lifecycle::{
+            CanisterLifecycleEvent, CanisterLifecycleWorkflow, propagation::PropagationWorkflow,
+        },
+        cost_guard::{CostGuardWorkflow, map_cost_guard_reserve_error},
+        fsm::{FsmWorkflow, StateMachine, Transition},
+        ic::{call::CallWorkflow, provision::ProvisionWorkflow},
+    },
+};
+use candid::CandidType;
+use serde::Deserialize;
+
+const BLUE_GREEN_COMMAND_KIND: &str = "blue_green.deploy.v1";
+const BLUE_GREEN_QUOTA_WINDOW_SECONDS: u64 = 60;
+const BLUE_GREEN_MAX_OPERATIONS_PER_WINDOW: u64 = 10;
+const BLUE_GREEN_MIN_CYCLES_AFTER_RESERVATION: u128 = TC;
+
+///
+/// BlueGreenWorkflow
+///
+/// Root-side entry points for blue/green deployments of direct root children.
+///
+
+pub struct BlueGreenWorkflow;
+
+impl BlueGreenWorkflow {
+    /// Start replacing the single root child of `request.role`.
+    ///
+    /// Nothing is awaited: the fsm instance and the deployment record commit
+    /// together, and provisioning starts on the next fsm tick. Repeating the
+    /// request while a deployment of the role is in flight returns its status.
+    pub fn deploy(request: BlueGreenDeployRequest) -> Result<BlueGreenStatus, InternalError> {
+        EnvOps::require_root()?;
+        if let Some(status) = BlueGreenStoreOps::status(&request.role) {
+            return Ok(status);
+        }
+
+        policy::validate_request(&request.smoke_check_method, request.grace_period_secs)
+            .map_err(|violation| InternalError::public(Error::invalid(violation.to_string())))?;
+        let blue_pid = match root_children(&request.role).as_slice() {
+            [pid] => *pid,
+            [] => {
+                return Err(InternalError::public(Error::not_found(format!(
+                    "no root child with role '{}' to replace",
+                    request.role
+                ))));
+            }
+            pids => {
+                return Err(InternalError::conflict(format!(
+                    "role '{}' has {} root children; blue/green needs exactly one",
+                    request.role,
+                    pids.len()
+                )));
+            }
+        };
+
+        let workflow_id =
+            FsmWorkflow::start_instance::<BlueGreenDeployment>(&BlueGreenState::Provision {
+                role: request.role.clone(),
+            })?;
+        BlueGreenStoreOps::begin(
+            request.role.clone(),
+            blue_pid,
+            request.smoke_check_method,
+            request.grace_period_secs,
+            workflow_id,
+            IcOps::now_nanos(),
+        );
+
+        BlueGreenStoreOps::status(&request.role).ok_or_else(|| {
+            InternalError::invariant(
+                InternalErrorOrigin::Workflow,
+                "blue/green record missing after begin",
+            )
+        })
+    }
+
+    /// In-flight deployments, in role order.
+    #[must_use]
+    pub fn statuses() -> Vec<BlueGreenStatus> {
+        BlueGreenStoreOps::statuses()
+    }
+}
+
+///
+/// BlueGreenState
+///
+/// Persisted progress of one blue/green deployment.
+///
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub enum BlueGreenState {
+    Provision {
+        role: CanisterRole,
+    },
+    SmokeCheck {
+        role: CanisterRole,
+        green_pid: Principal,
+    },
+    Flip {
+        role: CanisterRole,
+    },
+    Retire {
+        role: CanisterRole,
+        blue_pid: Principal,
+        retire_at_ns: u64,
+    },
+    Abandon {
+        role: CanisterRole,
+        green_pid: Principal,
+        reason: String,
+    },
+}
+
+///
+/// BlueGreenDeployment
+///
+/// Machine driving one deployment from provisioning to retirement. A failed
+/// smoke check deletes the new instance and leaves routing on the old one.
+///
+
+pub struct BlueGreenDeployment;
+
+impl StateMachine for BlueGreenDeployment {
+    const KIND: &'static str = "blue_green_deployment";
+    const TRANSITIONS: &'static [(&'static str, &'static str)] = &[
+        ("provision", "smoke_check"),
+        ("smoke_check", "flip"),
+        ("smoke_check", "abandon"),
+        ("flip", "retire"),
+    ];
+
+    type State = BlueGreenState;
+
+    fn label(state: &BlueGreenState) -> &'static str {
+        match state {
+            BlueGreenState::Provision { .. } => "provision",
+            BlueGreenState::SmokeCheck { .. } => "smoke_check",
+            BlueGreenState::Flip { .. } => "flip",
+            BlueGreenState::Retire { .. } => "retire",
+            BlueGreenState::Abandon { .. } => "abandon",
+        }
+    }
+
+    async fn step(state: BlueGreenState) -> Transition<BlueGreenState> {
+        match state {
+            BlueGreenState::Provision { role } => provision(role).await,
+            BlueGreenState::SmokeCheck { role, green_pid } => smoke_check(role, green_pid).await,
+            BlueGreenState::Flip { role } => flip(role).await,
+            BlueGreenState::Retire {
+                role,
+                blue_pid,
+                retire_at_ns,
+            } => retire(role, blue_pid, retire_at_ns).await,
+            BlueGreenState::Abandon {
+                role,
+                green_pid,
+                reason,
+            } => abandon(role, green_pid, reason).await,
+        }
+    }
+}
+
+// Create the green instance, or adopt one a trapped earlier attempt already registered.
+async fn provision(role: CanisterRole) -> Transition<BlueGreenState> {
+    let Some(status) = BlueGreenStoreOps::status(&role) else {
+        return Transition::Fail(format!("blue/green record for '{role}' is missing"));
+    };
+
+    let existing = root_children(&role)
+        .into_iter()
+        .find(|pid| *pid != status.blue_pid);
+    let green_pid = match existing {
+        Some(pid) => pid,
+        None => match create_green(&role).await {
+            Ok(pid) => pid,
+            Err(err) => {
+                BlueGreenStoreOps::clear(&role);
+                return Transition::Fail(format!("failed to provision new '{role}': {err}"));
+            }
+        },
+    };
+
+    BlueGreenStoreOps::provisioned(&role, green_pid);
+    Transition::Next(BlueGreenState::SmokeCheck { role, green_pid })
+}
+
+async fn create_green(role: &CanisterRole) -> Result<Principal, InternalError> {
+    let permit = reserve_cost_guard(role)?;
+    let event = CanisterLifecycleEvent::Create {
+        deployment_permit: &permit,
+        role: role.clone(),
+        parent: IcOps::canister_self(),
+        extra_arg: None,
+    };
+
+    let pid = match CanisterLifecycleWorkflow::apply(event).await {
+        Ok(result) => result.new_canister_pid,
+        Err(err) => {
+            return Err(CostGuardWorkflow::recover_after_failure(
+                &permit,
+                IcOps::now_secs(),
+                err,
+            ));
+        }
+    };
+    CostGuardWorkflow::complete(&permit, IcOps::now_secs())?;
+
+    pid.ok_or_else(|| {
+        InternalError::invariant(
+            InternalErrorOrigin::Workflow,
+            "canister create returned no canister id",
+        )
+    })
+}
+
+async fn smoke_check(role: CanisterRole, green_pid: Principal) -> Transition<BlueGreenState> {
+    let Some(status) = BlueGreenStoreOps::status(&role) else {
+        return Transition::Fail(format!("blue/green record for '{role}' is missing"));
+    };
+
+    let result =
+        match CallWorkflow::bounded_wait(green_pid, &status.smoke_check_method).with_args(()) {
+            Ok(call) => call.execute().await.map(|_| ()),
+            Err(err) => Err(err),
+        };
+
+    match result {
+        Ok(()) => Transition::Next(BlueGreenState::Flip { role }),
+        Err(err) => Transition::Next(BlueGreenState::Abandon {
+            role,
+            green_pid,
+            reason: err.to_string(),
+        }),
+    }
+}
+
+// Flip routing in one message, then push the rebuilt indexes to the fleet.
+async fn flip(role: CanisterRole) -> Transition<BlueGreenState> {
+    let Some(status) = BlueGreenStoreOps::status(&role) else {
+        return Transition::Fail(format!("blue/green record for '{role}' is missing"));
+    };
+    let Some(retire_at_ns) = BlueGreenStoreOps::flip(&role, IcOps::now_nanos()) else {
+        return Transition::Fail(format!(
+            "blue/green deployment of '{role}' has no new instance"
+        ));
+    };
+
+    if let Err(err) = PropagationWorkflow::propagate_state(&role).await {
+        return Transition::Retry(err.to_string());
+    }
+    log!(
+        Topic::CanisterLifecycle,
+        Ok,
+        "blue/green: routed {role} to {}",
+        status.green_pid.unwrap_or(status.routed_pid)
+    );
+
+    Transition::Next(BlueGreenState::Retire {
+        role,
+        blue_pid: status.blue_pid,
+        retire_at_ns,
+    })
+}
+
+async fn retire(
+    role: CanisterRole,
+    blue_pid: Principal,
+    retire_at_ns: u64,
+) -> Transition<BlueGreenState> {
+    if IcOps::now_nanos() < retire_at_ns {
+        return Transition::Wait(retire_at_ns);
+    }
+
+    if SubnetRegistryOps::role_parent(blue_pid).is_some()
+        && let Err(err) = ProvisionWorkflow::uninstall_and_delete_canister(blue_pid).await
+    {
+        return Transition::Retry(err.to_string());
+    }
+    BlueGreenStoreOps::clear(&role);
+
+    Transition::Done
+}
+
+async fn abandon(
+    role: CanisterRole,
+    green_pid: Principal,
+    reason: String,
+) -> Transition<BlueGreenState> {
+    if SubnetRegistryOps::role_parent(green_pid).is_some()
+        && let Err(err) = ProvisionWorkflow::uninstall_and_delete_canister(green_pid).await
+    {
+        return Transition::Retry(err.to_string());
+    }
+    BlueGreenStoreOps::clear(&role);
+
+    Transition::Fail(format!("smoke check of new '{role}' failed: {reason}"))
+}
+
+// Direct root children of `role`, including both instances during a deployment.
+fn root_children(role: &CanisterRole) -> Vec<Principal> {
+    SubnetRegistryOps::direct_root_role_index()
+        .remove(role)
+        .unwrap_or_default()
+}
+
+fn reserve_cost_guard(role: &CanisterRole) -> Result<CostGuardPermit, InternalError> {
+    let root_pid = IcOps::canister_self();
+    let cycle_reservation_cycles = ConfigOps::current_subnet_canister(role)?
+        .initial_cycles
+        .to_u128();
+
+    CostGuardWorkflow::reserve(CostGuardRequest {
+        cost_class: CostClass::ManagementDeployment,
+        command_kind: CommandKind::new(BLUE_GREEN_COMMAND_KIND)
+            .expect("blue/green command kind is a valid static label"),
+        quota_subject: root_pid,
+        payer: root_pid,
+        now_secs: IcOps::now_secs(),
+        quota_window_secs: BLUE_GREEN_QUOTA_WINDOW_SECONDS,
+        max_operations_per_window: BLUE_GREEN_MAX_OPERATIONS_PER_WINDOW,
+        current_cycle_balance: IcOps::canister_cycle_balance().to_u128(),
+        cycle_reservation_cycles,
+        min_cycles_after_reservation: BLUE_GREEN_MIN_CYCLES_AFTER_RESERVATION,
+    })
+    .map_err(map_cost_guard_reserve_error)
+}
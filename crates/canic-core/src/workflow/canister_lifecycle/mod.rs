@@ -4,6 +4,7 @@
 //! Does not own: endpoint authorization, stable registry schemas, or pure upgrade policy.
 //! Boundary: workflow layer coordinating replay, cost guards, IC ops, registry ops, and cascades.
 
+pub mod blue_green;
 mod propagation;
 
 use crate::{
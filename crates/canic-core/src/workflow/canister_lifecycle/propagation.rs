@@ -54,6 +54,7 @@ impl PropagationWorkflow {
         // state propagation must refresh all root children, not only the target branch.
         let snapshot = ProvisionWorkflow::rebuild_indexes_from_registry(Some(role))?
             .with_fleet_state()
+            .with_config_patch()?
             .build();
 
         StateCascadeWorkflow::root_cascade_state(&snapshot).await?;
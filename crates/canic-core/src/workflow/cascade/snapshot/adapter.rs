@@ -35,6 +35,7 @@ impl StateSnapshotAdapter {
             fleet_state: snapshot.fleet_state,
             fleet_directory: snapshot.fleet_directory.clone(),
             subnet_directory: snapshot.subnet_directory.clone(),
+            config_patch: snapshot.config_patch.clone(),
         }
     }
 
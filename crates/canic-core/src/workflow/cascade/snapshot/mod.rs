@@ -14,11 +14,13 @@ use crate::{
     cdk::types::Principal,
     dto::{
         cascade::StateSnapshotInput,
+        config::ConfigPatch,
         state::FleetStateInput,
         topology::{FleetDirectoryInput, SubnetDirectoryInput},
     },
     ids::CanisterRole,
     ops::{
+        config::ConfigOps,
         runtime::env::EnvOps,
         storage::{registry::subnet::SubnetRegistryOps, state::fleet::FleetStateOps},
         topology::index::{AppIndexResolver, SubnetIndexResolver},
@@ -36,6 +38,7 @@ pub struct StateSnapshot {
     pub fleet_state: Option<FleetStateInput>,
     pub fleet_directory: Option<FleetDirectoryInput>,
     pub subnet_directory: Option<SubnetDirectoryInput>,
+    pub config_patch: Option<ConfigPatch>,
 }
 
 ///
@@ -75,6 +78,12 @@ impl StateSnapshotBuilder {
         Ok(self)
     }
 
+    /// Include the accumulated runtime config overrides, when any are active.
+    pub fn with_config_patch(mut self) -> Result<Self, InternalError> {
+        self.snapshot.config_patch = ConfigOps::current_patch()?;
+        Ok(self)
+    }
+
     #[must_use]
     pub fn build(self) -> StateSnapshot {
         self.snapshot
@@ -87,6 +96,7 @@ impl From<StateSnapshotInput> for StateSnapshot {
             fleet_state: snapshot.fleet_state,
             fleet_directory: snapshot.fleet_directory,
             subnet_directory: snapshot.subnet_directory,
+            config_patch: snapshot.config_patch,
         }
     }
 }
@@ -187,6 +197,7 @@ pub const fn state_snapshot_is_empty(snapshot: &StateSnapshot) -> bool {
     snapshot.fleet_state.is_none()
         && snapshot.fleet_directory.is_none()
         && snapshot.subnet_directory.is_none()
+        && snapshot.config_patch.is_none()
 }
 
 #[must_use]
@@ -196,10 +207,11 @@ pub fn state_snapshot_debug(snapshot: &StateSnapshot) -> String {
     }
 
     format!(
-        "[{} {} {}]",
+        "[{} {} {} {}]",
         fmt(snapshot.fleet_state.is_some(), "fs"),
         fmt(snapshot.fleet_directory.is_some(), "fd"),
         fmt(snapshot.subnet_directory.is_some(), "sd"),
+        fmt(snapshot.config_patch.is_some(), "cp"),
     )
 }
 
@@ -217,8 +229,9 @@ mod tests {
             }),
             fleet_directory: None,
             subnet_directory: None,
+            config_patch: None,
         };
 
-        assert_eq!(super::state_snapshot_debug(&snapshot), "[fs .. ..]");
+        assert_eq!(super::state_snapshot_debug(&snapshot), "[fs .. .. ..]");
     }
 }
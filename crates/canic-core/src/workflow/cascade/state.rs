@@ -18,6 +18,7 @@ use crate::{
     log::Topic,
     ops::{
         cascade::CascadeOps,
+        config::ConfigOps,
         ic::IcOps,
         runtime::{
             env::EnvOps,
@@ -41,6 +42,7 @@ use crate::{
         },
         warn_if_large,
     },
+    workflow::runtime::log::LogRetentionWorkflow,
 };
 
 ///
@@ -288,6 +290,11 @@ impl StateCascadeWorkflow {
             SubnetIndexOps::import_args_allow_incomplete(filtered)?;
         }
 
+        if let Some(patch) = &snapshot.config_patch {
+            ConfigOps::apply_patch(patch)?;
+            LogRetentionWorkflow::start()?;
+        }
+
         Ok(())
     }
 
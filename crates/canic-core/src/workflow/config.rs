@@ -1,15 +1,23 @@
 //! Module: workflow::config
 //!
-//! Responsibility: provide the workflow facade for config export.
-//! Does not own: configuration storage, timer registration, or endpoint authorization.
-//! Boundary: delegates config serialization to ops.
+//! Responsibility: provide the workflow facade for config export and runtime patches.
+//! Does not own: configuration storage, patch validation rules, or endpoint authorization.
+//! Boundary: delegates config serialization and overrides to ops, then cascades patches.
 
-use crate::{InternalError, ops::config::ConfigOps};
+use crate::{
+    InternalError,
+    dto::config::ConfigPatch,
+    ops::{config::ConfigOps, runtime::env::EnvOps},
+    workflow::{
+        cascade::{snapshot::StateSnapshotBuilder, state::StateCascadeWorkflow},
+        runtime::log::LogRetentionWorkflow,
+    },
+};
 
 ///
 /// ConfigWorkflow
 ///
-/// Workflow facade for configuration export.
+/// Workflow facade for configuration export and runtime patches.
 ///
 
 pub struct ConfigWorkflow;
@@ -18,4 +26,15 @@ impl ConfigWorkflow {
     pub fn export_toml() -> Result<String, InternalError> {
         ConfigOps::export_toml()
     }
+
+    /// Apply a runtime config patch on root, then cascade the accumulated
+    /// overrides so every child consumes the same tunable values.
+    pub async fn apply_patch(patch: ConfigPatch) -> Result<(), InternalError> {
+        EnvOps::require_root()?;
+        ConfigOps::apply_patch(&patch)?;
+        LogRetentionWorkflow::start()?;
+
+        let snapshot = StateSnapshotBuilder::new()?.with_config_patch()?.build();
+        StateCascadeWorkflow::root_cascade_state(&snapshot).await
+    }
 }
@@ -0,0 +1,294 @@
+//! Module: workflow::event_bus
+//!
+//! Responsibility: broker pub/sub events on root and drain the delivery queue on a timer.
+//! Does not own: stable schemas, topic validation rules, or endpoint authorization.
+//! Boundary: root endpoints and the client facade reach event-bus storage through this workflow.
+
+use crate::{
+    InternalError,
+    domain::policy::pure::event_bus::{self as policy, EventBusPolicyViolation},
+    dto::{
+        error::Error,
+        event_bus::{
+            EventBusAdminCommand, EventDeadLetter, EventPublishRequest, EventPublishResponse,
+            EventSubscribeRequest, EventUnsubscribeRequest,
+        },
+        page::{Page, PageRequest},
+    },
+    ops::{
+        event_bus::EventBusOps,
+        ic::IcOps,
+        runtime::env::EnvOps,
+        storage::event_bus::{EventBusStoreOps, EventDelivery},
+    },
+    workflow::{
+        runtime::timer::{TimerDirective, TimerKey, TimerRunResult, TimerWorkflow},
+        view::paginate::paginate_vec,
+    },
+};
+use candid::Principal;
+
+const DELIVERY_BATCH_SIZE: usize = 16;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+///
+/// EventBusWorkflow
+///
+/// Root broker for topic subscriptions and at-least-once event delivery.
+///
+
+pub struct EventBusWorkflow;
+
+impl EventBusWorkflow {
+    /// Reconstruct the delivery deadline from the persisted queue.
+    pub fn start() -> Result<(), InternalError> {
+        EnvOps::require_root()?;
+        Self::reconcile();
+        Ok(())
+    }
+
+    /// Publish from any canister; non-root callers forward to the root broker.
+    pub async fn publish(
+        request: EventPublishRequest,
+    ) -> Result<EventPublishResponse, InternalError> {
+        validate_publish(&request)?;
+        if EnvOps::is_root() {
+            return Self::publish_root(IcOps::canister_self(), request);
+        }
+
+        EventBusOps::publish(EnvOps::root_pid()?, request).await
+    }
+
+    /// Subscribe the current canister's `method` to `topic` at the root broker.
+    pub async fn subscribe(request: EventSubscribeRequest) -> Result<(), InternalError> {
+        validate_subscribe(&request)?;
+        if EnvOps::is_root() {
+            return Self::subscribe_root(IcOps::canister_self(), request);
+        }
+
+        EventBusOps::subscribe(EnvOps::root_pid()?, request).await
+    }
+
+    /// Remove the current canister's subscription to `topic` at the root broker.
+    pub async fn unsubscribe(request: EventUnsubscribeRequest) -> Result<(), InternalError> {
+        validate(policy::validate_topic(&request.topic))?;
+        if EnvOps::is_root() {
+            return Self::unsubscribe_root(IcOps::canister_self(), request);
+        }
+
+        EventBusOps::unsubscribe(EnvOps::root_pid()?, request).await
+    }
+
+    /// Buffer one event and fan it out to the topic's current subscribers.
+    pub fn publish_root(
+        publisher: Principal,
+        request: EventPublishRequest,
+    ) -> Result<EventPublishResponse, InternalError> {
+        EnvOps::require_root()?;
+        validate_publish(&request)?;
+
+        let event = EventBusStoreOps::publish(
+            &request.topic,
+            publisher,
+            &request.payload,
+            IcOps::now_nanos(),
+        );
+        if event.deliveries > 0 {
+            Self::reconcile();
+        }
+
+        Ok(EventPublishResponse {
+            event_id: event.event_id,
+            deliveries: event.deliveries,
+        })
+    }
+
+    pub fn subscribe_root(
+        subscriber: Principal,
+        request: EventSubscribeRequest,
+    ) -> Result<(), InternalError> {
+        EnvOps::require_root()?;
+        validate_subscribe(&request)?;
+
+        EventBusStoreOps::subscribe(
+            &request.topic,
+            subscriber,
+            &request.method,
+            IcOps::now_nanos(),
+        );
+        Ok(())
+    }
+
+    pub fn unsubscribe_root(
+        subscriber: Principal,
+        request: EventUnsubscribeRequest,
+    ) -> Result<(), InternalError> {
+        EnvOps::require_root()?;
+        validate(policy::validate_topic(&request.topic))?;
+
+        EventBusStoreOps::unsubscribe(&request.topic, subscriber);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn dead_letters(page: PageRequest) -> Page<EventDeadLetter> {
+        paginate_vec(EventBusStoreOps::dead_letters(), page)
+    }
+
+    /// Redrive or discard one dead letter.
+    pub fn admin(command: EventBusAdminCommand) -> Result<(), InternalError> {
+        EnvOps::require_root()?;
+
+        let (delivery_id, found) = match command {
+            EventBusAdminCommand::DiscardDeadLetter { delivery_id } => (
+                delivery_id,
+                EventBusStoreOps::discard_dead_letter(delivery_id),
+            ),
+            EventBusAdminCommand::RedriveDeadLetter { delivery_id } => (
+                delivery_id,
+                EventBusStoreOps::redrive(delivery_id, IcOps::now_nanos()),
+            ),
+        };
+        if !found {
+            return Err(InternalError::public(Error::not_found(format!(
+                "event dead letter {delivery_id} not found"
+            ))));
+        }
+
+        Self::reconcile();
+        Ok(())
+    }
+
+    fn reconcile() {
+        TimerWorkflow::reconcile_at(
+            TimerKey::EventBusDelivery,
+            EventBusStoreOps::next_due_at_ns(),
+            || async { Self::run_due_batch().await },
+        );
+    }
+
+    async fn run_due_batch() -> TimerRunResult {
+        let due = EventBusStoreOps::due_deliveries(IcOps::now_nanos(), DELIVERY_BATCH_SIZE);
+        let mut delivered = 0_u64;
+
+        for delivery in due {
+            let result = EventBusOps::deliver(
+                delivery.subscriber(),
+                delivery.method(),
+                delivery.envelope(),
+            )
+            .await;
+
+            match result {
+                Ok(()) => {
+                    EventBusStoreOps::complete(&delivery);
+                    delivered += 1;
+                }
+                Err(err) => Self::record_failure(delivery, err.to_string(), IcOps::now_nanos()),
+            }
+        }
+
+        let directive = next_directive(EventBusStoreOps::next_due_at_ns(), IcOps::now_nanos());
+        if delivered == 0 {
+            TimerRunResult::no_work(directive)
+        } else {
+            TimerRunResult::success(delivered, directive)
+        }
+    }
+
+    fn record_failure(delivery: EventDelivery, error: String, now_ns: u64) {
+        let attempts = delivery.attempts().saturating_add(1);
+        if policy::delivery_exhausted(attempts) {
+            IcOps::println(&format!(
+                "event bus: dead-lettered delivery {} to {} after {attempts} attempts: {error}",
+                delivery.delivery_id(),
+                delivery.subscriber(),
+            ));
+            EventBusStoreOps::dead_letter(delivery, error);
+            return;
+        }
+
+        let backoff_ns = policy::retry_backoff_secs(attempts).saturating_mul(NANOS_PER_SECOND);
+        EventBusStoreOps::reschedule(delivery, error, now_ns.saturating_add(backoff_ns));
+    }
+}
+
+const fn next_directive(next_due_at_ns: Option<u64>, now_ns: u64) -> TimerDirective {
+    match next_due_at_ns {
+        None => TimerDirective::Stop,
+        Some(due_at_ns) if due_at_ns <= now_ns => TimerDirective::ContinueImmediately,
+        Some(due_at_ns) => TimerDirective::ScheduleAt(due_at_ns),
+    }
+}
+
+fn validate_publish(request: &EventPublishRequest) -> Result<(), InternalError> {
+    validate(policy::validate_topic(&request.topic))?;
+    validate(policy::validate_payload(request.payload.len()))
+}
+
+fn validate_subscribe(request: &EventSubscribeRequest) -> Result<(), InternalError> {
+    validate(policy::validate_topic(&request.topic))?;
+    validate(policy::validate_callback_method(&request.method))
+}
+
+fn validate(result: Result<(), EventBusPolicyViolation>) -> Result<(), InternalError> {
+    result.map_err(|violation| InternalError::public(Error::invalid(violation.to_string())))
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::seams;
+
+    #[test]
+    fn failures_back_off_until_the_attempt_budget_dead_letters() {
+        let _guard = seams::lock();
+        EventBusStoreOps::reset_for_tests();
+        EventBusStoreOps::subscribe("orders", seams::p(1), "on_order", 0);
+        EventBusStoreOps::publish("orders", seams::p(9), b"a", 0);
+
+        let mut now_ns = 0;
+        for attempt in 1..policy::EVENT_DELIVERY_MAX_ATTEMPTS {
+            let delivery = EventBusStoreOps::due_deliveries(now_ns, 1).remove(0);
+            EventBusWorkflow::record_failure(delivery, "rejected".to_string(), now_ns);
+
+            let retry_at = now_ns + policy::retry_backoff_secs(attempt) * NANOS_PER_SECOND;
+            assert_eq!(EventBusStoreOps::next_due_at_ns(), Some(retry_at));
+            now_ns = retry_at;
+        }
+
+        let delivery = EventBusStoreOps::due_deliveries(now_ns, 1).remove(0);
+        EventBusWorkflow::record_failure(delivery, "rejected".to_string(), now_ns);
+        assert_eq!(EventBusStoreOps::next_due_at_ns(), None);
+        assert_eq!(
+            EventBusStoreOps::dead_letters()[0].attempts,
+            policy::EVENT_DELIVERY_MAX_ATTEMPTS
+        );
+        EventBusStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn delivery_directive_tracks_the_next_queued_deadline() {
+        assert_eq!(next_directive(None, 10), TimerDirective::Stop);
+        assert_eq!(
+            next_directive(Some(10), 10),
+            TimerDirective::ContinueImmediately
+        );
+        assert_eq!(next_directive(Some(11), 10), TimerDirective::ScheduleAt(11));
+    }
+
+    #[test]
+    fn invalid_topics_are_rejected_as_public_input_errors() {
+        let err = validate_publish(&EventPublishRequest {
+            topic: "bad topic".to_string(),
+            payload: Vec::new(),
+        })
+        .expect_err("topic with a space must reject");
+
+        assert!(err.public_error().is_some());
+    }
+}
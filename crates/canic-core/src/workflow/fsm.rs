@@ -0,0 +1,380 @@
+//! Module: workflow::fsm
+//!
+//! Responsibility: drive declarative, persisted state machines one step at a time on a timer.
+//! Does not own: stable schemas, transition tables of concrete machines, or step side effects.
+//! Boundary: workflows and the public fsm facade start instances here; steps resume after upgrade.
+
+use crate::{
+    InternalError,
+    domain::policy::pure::fsm::{self as policy, FsmPolicyViolation},
+    dto::{error::Error, fsm::WorkflowStatusResponse},
+    ops::{
+        ic::IcOps,
+        storage::fsm::{FsmInstance, FsmStoreOps},
+    },
+    workflow::{
+        canister_lifecycle::blue_green::BlueGreenDeployment,
+        ic::provision::cleanup::ProvisionCleanup,
+        runtime::timer::{TimerDirective, TimerKey, TimerRunResult, TimerWorkflow},
+    },
+};
+use candid::CandidType;
+use serde::de::DeserializeOwned;
+use std::{cell::RefCell, collections::BTreeMap, future::Future, pin::Pin};
+
+const STEP_BATCH_SIZE: usize = 8;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+type StepFuture = Pin<Box<dyn Future<Output = StepOutcome>>>;
+type StepRunner = fn(Vec<u8>) -> StepFuture;
+
+thread_local! {
+    static MACHINES: RefCell<BTreeMap<&'static str, StepRunner>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+///
+/// StateMachine
+///
+/// Declarative multi-step workflow persisted between steps.
+///
+/// Each `step` runs from the persisted state and returns the next
+/// transition; only the state is kept across upgrades, so a step interrupted
+/// by a trap or upgrade runs again from the same state and must be
+/// idempotent. `Next` transitions must appear in `TRANSITIONS` as
+/// `(from_label, to_label)`; `Done` and `Fail` are allowed from any state.
+///
+
+pub trait StateMachine: 'static {
+    /// Stable machine name persisted with every instance.
+    const KIND: &'static str;
+
+    /// Allowed `(from, to)` state-label pairs.
+    const TRANSITIONS: &'static [(&'static str, &'static str)];
+
+    type State: CandidType + DeserializeOwned + 'static;
+
+    /// Stable label of one state, reported by `canic_workflow_status`.
+    fn label(state: &Self::State) -> &'static str;
+
+    /// Run the side effects of one state and decide what happens next.
+    fn step(state: Self::State) -> impl Future<Output = Transition<Self::State>>;
+}
+
+///
+/// Transition
+///
+/// Outcome of one state-machine step.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Transition<S> {
+    /// The machine finished successfully.
+    Done,
+    /// The machine cannot make progress; keep the instance as failed.
+    Fail(String),
+    /// Persist `S` and run its step next.
+    Next(S),
+    /// Run the same step again after a backoff.
+    Retry(String),
+    /// Run the same step again at the given IC time (nanoseconds) without
+    /// spending an attempt.
+    Wait(u64),
+}
+
+enum StepOutcome {
+    Done,
+    Fail(String),
+    Next { label: &'static str, state: Vec<u8> },
+    Retry(String),
+    Wait(u64),
+}
+
+///
+/// FsmWorkflow
+///
+/// Per-canister driver for persisted state-machine instances.
+///
+
+pub struct FsmWorkflow;
+
+impl FsmWorkflow {
+    /// Register built-in machines and resume persisted instances.
+    pub fn start() {
+        Self::register::<ProvisionCleanup>();
+        Self::register::<BlueGreenDeployment>();
+        Self::reconcile();
+    }
+
+    /// Make a machine runnable in this canister.
+    ///
+    /// Instances of kinds that are not registered after an upgrade wait until
+    /// they are, so applications should register their machines during setup.
+    pub fn register<M: StateMachine>() {
+        MACHINES.with_borrow_mut(|machines| {
+            machines.insert(M::KIND, run_step::<M>);
+        });
+    }
+
+    /// Persist a new instance in `state` and arm the driver.
+    ///
+    /// No await happens here, so the instance commits or rolls back together
+    /// with the caller's other state changes in the same message.
+    pub fn start_instance<M: StateMachine>(state: &M::State) -> Result<u64, InternalError> {
+        let label = M::label(state);
+        validate(policy::validate_name(M::KIND))?;
+        validate(policy::validate_name(label))?;
+        let bytes =
+            encode_state(state).map_err(|err| InternalError::public(Error::invalid(err)))?;
+
+        Self::register::<M>();
+        let instance_id = FsmStoreOps::create(M::KIND, label, bytes, IcOps::now_nanos());
+        Self::reconcile();
+        Ok(instance_id)
+    }
+
+    #[must_use]
+    pub fn status(instance_id: u64) -> Option<WorkflowStatusResponse> {
+        FsmStoreOps::status(instance_id)
+    }
+
+    fn reconcile() {
+        TimerWorkflow::reconcile_at(TimerKey::FsmStep, FsmStoreOps::next_due_at_ns(), || async {
+            Self::run_due_batch().await
+        });
+    }
+
+    #[expect(
+        clippy::future_not_send,
+        reason = "state-machine steps run on the single-threaded IC executor"
+    )]
+    async fn run_due_batch() -> TimerRunResult {
+        let due = FsmStoreOps::due(IcOps::now_nanos(), STEP_BATCH_SIZE);
+        let mut stepped = 0_u64;
+
+        for instance in due {
+            let runner = MACHINES.with_borrow(|machines| machines.get(instance.kind()).copied());
+            let Some(runner) = runner else {
+                let retry_at_ns = IcOps::now_nanos().saturating_add(
+                    policy::FSM_UNREGISTERED_RETRY_SECS.saturating_mul(NANOS_PER_SECOND),
+                );
+                FsmStoreOps::defer(instance, retry_at_ns);
+                continue;
+            };
+
+            let outcome = runner(instance.state().to_vec()).await;
+            Self::record_outcome(instance, outcome, IcOps::now_nanos());
+            stepped += 1;
+        }
+
+        let now_ns = IcOps::now_nanos();
+        FsmStoreOps::prune_terminal(|updated_at_ns| {
+            policy::terminal_expired(updated_at_ns, now_ns)
+        });
+
+        let directive = next_directive(FsmStoreOps::next_due_at_ns(), now_ns);
+        if stepped == 0 {
+            TimerRunResult::no_work(directive)
+        } else {
+            TimerRunResult::success(stepped, directive)
+        }
+    }
+
+    fn record_outcome(instance: FsmInstance, outcome: StepOutcome, now_ns: u64) {
+        match outcome {
+            StepOutcome::Done => FsmStoreOps::complete(instance, now_ns),
+            StepOutcome::Next { label, state } => {
+                FsmStoreOps::advance(instance, label, state, now_ns);
+            }
+            StepOutcome::Fail(error) => Self::fail(instance, error, now_ns),
+            StepOutcome::Retry(error) => {
+                let attempts = instance.attempts().saturating_add(1);
+                if policy::attempts_exhausted(attempts) {
+                    Self::fail(
+                        instance,
+                        format!("gave up after {attempts} attempts: {error}"),
+                        now_ns,
+                    );
+                    return;
+                }
+
+                let backoff_ns =
+                    policy::retry_backoff_secs(attempts).saturating_mul(NANOS_PER_SECOND);
+                FsmStoreOps::retry(instance, error, now_ns.saturating_add(backoff_ns), now_ns);
+            }
+            StepOutcome::Wait(until_ns) => FsmStoreOps::defer(instance, until_ns),
+        }
+    }
+
+    fn fail(instance: FsmInstance, error: String, now_ns: u64) {
+        IcOps::println(&format!(
+            "fsm: instance {} ({}) failed: {error}",
+            instance.instance_id(),
+            instance.kind(),
+        ));
+        FsmStoreOps::fail(instance, error, now_ns);
+    }
+}
+
+// Decode, step, and re-encode one instance of `M`, enforcing its transition table.
+fn run_step<M: StateMachine>(bytes: Vec<u8>) -> StepFuture {
+    Box::pin(async move {
+        let state: M::State = match candid::decode_one(&bytes) {
+            Ok(state) => state,
+            Err(err) => return StepOutcome::Fail(format!("failed to decode state: {err}")),
+        };
+        let from = M::label(&state);
+
+        match M::step(state).await {
+            Transition::Done => StepOutcome::Done,
+            Transition::Fail(error) => StepOutcome::Fail(error),
+            Transition::Retry(error) => StepOutcome::Retry(error),
+            Transition::Wait(until_ns) => StepOutcome::Wait(until_ns),
+            Transition::Next(next) => {
+                let to = M::label(&next);
+                if let Err(violation) =
+                    policy::validate_transition(M::KIND, M::TRANSITIONS, from, to)
+                        .and_then(|()| policy::validate_name(to))
+                {
+                    return StepOutcome::Fail(violation.to_string());
+                }
+
+                match encode_state(&next) {
+                    Ok(state) => StepOutcome::Next { label: to, state },
+                    Err(err) => StepOutcome::Fail(err),
+                }
+            }
+        }
+    })
+}
+
+fn encode_state<S: CandidType>(state: &S) -> Result<Vec<u8>, String> {
+    let bytes =
+        candid::encode_one(state).map_err(|err| format!("failed to encode state: {err}"))?;
+    policy::validate_state_len(bytes.len()).map_err(|violation| violation.to_string())?;
+
+    Ok(bytes)
+}
+
+const fn next_directive(next_due_at_ns: Option<u64>, now_ns: u64) -> TimerDirective {
+    match next_due_at_ns {
+        None => TimerDirective::Stop,
+        Some(due_at_ns) if due_at_ns <= now_ns => TimerDirective::ContinueImmediately,
+        Some(due_at_ns) => TimerDirective::ScheduleAt(due_at_ns),
+    }
+}
+
+fn validate(result: Result<(), FsmPolicyViolation>) -> Result<(), InternalError> {
+    result.map_err(|violation| InternalError::public(Error::invalid(violation.to_string())))
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dto::fsm::WorkflowRunStatus, test::seams};
+    use futures::executor::block_on;
+    use serde::Deserialize;
+
+    #[derive(CandidType, Deserialize)]
+    enum Counter {
+        Counting(u8),
+        Skipped,
+        Waiting,
+    }
+
+    struct CounterMachine;
+
+    impl StateMachine for CounterMachine {
+        const KIND: &'static str = "counter";
+        const TRANSITIONS: &'static [(&'static str, &'static str)] = &[("counting", "counting")];
+
+        type State = Counter;
+
+        fn label(state: &Counter) -> &'static str {
+            match state {
+                Counter::Counting(_) => "counting",
+                Counter::Skipped => "skipped",
+                Counter::Waiting => "waiting",
+            }
+        }
+
+        async fn step(state: Counter) -> Transition<Counter> {
+            match state {
+                Counter::Counting(0) => Transition::Next(Counter::Skipped),
+                Counter::Counting(1) => Transition::Done,
+                Counter::Counting(2) => Transition::Retry("not yet".to_string()),
+                Counter::Counting(n) => Transition::Next(Counter::Counting(n - 1)),
+                Counter::Skipped => Transition::Fail("unreachable".to_string()),
+                Counter::Waiting => Transition::Wait(50),
+            }
+        }
+    }
+
+    fn step_once(now_ns: u64) {
+        let instance = FsmStoreOps::due(now_ns, 1).remove(0);
+        let outcome = block_on(run_step::<CounterMachine>(instance.state().to_vec()));
+        FsmWorkflow::record_outcome(instance, outcome, now_ns);
+    }
+
+    fn create(state: &Counter) -> u64 {
+        let bytes = encode_state(state).expect("encode counter");
+        FsmStoreOps::create(CounterMachine::KIND, CounterMachine::label(state), bytes, 0)
+    }
+
+    #[test]
+    fn declared_transitions_advance_until_done() {
+        let _guard = seams::lock();
+        FsmStoreOps::reset_for_tests();
+        let instance_id = create(&Counter::Counting(4));
+
+        step_once(0);
+        step_once(0);
+        let status = FsmWorkflow::status(instance_id).expect("status");
+        assert_eq!(status.state, "counting");
+
+        step_once(0);
+        let status = FsmWorkflow::status(instance_id).expect("status");
+        assert_eq!(status.attempts, 1);
+        assert_eq!(status.last_error.as_deref(), Some("not yet"));
+        assert_eq!(FsmStoreOps::next_due_at_ns(), Some(2 * NANOS_PER_SECOND));
+        FsmStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn undeclared_transitions_fail_the_instance() {
+        let _guard = seams::lock();
+        FsmStoreOps::reset_for_tests();
+        let instance_id = create(&Counter::Counting(0));
+
+        step_once(0);
+        let status = FsmWorkflow::status(instance_id).expect("status");
+        assert_eq!(status.status, WorkflowRunStatus::Failed);
+        assert_eq!(status.state, "counting");
+        assert!(
+            status
+                .last_error
+                .is_some_and(|error| error.contains("counting -> skipped"))
+        );
+        assert_eq!(FsmStoreOps::next_due_at_ns(), None);
+        FsmStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn waiting_defers_without_spending_an_attempt() {
+        let _guard = seams::lock();
+        FsmStoreOps::reset_for_tests();
+        let instance_id = create(&Counter::Waiting);
+
+        step_once(0);
+        let status = FsmWorkflow::status(instance_id).expect("status");
+        assert_eq!(status.status, WorkflowRunStatus::Running);
+        assert_eq!(status.state, "waiting");
+        assert_eq!(status.attempts, 0);
+        assert_eq!(FsmStoreOps::next_due_at_ns(), Some(50));
+        FsmStoreOps::reset_for_tests();
+    }
+}
@@ -78,6 +78,25 @@ impl CallBuilder<'_> {
         }
     }
 
+    /// Limit calls sharing `class` to `max_in_flight` at once (minimum one).
+    ///
+    /// Calls beyond the limit wait in FIFO order unless
+    /// [`reject_when_saturated`](Self::reject_when_saturated) is set.
+    #[must_use]
+    pub fn with_concurrency_class(self, class: &'static str, max_in_flight: u32) -> Self {
+        Self {
+            inner: self.inner.with_concurrency_class(class, max_in_flight),
+        }
+    }
+
+    /// Fail immediately instead of queueing when the concurrency class is saturated.
+    #[must_use]
+    pub fn reject_when_saturated(self) -> Self {
+        Self {
+            inner: self.inner.reject_when_saturated(),
+        }
+    }
+
     /// Execute the configured call.
     pub async fn execute(self) -> Result<CallResult, InternalError> {
         Ok(CallResult {
@@ -0,0 +1,67 @@
+//! Module: workflow::ic::provision::cleanup
+//!
+//! Responsibility: release a canister whose install failed, retrying durably across upgrades.
+//! Does not own: allocation, install, or the fsm driver itself.
+//! Boundary: provisioning starts one cleanup instance when inline cleanup fails.
+
+use crate::{
+    cdk::types::Principal,
+    workflow::{
+        fsm::{StateMachine, Transition},
+        ic::provision::ProvisionWorkflow,
+        pool::PoolWorkflow,
+    },
+};
+use candid::CandidType;
+use serde::Deserialize;
+
+///
+/// ProvisionCleanupState
+///
+/// Canister to release after a failed install, and how.
+///
+
+#[derive(CandidType, Clone, Debug, Deserialize, Eq, PartialEq)]
+pub enum ProvisionCleanupState {
+    DeleteCanister { pid: Principal },
+    RecyclePool { pid: Principal },
+}
+
+///
+/// ProvisionCleanup
+///
+/// Single-step machine returning a failed-install canister to the pool or
+/// deleting it.
+///
+
+pub struct ProvisionCleanup;
+
+impl StateMachine for ProvisionCleanup {
+    const KIND: &'static str = "provision_cleanup";
+    const TRANSITIONS: &'static [(&'static str, &'static str)] = &[];
+
+    type State = ProvisionCleanupState;
+
+    fn label(state: &ProvisionCleanupState) -> &'static str {
+        match state {
+            ProvisionCleanupState::DeleteCanister { .. } => "delete_canister",
+            ProvisionCleanupState::RecyclePool { .. } => "recycle_pool",
+        }
+    }
+
+    async fn step(state: ProvisionCleanupState) -> Transition<ProvisionCleanupState> {
+        let result = match state {
+            ProvisionCleanupState::DeleteCanister { pid } => {
+                ProvisionWorkflow::uninstall_and_delete_canister(pid).await
+            }
+            ProvisionCleanupState::RecyclePool { pid } => {
+                PoolWorkflow::pool_import_canister(pid).await
+            }
+        };
+
+        match result {
+            Ok(()) => Transition::Done,
+            Err(err) => Transition::Retry(err.to_string()),
+        }
+    }
+}
@@ -28,7 +28,7 @@ impl ProvisionWorkflow {
     ) -> Result<StateSnapshotBuilder, InternalError> {
         let cfg = ConfigOps::get()?;
         let subnet_cfg = ConfigOps::current_subnet()?;
-        let registry = SubnetRegistryOps::data();
+        let registry = SubnetRegistryOps::routed_data();
         let allow_incomplete = updated_role.is_some();
         let subnet_index_roles = subnet_cfg.subnet_index_roles();
 
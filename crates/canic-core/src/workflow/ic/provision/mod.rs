@@ -5,6 +5,7 @@
 //! Boundary: workflow calls ops and policy after endpoints authenticate input.
 
 mod allocation;
+pub mod cleanup;
 mod delete;
 mod indexes;
 mod install;
@@ -31,12 +32,13 @@ use crate::{
         },
     },
     workflow::{
+        fsm::{FsmWorkflow, StateMachine, Transition},
         ic::provision::{
             allocation::{AllocationSource, allocate_canister},
+            cleanup::{ProvisionCleanup, ProvisionCleanupState},
             install::install_canister,
             metrics::{record_canister_op, record_provisioning},
         },
-        pool::PoolWorkflow,
     },
 };
 
@@ -56,6 +58,9 @@ impl ProvisionWorkflow {
     /// 2. Install WASM + bootstrap initial state.
     /// 3. Register canister in SubnetRegistry.
     /// 4. Cascade topology + sync directories.
+    ///
+    /// When install fails the canister is recycled or deleted inline; if that
+    /// cleanup fails too, it continues as a durable `provision_cleanup` workflow.
     pub async fn create_and_install_canister(
         deployment_permit: &CostGuardPermit,
         role: &CanisterRole,
@@ -112,20 +117,24 @@ impl ProvisionWorkflow {
                 Error,
                 "install failed for {pid} ({role}): {err}"
             );
-            if source == AllocationSource::Pool {
-                if let Err(recycle_err) = PoolWorkflow::pool_import_canister(pid).await {
-                    log!(
-                        Topic::CanisterPool,
+            let cleanup = if source == AllocationSource::Pool {
+                ProvisionCleanupState::RecyclePool { pid }
+            } else {
+                ProvisionCleanupState::DeleteCanister { pid }
+            };
+            if let Transition::Retry(cleanup_err) = ProvisionCleanup::step(cleanup.clone()).await {
+                match FsmWorkflow::start_instance::<ProvisionCleanup>(&cleanup) {
+                    Ok(instance_id) => log!(
+                        Topic::CanisterLifecycle,
+                        Warn,
+                        "cleanup after install failure failed for {pid} ({cleanup_err}); retrying as workflow {instance_id}"
+                    ),
+                    Err(start_err) => log!(
+                        Topic::CanisterLifecycle,
                         Warn,
-                        "failed to recycle pool canister after install failure: {pid} ({recycle_err})"
-                    );
+                        "cleanup after install failure failed for {pid} ({cleanup_err}); retry not scheduled: {start_err}"
+                    ),
                 }
-            } else if let Err(delete_err) = Self::uninstall_and_delete_canister(pid).await {
-                log!(
-                    Topic::CanisterLifecycle,
-                    Warn,
-                    "failed to delete canister after install failure: {pid} ({delete_err})"
-                );
             }
 
             return Err(InternalError::workflow(
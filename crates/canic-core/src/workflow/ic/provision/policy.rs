@@ -11,10 +11,14 @@ use crate::{
     domain::policy::pure as policy,
     domain::policy::pure::topology::registry::{RegistryCanisterKind, RegistryCanisterShape},
     ids::CanisterRole,
-    ops::{config::ConfigOps, storage::registry::subnet::SubnetRegistryOps},
+    ops::{
+        config::ConfigOps,
+        storage::{blue_green::BlueGreenStoreOps, registry::subnet::SubnetRegistryOps},
+    },
 };
 
 // Validate create-time registry policy using targeted registry lookups instead of a full export.
+// An instance being replaced by a blue/green deployment does not count as existing.
 pub(super) fn validate_registration_policy(
     role: &CanisterRole,
     parent_pid: Principal,
@@ -35,7 +39,8 @@ pub(super) fn validate_registration_policy(
             RegistryCanisterKind::Root | RegistryCanisterKind::Service
         )
         .then(|| SubnetRegistryOps::find_pid_for_role(role))
-        .flatten(),
+        .flatten()
+        .filter(|pid| !BlueGreenStoreOps::is_being_replaced(role, *pid)),
         existing_singleton_under_parent_pid: matches!(
             canister_shape.kind,
             RegistryCanisterKind::Singleton
@@ -47,7 +52,8 @@ pub(super) fn validate_registration_policy(
                 SubnetRegistryOps::find_child_pid_for_role(parent_pid, role)
             }
         })
-        .flatten(),
+        .flatten()
+        .filter(|pid| !BlueGreenStoreOps::is_being_replaced(role, *pid)),
     };
 
     policy::topology::registry::RegistryPolicy::can_register_role_observed(
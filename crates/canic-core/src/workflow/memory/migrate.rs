@@ -0,0 +1,190 @@
+//! Module: workflow::memory::migrate
+//!
+//! Responsibility: drive resumable stable map migrations across timer invocations.
+//! Does not own: batch rewriting, cursor schemas, or value conversion logic.
+//! Boundary: each timer run migrates one bounded batch and persists the cursor before rescheduling.
+
+use crate::{
+    InternalError,
+    cdk::structures::Storable,
+    dto::memory::MapMigrationStatus,
+    log::Topic,
+    memory::migrate::MapMigrationLimits,
+    ops::{
+        ic::IcOps, runtime::memory::MemoryRegistryOps, storage::migration::MapMigrationCursorOps,
+    },
+    storage::stable::migration::MapMigrationCursorRecord,
+    workflow::runtime::timer::TimerWorkflow,
+};
+use std::{any::type_name, time::Duration};
+
+const MAP_MIGRATION_LIMITS: MapMigrationLimits = MapMigrationLimits {
+    max_entries: 1_000,
+    instruction_budget: 5_000_000_000,
+};
+
+///
+/// MapMigrationWorkflow
+///
+
+pub struct MapMigrationWorkflow;
+
+impl MapMigrationWorkflow {
+    /// Start or resume migrating the map behind `memory_id` from `VOld` to `VNew`.
+    ///
+    /// The target value type identifies the migration: a completed cursor for
+    /// the same target is a no-op, so this is safe to call on every upgrade.
+    pub fn start<K, VOld, VNew>(memory_id: u8, f: fn(VOld) -> VNew) -> Result<(), InternalError>
+    where
+        K: Storable + Ord + Clone + 'static,
+        VOld: Storable + 'static,
+        VNew: Storable + 'static,
+    {
+        let target = type_name::<VNew>();
+        match MapMigrationCursorOps::get(memory_id) {
+            Some(cursor) if cursor.target == target && cursor.completed => return Ok(()),
+            Some(cursor) if cursor.target == target => {}
+            Some(cursor) if !cursor.completed => {
+                return Err(InternalError::conflict(format!(
+                    "memory {memory_id} is still migrating to {}",
+                    cursor.target
+                )));
+            }
+            _ => {
+                let now = IcOps::now_secs();
+                MapMigrationCursorOps::upsert(
+                    memory_id,
+                    MapMigrationCursorRecord {
+                        target: target.to_string(),
+                        last_key: None,
+                        migrated: 0,
+                        batches: 0,
+                        completed: false,
+                        started_at: now,
+                        updated_at: now,
+                    },
+                );
+            }
+        }
+
+        Self::schedule::<K, VOld, VNew>(memory_id, f);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn status(memory_id: u8) -> Option<MapMigrationStatus> {
+        MapMigrationCursorOps::status(memory_id)
+    }
+
+    fn schedule<K, VOld, VNew>(memory_id: u8, f: fn(VOld) -> VNew)
+    where
+        K: Storable + Ord + Clone + 'static,
+        VOld: Storable + 'static,
+        VNew: Storable + 'static,
+    {
+        let _ = TimerWorkflow::set_application_once(
+            Duration::ZERO,
+            format!("memory:migrate_map:{memory_id}"),
+            async move {
+                if !Self::run_batch::<K, VOld, VNew>(memory_id, f) {
+                    Self::schedule::<K, VOld, VNew>(memory_id, f);
+                }
+            },
+        );
+    }
+
+    // Migrate one batch and persist the cursor; returns whether the map is done.
+    fn run_batch<K, VOld, VNew>(memory_id: u8, f: fn(VOld) -> VNew) -> bool
+    where
+        K: Storable + Ord + Clone,
+        VOld: Storable,
+        VNew: Storable,
+    {
+        let Some(cursor) = MapMigrationCursorOps::get(memory_id) else {
+            return true;
+        };
+        if cursor.completed {
+            return true;
+        }
+
+        let batch = MemoryRegistryOps::migrate_map_batch::<K, VOld, VNew>(
+            memory_id,
+            cursor.last_key.as_deref(),
+            MAP_MIGRATION_LIMITS,
+            f,
+        );
+        let migrated = cursor.migrated.saturating_add(batch.migrated);
+        MapMigrationCursorOps::upsert(
+            memory_id,
+            MapMigrationCursorRecord {
+                last_key: batch.last_key,
+                migrated,
+                batches: cursor.batches.saturating_add(1),
+                completed: batch.done,
+                updated_at: IcOps::now_secs(),
+                ..cursor
+            },
+        );
+
+        if batch.done {
+            crate::log!(
+                Topic::Memory,
+                Ok,
+                "💾 memory {memory_id}: migrated {migrated} entries to {}",
+                type_name::<VNew>()
+            );
+        }
+
+        batch.done
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::error::ErrorCode;
+
+    fn cursor(target: &str, completed: bool) -> MapMigrationCursorRecord {
+        MapMigrationCursorRecord {
+            target: target.to_string(),
+            last_key: None,
+            migrated: 0,
+            batches: 0,
+            completed,
+            started_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn start_is_a_no_op_once_the_same_target_completed() {
+        const ID: u8 = 201;
+        MapMigrationCursorOps::upsert(ID, cursor(type_name::<u64>(), true));
+
+        MapMigrationWorkflow::start::<u32, u32, u64>(ID, u64::from)
+            .expect("completed migration is idempotent");
+
+        assert_eq!(
+            MapMigrationCursorOps::get(ID),
+            Some(cursor(type_name::<u64>(), true))
+        );
+    }
+
+    #[test]
+    fn start_rejects_a_second_target_while_one_is_in_progress() {
+        const ID: u8 = 202;
+        MapMigrationCursorOps::upsert(ID, cursor(type_name::<u64>(), false));
+
+        let err = MapMigrationWorkflow::start::<u32, u32, u128>(ID, u128::from)
+            .expect_err("in-progress migration blocks a different target");
+
+        assert_eq!(
+            err.public_error().map(|err| err.code),
+            Some(ErrorCode::Conflict)
+        );
+    }
+}
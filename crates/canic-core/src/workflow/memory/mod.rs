@@ -1,7 +1,8 @@
 //! Module: workflow::memory
 //!
-//! Responsibility: group read-only memory workflow queries.
+//! Responsibility: group memory workflow queries and resumable map migrations.
 //! Does not own: memory registry mutation, endpoint authorization, or DTO schemas.
 //! Boundary: workflow query namespace over runtime memory ops.
 
+pub mod migrate;
 pub mod query;
@@ -1,11 +1,13 @@
 //! Module: workflow::memory::query
 //!
-//! Responsibility: expose memory ledger workflow snapshots.
+//! Responsibility: expose memory ledger, store catalog, and eager-init workflow snapshots.
 //! Does not own: memory registry mutation, endpoint authorization, or DTO schemas.
 //! Boundary: workflow query facade over runtime memory ops.
 
 use crate::{
-    InternalError, dto::memory::MemoryLedgerResponse, ops::runtime::memory::MemoryRegistryOps,
+    InternalError,
+    dto::memory::{EagerInitReport, MemoryLedgerResponse, StoreCatalogResponse},
+    ops::runtime::memory::MemoryRegistryOps,
 };
 
 ///
@@ -18,4 +20,13 @@ impl MemoryQuery {
     pub fn ledger() -> Result<MemoryLedgerResponse, InternalError> {
         MemoryRegistryOps::ledger_snapshot()
     }
+
+    pub fn stores() -> Result<StoreCatalogResponse, InternalError> {
+        MemoryRegistryOps::store_catalog()
+    }
+
+    #[must_use]
+    pub fn eager_init_report() -> EagerInitReport {
+        MemoryRegistryOps::eager_init_report()
+    }
 }
@@ -3,6 +3,7 @@
 //! `workflow` sequences ops calls, schedules async follow-up work, and owns
 //! behavior that unfolds over time.
 
+pub mod acl;
 #[cfg(feature = "blob-storage-billing")]
 pub mod blob_storage;
 pub mod bootstrap;
@@ -11,6 +12,8 @@ pub mod cascade;
 pub mod config;
 pub mod cost_guard;
 pub mod env;
+pub mod event_bus;
+pub mod fsm;
 pub mod ic;
 pub mod icrc;
 pub mod log;
@@ -21,6 +24,7 @@ pub mod pool;
 pub mod replay;
 pub mod rpc;
 pub mod runtime;
+pub mod session;
 pub mod state;
 pub mod topology;
 pub mod view;
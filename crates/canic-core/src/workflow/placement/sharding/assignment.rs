@@ -11,14 +11,16 @@ use crate::{
     domain::policy::pure::placement::sharding::{
         ShardingPolicy, ShardingState, compute_pool_metrics,
     },
-    dto::placement::sharding::ShardingPlanStateResponse,
+    dto::placement::sharding::ShardingPlanResponse,
     ids::CanisterRole,
     log::Topic,
     model::placement::sharding::ShardingPlanState,
     ops::{
-        placement::sharding::mapper::{
-            ShardPartitionKeyAssignmentMapper, ShardPlacementMapper,
-            ShardingPlanStateResponseMapper,
+        placement::sharding::{
+            mapper::{
+                ShardPartitionKeyAssignmentMapper, ShardPlacementMapper, ShardingPlanResponseMapper,
+            },
+            strategy::PlacementStrategyOps,
         },
         runtime::metrics::{
             recording::ShardingMetricEvent as MetricEvent,
@@ -70,6 +72,10 @@ impl ShardingWorkflow {
             MetricEvent::failed(MetricOperation::Assign, &err);
             return Err(err);
         }
+        if let Err(err) = PlacementStrategyOps::resolve(pool, &policy.strategy) {
+            MetricEvent::failed(MetricOperation::Assign, &err);
+            return Err(err);
+        }
         let active = ShardingLifecycleOps::active_shards();
         crate::perf!("load_active_shards");
         if active.is_empty() {
@@ -114,16 +120,21 @@ impl ShardingWorkflow {
             .collect();
         crate::perf!("collect_registry");
 
-        let state = ShardingState {
-            pool,
-            max_shards: policy.max_shards,
-            metrics: &metrics,
-            entries: &entry_views,
-            assignments: &assignment_views,
-        };
-
         MetricEvent::started(MetricOperation::PlanAssign);
-        let plan = ShardingPolicy::plan_assign(&state, partition_key, None);
+        let plan = {
+            // The resolved strategy is heap-local and must not live across awaits.
+            let strategy = PlacementStrategyOps::resolve(pool, &policy.strategy)?;
+            let state = ShardingState {
+                pool,
+                max_shards: policy.max_shards,
+                metrics: &metrics,
+                entries: &entry_views,
+                assignments: &assignment_views,
+                strategy: strategy.as_ref(),
+            };
+
+            ShardingPolicy::plan_assign(&state, partition_key, None)
+        };
         crate::perf!("plan_assign");
 
         match plan.state {
@@ -162,7 +173,8 @@ impl ShardingWorkflow {
                 crate::log!(
                     Topic::Sharding,
                     Info,
-                    "📦 partition_key={partition_key} assigned shard={pid} pool={pool} slot={slot:?}"
+                    "📦 partition_key={partition_key} assigned shard={pid} pool={pool} slot={slot:?} strategy={:?}",
+                    plan.strategy
                 );
 
                 MetricEvent::completed(MetricOperation::Assign, MetricReason::ExistingCapacity);
@@ -227,10 +239,11 @@ impl ShardingWorkflow {
     pub fn plan_assign_to_pool(
         pool: &str,
         partition_key: impl AsRef<str>,
-    ) -> Result<ShardingPlanStateResponse, InternalError> {
+    ) -> Result<ShardingPlanResponse, InternalError> {
         let pool_cfg = Self::get_shard_pool_cfg(pool)?;
         let partition_key = partition_key.as_ref();
         ShardingRegistryOps::validate_assignment_key(pool, partition_key)?;
+        let strategy = PlacementStrategyOps::resolve(pool, &pool_cfg.policy.strategy)?;
 
         let active = ShardingLifecycleOps::active_shards();
         if active.is_empty() {
@@ -263,11 +276,10 @@ impl ShardingWorkflow {
             metrics: &metrics,
             entries: &entry_views,
             assignments: &assignment_views,
+            strategy: strategy.as_ref(),
         };
 
         let plan = ShardingPolicy::plan_assign(&state, partition_key, None);
-        Ok(ShardingPlanStateResponseMapper::plan_to_response(
-            plan.state,
-        ))
+        Ok(ShardingPlanResponseMapper::plan_to_response(plan))
     }
 }
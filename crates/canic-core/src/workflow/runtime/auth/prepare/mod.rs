@@ -379,10 +379,10 @@ mod tests {
         Principal::from_slice(&[id; 29])
     }
 
-    fn grant(role: &str, scopes: &[&str]) -> DelegatedRoleGrant {
+    fn grant(role: &str, scopes: &[cap::Capability]) -> DelegatedRoleGrant {
         DelegatedRoleGrant {
             target: CanisterRole::owned(role.to_string()),
-            scopes: scopes.iter().map(|scope| (*scope).to_string()).collect(),
+            scopes: scopes.iter().map(ToString::to_string).collect(),
         }
     }
 
@@ -398,7 +398,10 @@ mod tests {
             metadata: Some(meta(metadata_id, 60_000_000_000)),
             subject: p(8),
             aud: DelegationAudience::Project("test".to_string()),
-            grants: vec![grant("project_instance", &["canic.verify"])],
+            grants: vec![grant(
+                "project_instance",
+                &[cap::Capability::new("canic.verify")],
+            )],
             ttl_ns: 30_000_000_000,
             ext: None,
         }
@@ -0,0 +1,162 @@
+//! Module: workflow::runtime::health
+//!
+//! Responsibility: push child health reports to root on a jittered timer and project fleet health on root.
+//! Does not own: report transport, staleness thresholds, or registry storage.
+//! Boundary: the public health facade and root endpoints reach health state through this workflow.
+
+use crate::{
+    InternalError,
+    domain::{
+        policy::pure::health::{self as policy, HealthPolicyViolation},
+        runtime::TimerExecutionOutcome,
+    },
+    dto::{
+        error::Error,
+        health::{FleetHealthEntry, FleetHealthResponse, HealthReport},
+    },
+    ops::{
+        health::HealthOps,
+        ic::IcOps,
+        runtime::{env::EnvOps, health::HealthStateOps},
+        storage::registry::subnet::SubnetRegistryOps,
+    },
+    workflow::runtime::timer::{TimerDirective, TimerKey, TimerRunResult, TimerWorkflow},
+};
+use candid::Principal;
+use std::time::Duration;
+
+///
+/// HealthWorkflow
+///
+/// Child-side health reporter and root-side fleet health aggregator.
+///
+
+pub struct HealthWorkflow;
+
+impl HealthWorkflow {
+    /// Record the start time and, on non-root canisters, arm the report timer.
+    pub fn start() {
+        HealthStateOps::record_started(IcOps::now_nanos());
+        if EnvOps::is_root() {
+            return;
+        }
+
+        TimerWorkflow::schedule(TimerKey::HealthReport, next_report_delay(), || async {
+            Self::send_report().await
+        });
+    }
+
+    /// Set one application gauge sampled into every later report.
+    pub fn set_gauge(name: &str, value: u64) -> Result<(), InternalError> {
+        validate(policy::validate_gauge_name(name))?;
+        if !HealthStateOps::has_gauge(name) {
+            validate(policy::validate_gauge_count(
+                HealthStateOps::gauge_count().saturating_add(1),
+            ))?;
+        }
+
+        HealthStateOps::set_gauge(name, value);
+        Ok(())
+    }
+
+    pub fn remove_gauge(name: &str) -> bool {
+        HealthStateOps::remove_gauge(name)
+    }
+
+    /// Store the latest report from one registered child.
+    pub fn receive_root(reporter: Principal, report: HealthReport) -> Result<(), InternalError> {
+        EnvOps::require_root()?;
+        validate(policy::validate_gauge_count(report.gauges.len()))?;
+        for gauge in &report.gauges {
+            validate(policy::validate_gauge_name(&gauge.name))?;
+        }
+
+        HealthStateOps::record_report(reporter, report, IcOps::now_nanos());
+        HealthStateOps::retain_reports(|pid| SubnetRegistryOps::registration(*pid).is_some());
+        Ok(())
+    }
+
+    /// Latest report per registered child, flagged stale when overdue or missing.
+    pub fn fleet_health() -> Result<FleetHealthResponse, InternalError> {
+        EnvOps::require_root()?;
+
+        let now_ns = IcOps::now_nanos();
+        let entries = SubnetRegistryOps::data()
+            .entries
+            .into_iter()
+            .filter(|entry| entry.record.parent_pid.is_some())
+            .map(|entry| {
+                let received = HealthStateOps::report(&entry.pid);
+                let stale = received
+                    .as_ref()
+                    .is_none_or(|received| policy::is_stale(received.received_at_ns, now_ns));
+
+                FleetHealthEntry {
+                    pid: entry.pid,
+                    role: entry.record.role,
+                    received_at_ns: received.as_ref().map(|received| received.received_at_ns),
+                    report: received.map(|received| received.report),
+                    stale,
+                }
+            })
+            .collect();
+
+        Ok(FleetHealthResponse {
+            observed_at_ns: now_ns,
+            stale_after_secs: policy::HEALTH_REPORT_STALE_AFTER_SECS,
+            entries,
+        })
+    }
+
+    async fn send_report() -> TimerRunResult {
+        let directive = TimerDirective::RecurAfter(next_report_delay());
+        let root_pid = match EnvOps::root_pid() {
+            Ok(root_pid) => root_pid,
+            Err(err) => {
+                IcOps::println(&format!("health: root pid unavailable: {err}"));
+                return TimerRunResult::invariant_failure();
+            }
+        };
+
+        match HealthOps::report(root_pid, sample()).await {
+            Ok(()) => TimerRunResult::success(1, directive),
+            Err(err) => {
+                IcOps::println(&format!("health: report to root failed: {err}"));
+                TimerRunResult {
+                    outcome: TimerExecutionOutcome::RetryableFailure,
+                    work_count: 0,
+                    directive,
+                }
+            }
+        }
+    }
+}
+
+fn sample() -> HealthReport {
+    HealthReport {
+        cycles: IcOps::canister_cycle_balance().to_u128(),
+        heap_memory_pages: IcOps::heap_memory_pages(),
+        stable_memory_pages: IcOps::stable_memory_pages(),
+        canister_version: IcOps::canister_version(),
+        last_upgrade_at_ns: HealthStateOps::started_at_ns(),
+        reported_at_ns: IcOps::now_nanos(),
+        gauges: HealthStateOps::gauges(),
+    }
+}
+
+// Spread reports by canister id so children installed together do not report
+// to root in the same round.
+fn next_report_delay() -> Duration {
+    let seed = IcOps::canister_self()
+        .as_slice()
+        .iter()
+        .fold(IcOps::now_nanos(), |acc, byte| {
+            acc.rotate_left(5) ^ u64::from(*byte)
+        });
+
+    Duration::from_secs(policy::report_delay_secs(seed))
+}
+
+fn validate(result: Result<(), HealthPolicyViolation>) -> Result<(), InternalError> {
+    result.map_err(|violation| InternalError::public(Error::invalid(violation.to_string())))
+}
@@ -0,0 +1,241 @@
+//! Module: workflow::runtime::idempotency
+//!
+//! Responsibility: admit, replay, and commit idempotent update endpoint calls.
+//! Does not own: replay identity derivation, receipt storage, or response encoding.
+//! Boundary: endpoint dispatch calls this around `idempotent(...)` handlers.
+
+use crate::{
+    InternalError, InternalErrorOrigin,
+    model::{
+        idempotency::{IDEMPOTENT_RESPONSE_SCHEMA_VERSION, IdempotentCall},
+        replay::ReplayReceipt,
+    },
+    ops::{
+        ic::IcOps,
+        replay::receipt::{
+            ReplayReceiptDecision, ReplayReceiptReserveInput, ReplayReceiptRetentionError,
+            ReplayReceiptRetentionLimits, ReplayReceiptStoreError, ReplayReceiptToken,
+            abort_reserved_receipt, commit_staged_receipt_response,
+            reserve_or_replay_receipt_with_retention, stage_receipt_response,
+        },
+        storage::replay::ReplayReceiptOps,
+    },
+};
+
+const IDEMPOTENT_RETENTION_LIMITS: ReplayReceiptRetentionLimits = ReplayReceiptRetentionLimits {
+    max_active_per_actor: 64,
+    max_active_per_command_kind: 1_024,
+    purge_scan_limit: 64,
+};
+
+///
+/// IdempotentAdmission
+///
+/// Outcome of admitting one idempotent call: run the handler, or replay a stored response.
+///
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IdempotentAdmission {
+    Execute(Box<ReplayReceiptToken>),
+    Replay(Vec<u8>),
+}
+
+///
+/// IdempotencyWorkflow
+///
+
+pub struct IdempotencyWorkflow;
+
+impl IdempotencyWorkflow {
+    /// Reserve a receipt for a fresh call, or return the response stored by an earlier one.
+    pub fn admit(
+        call: IdempotentCall<'_>,
+        ttl_secs: u64,
+    ) -> Result<IdempotentAdmission, InternalError> {
+        let ttl_ns = ttl_secs
+            .checked_mul(1_000_000_000)
+            .filter(|ttl_ns| *ttl_ns > 0)
+            .ok_or_else(|| {
+                InternalError::invalid_input(format!("invalid idempotency ttl: {ttl_secs}s"))
+            })?;
+        let command_kind = call.command_kind();
+        let now_ns = IcOps::now_nanos();
+        let input = ReplayReceiptReserveInput::new(
+            command_kind.clone(),
+            call.operation_id(&command_kind),
+            call.actor(),
+            call.payload_hash(&command_kind),
+            now_ns,
+        )
+        .with_expires_at_ns(now_ns.saturating_add(ttl_ns));
+
+        let decision = match reserve(input.clone())? {
+            ReplayReceiptDecision::Expired => {
+                // An expired receipt no longer protects anything; clear it and start over.
+                let _ = ReplayReceiptOps::remove(ReplayReceiptOps::slot_key(
+                    &input.command_kind,
+                    input.operation_id,
+                ));
+                reserve(input)?
+            }
+            decision => decision,
+        };
+
+        map_decision(call.method, decision)
+    }
+
+    /// Store the encoded response so retries within the TTL replay it.
+    pub fn commit(
+        token: &ReplayReceiptToken,
+        response_bytes: Vec<u8>,
+    ) -> Result<(), InternalError> {
+        let now_ns = IcOps::now_nanos();
+        stage_receipt_response(
+            token,
+            IDEMPOTENT_RESPONSE_SCHEMA_VERSION,
+            response_bytes,
+            now_ns,
+        )
+        .map_err(map_store_error)?;
+        commit_staged_receipt_response(token, now_ns).map_err(map_store_error)?;
+
+        Ok(())
+    }
+
+    /// Release a reservation whose handler failed, so a retry runs the handler again.
+    pub fn abort(token: &ReplayReceiptToken) {
+        let _ = abort_reserved_receipt(token);
+    }
+}
+
+fn reserve(input: ReplayReceiptReserveInput) -> Result<ReplayReceiptDecision, InternalError> {
+    reserve_or_replay_receipt_with_retention(input, IDEMPOTENT_RETENTION_LIMITS).map_err(|err| {
+        match err {
+            ReplayReceiptRetentionError::ActorQuotaExceeded { max_retained, .. } => {
+                InternalError::resource_exhausted(format!(
+                    "idempotent response quota exceeded for caller; max_retained={max_retained}"
+                ))
+            }
+            ReplayReceiptRetentionError::CommandQuotaExceeded { max_retained, .. } => {
+                InternalError::resource_exhausted(format!(
+                    "idempotent response quota exceeded for endpoint; max_retained={max_retained}"
+                ))
+            }
+            ReplayReceiptRetentionError::Store(err) => map_store_error(err),
+        }
+    })
+}
+
+fn map_decision(
+    method: &str,
+    decision: ReplayReceiptDecision,
+) -> Result<IdempotentAdmission, InternalError> {
+    match decision {
+        ReplayReceiptDecision::Fresh(token) => Ok(IdempotentAdmission::Execute(Box::new(token))),
+        ReplayReceiptDecision::ReturnCommitted(receipt) => replay_response(receipt),
+        ReplayReceiptDecision::OperationInProgress => Err(InternalError::conflict(format!(
+            "{method}: an identical request is already in progress"
+        ))),
+        ReplayReceiptDecision::ActorMismatch => Err(InternalError::conflict(format!(
+            "{method}: idempotency key belongs to a different caller"
+        ))),
+        ReplayReceiptDecision::PayloadMismatch => Err(InternalError::conflict(format!(
+            "{method}: idempotency key was reused with different arguments"
+        ))),
+        ReplayReceiptDecision::Expired => Err(InternalError::conflict(format!(
+            "{method}: idempotency receipt expired during admission"
+        ))),
+        ReplayReceiptDecision::RecoveryRequired { reason, .. } => Err(InternalError::conflict(
+            format!("{method}: idempotent request requires recovery: {reason:?}"),
+        )),
+        ReplayReceiptDecision::PendingActorQuotaExceeded { max_pending, .. }
+        | ReplayReceiptDecision::PendingCommandQuotaExceeded { max_pending, .. } => {
+            Err(InternalError::resource_exhausted(format!(
+                "{method}: pending idempotent request quota exceeded; max_pending={max_pending}"
+            )))
+        }
+    }
+}
+
+fn replay_response(receipt: ReplayReceipt) -> Result<IdempotentAdmission, InternalError> {
+    if receipt.response_schema_version != Some(IDEMPOTENT_RESPONSE_SCHEMA_VERSION) {
+        return Err(InternalError::workflow(
+            InternalErrorOrigin::Workflow,
+            "idempotent replay receipt has an unsupported response schema",
+        ));
+    }
+
+    receipt
+        .response_bytes
+        .map(IdempotentAdmission::Replay)
+        .ok_or_else(|| map_store_error(ReplayReceiptStoreError::StagedResponseMissing))
+}
+
+fn map_store_error(err: ReplayReceiptStoreError) -> InternalError {
+    InternalError::workflow(
+        InternalErrorOrigin::Workflow,
+        format!("idempotent replay receipt: {err}"),
+    )
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cdk::types::Principal, dto::error::ErrorCode};
+
+    fn call<'a>(key: Option<&'a [u8]>, args: &'a [u8]) -> IdempotentCall<'a> {
+        IdempotentCall {
+            method: "transfer",
+            caller: Principal::from_slice(&[3; 29]),
+            key,
+            args,
+        }
+    }
+
+    fn execute(call: IdempotentCall<'_>) -> Box<ReplayReceiptToken> {
+        match IdempotencyWorkflow::admit(call, 60).expect("admit") {
+            IdempotentAdmission::Execute(token) => token,
+            IdempotentAdmission::Replay(_) => panic!("expected a fresh admission"),
+        }
+    }
+
+    #[test]
+    fn committed_response_is_replayed_for_a_retry() {
+        ReplayReceiptOps::reset_for_tests();
+        let token = execute(call(None, b"args"));
+        IdempotencyWorkflow::commit(&token, vec![7, 7]).expect("commit");
+
+        assert_eq!(
+            IdempotencyWorkflow::admit(call(None, b"args"), 60).expect("replay"),
+            IdempotentAdmission::Replay(vec![7, 7])
+        );
+    }
+
+    #[test]
+    fn reused_key_with_different_args_is_a_conflict() {
+        ReplayReceiptOps::reset_for_tests();
+        let token = execute(call(Some(b"k"), b"a"));
+        IdempotencyWorkflow::commit(&token, vec![1]).expect("commit");
+
+        let err =
+            IdempotencyWorkflow::admit(call(Some(b"k"), b"b"), 60).expect_err("payload mismatch");
+
+        assert_eq!(
+            err.public_error().map(|err| err.code),
+            Some(ErrorCode::Conflict)
+        );
+    }
+
+    #[test]
+    fn aborted_reservation_lets_the_retry_execute() {
+        ReplayReceiptOps::reset_for_tests();
+        let token = execute(call(None, b"retry"));
+        IdempotencyWorkflow::abort(&token);
+
+        let _ = execute(call(None, b"retry"));
+    }
+}
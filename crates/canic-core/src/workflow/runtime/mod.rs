@@ -7,10 +7,14 @@
 pub mod auth;
 pub mod cycles;
 pub mod fleet_activation;
+pub mod health;
+pub mod idempotency;
 pub mod install;
 pub mod intent;
 pub mod log;
 mod nonroot;
+pub mod outbox;
+pub mod query_cache;
 mod root;
 pub mod timer;
 
@@ -45,6 +49,9 @@ impl RuntimeWorkflow {
         workflow::runtime::log::LogRetentionWorkflow::start()?;
         workflow::runtime::cycles::CycleWorkflow::start()?;
         workflow::runtime::intent::IntentCleanupWorkflow::start()?;
+        workflow::runtime::outbox::OutboxWorkflow::start();
+        workflow::runtime::health::HealthWorkflow::start();
+        workflow::fsm::FsmWorkflow::start();
         Ok(())
     }
 
@@ -61,16 +68,31 @@ impl RuntimeWorkflow {
         workflow::runtime::log::LogRetentionWorkflow::start()?;
         workflow::runtime::cycles::CycleWorkflow::start()?;
         workflow::runtime::intent::IntentCleanupWorkflow::start()?;
+        workflow::runtime::outbox::OutboxWorkflow::start();
+        workflow::runtime::health::HealthWorkflow::start();
+        workflow::fsm::FsmWorkflow::start();
 
         // root-only services
         workflow::pool::scheduler::PoolSchedulerWorkflow::start();
         workflow::runtime::auth::RuntimeAuthWorkflow::reconcile_root_issuer_renewal()?;
+        workflow::event_bus::EventBusWorkflow::start()?;
         Ok(())
     }
 }
 
 pub(super) fn log_memory_summary() {
     crate::log!(Topic::Memory, Info, "💾 memory.registry: bootstrapped");
+
+    // Eager statics initialize before the log is ready, so failures surface here.
+    for failure in MemoryRegistryOps::eager_init_report().failures {
+        crate::log!(
+            Topic::Memory,
+            Error,
+            "eager static {} fell back: {}",
+            failure.name,
+            failure.error
+        );
+    }
 }
 
 fn init_post_upgrade_memory_registry() -> Result<(), InternalError> {
@@ -0,0 +1,199 @@
+//! Module: workflow::runtime::outbox
+//!
+//! Responsibility: persist outbox call descriptors and drain them on a timer with retries.
+//! Does not own: stable schemas, descriptor limits, or raw call mechanics.
+//! Boundary: the public outbox facade reaches outbox storage through this workflow.
+
+use crate::{
+    InternalError,
+    domain::policy::pure::outbox as policy,
+    dto::{
+        error::Error,
+        outbox::{OutboxAdminCommand, OutboxFailedEntry},
+        page::{Page, PageRequest},
+    },
+    ops::{
+        ic::{IcOps, outbox::OutboxOps},
+        storage::outbox::{OutboxEntry, OutboxStoreOps},
+    },
+    workflow::{
+        runtime::timer::{TimerDirective, TimerKey, TimerRunResult, TimerWorkflow},
+        view::paginate::paginate_vec,
+    },
+};
+use candid::Principal;
+
+const DISPATCH_BATCH_SIZE: usize = 16;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+///
+/// OutboxWorkflow
+///
+/// Per-canister owner of the durable call outbox.
+///
+
+pub struct OutboxWorkflow;
+
+impl OutboxWorkflow {
+    /// Reconstruct the dispatch deadline from the persisted outbox.
+    pub fn start() {
+        Self::reconcile();
+    }
+
+    /// Persist one call descriptor and arm the dispatcher.
+    ///
+    /// No await happens here, so the entry commits or rolls back together with
+    /// the caller's other state changes in the same message.
+    pub fn enqueue(
+        target: Principal,
+        method: &str,
+        args: Vec<u8>,
+        cycles: u128,
+    ) -> Result<u64, InternalError> {
+        policy::validate_call(method, args.len())
+            .map_err(|violation| InternalError::public(Error::invalid(violation.to_string())))?;
+
+        let entry_id = OutboxStoreOps::enqueue(target, method, args, cycles, IcOps::now_nanos());
+        Self::reconcile();
+        Ok(entry_id)
+    }
+
+    #[must_use]
+    pub fn pending_count() -> u64 {
+        OutboxStoreOps::pending_count()
+    }
+
+    #[must_use]
+    pub fn failed(page: PageRequest) -> Page<OutboxFailedEntry> {
+        paginate_vec(OutboxStoreOps::failed(), page)
+    }
+
+    /// Retry or discard one permanently-failed entry.
+    pub fn admin(command: OutboxAdminCommand) -> Result<(), InternalError> {
+        let (entry_id, found) = match command {
+            OutboxAdminCommand::DiscardFailed { entry_id } => {
+                (entry_id, OutboxStoreOps::discard_failed(entry_id))
+            }
+            OutboxAdminCommand::RetryFailed { entry_id } => (
+                entry_id,
+                OutboxStoreOps::retry_failed(entry_id, IcOps::now_nanos()),
+            ),
+        };
+        if !found {
+            return Err(InternalError::public(Error::not_found(format!(
+                "failed outbox entry {entry_id} not found"
+            ))));
+        }
+
+        Self::reconcile();
+        Ok(())
+    }
+
+    fn reconcile() {
+        TimerWorkflow::reconcile_at(
+            TimerKey::OutboxDispatch,
+            OutboxStoreOps::next_due_at_ns(),
+            || async { Self::run_due_batch().await },
+        );
+    }
+
+    async fn run_due_batch() -> TimerRunResult {
+        let due = OutboxStoreOps::due_entries(IcOps::now_nanos(), DISPATCH_BATCH_SIZE);
+        let mut dispatched = 0_u64;
+
+        for entry in due {
+            match OutboxOps::dispatch(&entry).await {
+                Ok(()) => {
+                    OutboxStoreOps::complete(&entry);
+                    dispatched += 1;
+                }
+                Err(err) => Self::record_failure(entry, err.to_string(), IcOps::now_nanos()),
+            }
+        }
+
+        let directive = next_directive(OutboxStoreOps::next_due_at_ns(), IcOps::now_nanos());
+        if dispatched == 0 {
+            TimerRunResult::no_work(directive)
+        } else {
+            TimerRunResult::success(dispatched, directive)
+        }
+    }
+
+    fn record_failure(entry: OutboxEntry, error: String, now_ns: u64) {
+        let attempts = entry.attempts().saturating_add(1);
+        if policy::attempts_exhausted(attempts) {
+            IcOps::println(&format!(
+                "outbox: entry {} to {}::{} failed permanently after {attempts} attempts: {error}",
+                entry.entry_id(),
+                entry.target(),
+                entry.method(),
+            ));
+            OutboxStoreOps::fail(entry, error);
+            return;
+        }
+
+        let backoff_ns = policy::retry_backoff_secs(attempts).saturating_mul(NANOS_PER_SECOND);
+        OutboxStoreOps::reschedule(entry, error, now_ns.saturating_add(backoff_ns));
+    }
+}
+
+const fn next_directive(next_due_at_ns: Option<u64>, now_ns: u64) -> TimerDirective {
+    match next_due_at_ns {
+        None => TimerDirective::Stop,
+        Some(due_at_ns) if due_at_ns <= now_ns => TimerDirective::ContinueImmediately,
+        Some(due_at_ns) => TimerDirective::ScheduleAt(due_at_ns),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::seams;
+
+    #[test]
+    fn failures_back_off_until_the_entry_is_marked_failed() {
+        let _guard = seams::lock();
+        OutboxStoreOps::reset_for_tests();
+        OutboxStoreOps::enqueue(seams::p(1), "notify", Vec::new(), 0, 0);
+
+        let mut now_ns = 0;
+        for attempt in 1..policy::OUTBOX_MAX_ATTEMPTS {
+            let entry = OutboxStoreOps::due_entries(now_ns, 1).remove(0);
+            OutboxWorkflow::record_failure(entry, "unreachable".to_string(), now_ns);
+
+            let retry_at = now_ns + policy::retry_backoff_secs(attempt) * NANOS_PER_SECOND;
+            assert_eq!(OutboxStoreOps::next_due_at_ns(), Some(retry_at));
+            now_ns = retry_at;
+        }
+
+        let entry = OutboxStoreOps::due_entries(now_ns, 1).remove(0);
+        OutboxWorkflow::record_failure(entry, "unreachable".to_string(), now_ns);
+        assert_eq!(OutboxStoreOps::next_due_at_ns(), None);
+        assert_eq!(
+            OutboxStoreOps::failed()[0].attempts,
+            policy::OUTBOX_MAX_ATTEMPTS
+        );
+        OutboxStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn oversized_descriptors_are_rejected_before_persisting() {
+        let _guard = seams::lock();
+        OutboxStoreOps::reset_for_tests();
+
+        let err = OutboxWorkflow::enqueue(
+            seams::p(1),
+            "notify",
+            vec![0; policy::OUTBOX_ARGS_MAX_BYTES + 1],
+            0,
+        )
+        .expect_err("oversized args must reject");
+
+        assert!(err.public_error().is_some());
+        assert_eq!(OutboxStoreOps::pending_count(), 0);
+    }
+}
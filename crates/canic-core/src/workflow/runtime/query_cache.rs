@@ -0,0 +1,226 @@
+//! Module: workflow::runtime::query_cache
+//!
+//! Responsibility: serve, fill, and invalidate cached query responses across the heap and stable tiers.
+//! Does not own: endpoint argument decoding, response encoding, or cache registration.
+//! Boundary: query dispatch reads through here; the public cache facade warms and invalidates.
+
+use crate::{
+    InternalError,
+    domain::policy::pure::query_cache as policy,
+    dto::error::Error,
+    ops::{
+        ic::IcOps,
+        runtime::query_cache::{CachedQuery, QueryCacheOps},
+        storage::query_cache::{CachedResponse, QueryCacheStoreOps},
+    },
+};
+
+///
+/// QueryCacheWorkflow
+///
+/// Two-tier cache for `cache(...)` query endpoints.
+///
+/// Replicated queries discard their state changes, so lookups only read;
+/// entries are filled by `warm` from update or timer context and dropped by
+/// `invalidate` when the data behind an endpoint changes.
+///
+
+pub struct QueryCacheWorkflow;
+
+impl QueryCacheWorkflow {
+    /// Make one `cache(...)` endpoint known to lookups and `warm`.
+    pub fn register(query: CachedQuery) {
+        QueryCacheOps::register(query);
+    }
+
+    /// Return the fresh cached response for one endpoint and canonical argument key.
+    #[must_use]
+    pub fn lookup(endpoint: &str, key: &[u8]) -> Option<Vec<u8>> {
+        Self::lookup_at(endpoint, key, IcOps::now_nanos())
+    }
+
+    /// Run a registered endpoint with Candid-encoded `args` and cache its response.
+    #[expect(
+        clippy::future_not_send,
+        reason = "cached query handlers run on the single-threaded IC executor"
+    )]
+    pub async fn warm(endpoint: &str, args: Vec<u8>) -> Result<(), InternalError> {
+        let query = Self::registration(endpoint)?;
+        let computed = (query.compute)(args).await.map_err(InternalError::public)?;
+
+        Self::store(&query, &computed.key, computed.response, IcOps::now_nanos())
+    }
+
+    /// Drop every cached response of one endpoint from both tiers.
+    pub fn invalidate(endpoint: &str) -> usize {
+        QueryCacheOps::remove_endpoint(endpoint) + QueryCacheStoreOps::remove_endpoint(endpoint)
+    }
+
+    fn registration(endpoint: &str) -> Result<CachedQuery, InternalError> {
+        QueryCacheOps::registration(endpoint).ok_or_else(|| {
+            InternalError::public(Error::not_found(format!(
+                "query endpoint '{endpoint}' is not cached"
+            )))
+        })
+    }
+
+    fn lookup_at(endpoint: &str, key: &[u8], now_ns: u64) -> Option<Vec<u8>> {
+        let digest = QueryCacheOps::digest(endpoint, key);
+        if let Some(entry) = QueryCacheOps::get(digest)
+            && policy::is_fresh(entry.expires_at_ns, now_ns)
+        {
+            return Some(entry.response);
+        }
+
+        QueryCacheOps::registration(endpoint)
+            .filter(|query| query.stable)
+            .and_then(|_| QueryCacheStoreOps::get(digest))
+            .filter(|entry| policy::is_fresh(entry.expires_at_ns, now_ns))
+            .map(|entry| entry.response)
+    }
+
+    fn store(
+        query: &CachedQuery,
+        key: &[u8],
+        response: Vec<u8>,
+        now_ns: u64,
+    ) -> Result<(), InternalError> {
+        policy::validate_response_len(response.len())
+            .map_err(|violation| InternalError::invalid_input(violation.to_string()))?;
+
+        let entry = CachedResponse {
+            endpoint: query.endpoint.to_string(),
+            response,
+            expires_at_ns: policy::expires_at_ns(now_ns, query.ttl_secs),
+        };
+        for (digest, evicted) in
+            QueryCacheOps::insert(QueryCacheOps::digest(query.endpoint, key), entry)
+        {
+            let spill = QueryCacheOps::registration(&evicted.endpoint)
+                .is_some_and(|query| query.stable)
+                && policy::is_fresh(evicted.expires_at_ns, now_ns);
+            if spill {
+                QueryCacheStoreOps::insert(digest, evicted, now_ns);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ops::runtime::query_cache::{CachedQueryFuture, CachedQueryResponse},
+        test::seams,
+    };
+    use futures::executor::block_on;
+
+    const NANOS: u64 = 1_000_000_000;
+
+    fn echo(args: Vec<u8>) -> CachedQueryFuture {
+        Box::pin(async move {
+            Ok(CachedQueryResponse {
+                key: args.clone(),
+                response: args,
+            })
+        })
+    }
+
+    fn register(endpoint: &'static str, stable: bool) {
+        QueryCacheOps::register(CachedQuery {
+            endpoint,
+            ttl_secs: 30,
+            stable,
+            compute: echo,
+        });
+    }
+
+    #[test]
+    fn warmed_responses_are_served_until_their_ttl() {
+        let _guard = seams::lock();
+        QueryCacheOps::reset_for_tests();
+        register("workflow_query_cache_ttl", false);
+
+        let before_ns = IcOps::now_nanos();
+        block_on(QueryCacheWorkflow::warm(
+            "workflow_query_cache_ttl",
+            vec![1],
+        ))
+        .expect("warm");
+        assert_eq!(
+            QueryCacheWorkflow::lookup("workflow_query_cache_ttl", &[1]),
+            Some(vec![1])
+        );
+        assert_eq!(
+            QueryCacheWorkflow::lookup_at("workflow_query_cache_ttl", &[1], before_ns + 31 * NANOS),
+            None
+        );
+        assert_eq!(
+            QueryCacheWorkflow::lookup("workflow_query_cache_ttl", &[2]),
+            None
+        );
+        QueryCacheOps::reset_for_tests();
+    }
+
+    #[test]
+    fn stable_endpoints_fall_back_to_spilled_entries_until_invalidated() {
+        let _guard = seams::lock();
+        QueryCacheOps::reset_for_tests();
+        QueryCacheStoreOps::reset_for_tests();
+        register("workflow_query_cache_stable", true);
+        let digest = QueryCacheOps::digest("workflow_query_cache_stable", &[3]);
+        QueryCacheStoreOps::insert(
+            digest,
+            CachedResponse {
+                endpoint: "workflow_query_cache_stable".to_string(),
+                response: vec![3],
+                expires_at_ns: NANOS,
+            },
+            0,
+        );
+
+        assert_eq!(
+            QueryCacheWorkflow::lookup_at("workflow_query_cache_stable", &[3], 0),
+            Some(vec![3])
+        );
+        assert_eq!(
+            QueryCacheWorkflow::invalidate("workflow_query_cache_stable"),
+            1
+        );
+        assert_eq!(
+            QueryCacheWorkflow::lookup_at("workflow_query_cache_stable", &[3], 0),
+            None
+        );
+        QueryCacheStoreOps::reset_for_tests();
+    }
+
+    #[test]
+    fn unregistered_and_oversized_responses_are_rejected() {
+        let _guard = seams::lock();
+        QueryCacheOps::reset_for_tests();
+        register("workflow_query_cache_oversized", false);
+
+        assert!(
+            block_on(QueryCacheWorkflow::warm(
+                "workflow_query_cache_missing",
+                Vec::new()
+            ))
+            .is_err()
+        );
+        let oversized = vec![0; policy::QUERY_CACHE_ENTRY_MAX_BYTES + 1];
+        assert!(
+            block_on(QueryCacheWorkflow::warm(
+                "workflow_query_cache_oversized",
+                oversized
+            ))
+            .is_err()
+        );
+        QueryCacheOps::reset_for_tests();
+    }
+}
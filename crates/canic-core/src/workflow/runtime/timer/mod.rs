@@ -8,6 +8,7 @@ mod control;
 
 use self::control::{TimerControl, TimerControlAction, TimerRegistration};
 use crate::{
+    domain::cron::CronSchedule,
     domain::runtime::{
         TimerExecutionOutcome, TimerMode, TimerProcessCondition, TimerRegistrationStatus,
         TimerSchedulingMode,
@@ -39,8 +40,12 @@ thread_local! {
 pub enum TimerKey {
     AuthRenewal,
     CycleTopup,
+    EventBusDelivery,
+    FsmStep,
+    HealthReport,
     IntentCleanup,
     LogRetention,
+    OutboxDispatch,
     PlacementReceiptAcknowledgement,
     PoolReset,
 }
@@ -50,8 +55,12 @@ impl TimerKey {
         match self {
             Self::AuthRenewal => "auth_renewal:run",
             Self::CycleTopup => "cycles:topup",
+            Self::EventBusDelivery => "event_bus:delivery",
+            Self::FsmStep => "fsm:step",
+            Self::HealthReport => "health:report",
             Self::IntentCleanup => "intent_cleanup:run",
             Self::LogRetention => "log_retention:run",
+            Self::OutboxDispatch => "outbox:dispatch",
             Self::PlacementReceiptAcknowledgement => "placement:receipt_ack",
             Self::PoolReset => "pool:pending",
         }
@@ -312,6 +321,48 @@ impl TimerWorkflow {
         ApplicationTimerId(id)
     }
 
+    /// Schedule a cancellable application task at each occurrence of a cron schedule.
+    ///
+    /// Each completed run arms the next occurrence after the current IC time,
+    /// so recurrences never drift. Occurrences missed while the canister was
+    /// stopped or upgrading are skipped rather than replayed. Returns `None`
+    /// when the schedule has no future occurrence.
+    pub fn set_application_schedule<F, Fut>(
+        schedule: CronSchedule,
+        label: impl Into<String>,
+        task: F,
+    ) -> Option<ApplicationTimerId>
+    where
+        F: FnMut() -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let first_deadline_ns = next_cron_deadline_ns(&schedule, IcOps::now_nanos())?;
+        let id = next_application_timer_id();
+        let mut task = task;
+        let factory = timer_factory(move || {
+            let future = task();
+            async move {
+                future.await;
+                let directive = next_cron_deadline_ns(&schedule, IcOps::now_nanos())
+                    .map_or(TimerDirective::Stop, TimerDirective::ScheduleAt);
+                TimerRunResult::success(1, directive)
+            }
+        });
+        let identity = TimerIdentity::Application(id);
+        insert_entry(
+            identity,
+            TimerEntry::new(
+                label.into(),
+                TimerMode::Interval,
+                TimerSchedulingMode::Deadline,
+                false,
+                factory,
+            ),
+        );
+        request_at(identity, first_deadline_ns);
+        Some(ApplicationTimerId(id))
+    }
+
     /// Consume an application timer identity and suppress any future invocation.
     #[must_use]
     pub fn cancel_application(id: ApplicationTimerId) -> bool {
@@ -741,6 +792,12 @@ fn next_application_timer_id() -> u64 {
     })
 }
 
+fn next_cron_deadline_ns(schedule: &CronSchedule, now_ns: u64) -> Option<u64> {
+    schedule
+        .next_after_secs(now_ns / 1_000_000_000)?
+        .checked_mul(1_000_000_000)
+}
+
 fn deadline_after(now_ns: u64, delay: Duration) -> Result<u64, &'static str> {
     let delay_ns = u64::try_from(delay.as_nanos()).map_err(|_| "timer delay exceeds u64 nanos")?;
     now_ns
@@ -761,13 +818,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cron_deadline_is_the_next_whole_minute_occurrence() {
+        let schedule = CronSchedule::parse("*/5 * * * *").expect("valid schedule");
+
+        assert_eq!(
+            next_cron_deadline_ns(&schedule, 301_500_000_000),
+            Some(600_000_000_000)
+        );
+    }
+
     #[test]
     fn fixed_timer_labels_are_unique_and_low_cardinality() {
         let keys = [
             TimerKey::AuthRenewal,
             TimerKey::CycleTopup,
+            TimerKey::EventBusDelivery,
+            TimerKey::FsmStep,
+            TimerKey::HealthReport,
             TimerKey::IntentCleanup,
             TimerKey::LogRetention,
+            TimerKey::OutboxDispatch,
             TimerKey::PlacementReceiptAcknowledgement,
             TimerKey::PoolReset,
         ];
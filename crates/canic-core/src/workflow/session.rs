@@ -0,0 +1,93 @@
+//! Module: workflow::session
+//!
+//! Responsibility: read sessions opened by verified delegated tokens.
+//! Does not own: session creation, idle-timeout enforcement, or stable schemas.
+//! Boundary: the session facade and controller session endpoint read sessions through this workflow.
+
+use crate::{
+    cdk::types::Principal,
+    domain::policy::pure::session as policy,
+    dto::{
+        page::{Page, PageRequest},
+        session::SessionEntry,
+    },
+    ops::storage::session::{Session, SessionStoreOps},
+    workflow::view::paginate::paginate_vec,
+};
+
+///
+/// SessionWorkflow
+///
+/// Sessions recorded when `auth::authenticated(...)` verifies a token.
+///
+/// Expired sessions are hidden here and replaced on the next verification.
+///
+
+pub struct SessionWorkflow;
+
+impl SessionWorkflow {
+    #[must_use]
+    pub fn current(subject: Principal, now_ns: u64) -> Option<SessionEntry> {
+        SessionStoreOps::get(subject)
+            .filter(|session| !policy::is_expired(session.expires_at_ns, now_ns))
+            .map(Session::into_entry)
+    }
+
+    #[must_use]
+    pub fn list(page: PageRequest, now_ns: u64) -> Page<SessionEntry> {
+        let entries = SessionStoreOps::entries()
+            .into_iter()
+            .filter(|entry| !policy::is_expired(entry.expires_at_ns, now_ns))
+            .collect();
+
+        paginate_vec(entries, page)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ops::storage::session::VerifiedSession, test::seams};
+
+    fn open(subject: Principal, expires_at_ns: u64, now_ns: u64) {
+        SessionStoreOps::observe(
+            VerifiedSession {
+                subject,
+                issuer_pid: seams::p(9),
+                scopes: Vec::new(),
+                token_issued_at_ns: 1,
+                expires_at_ns,
+            },
+            now_ns,
+        );
+    }
+
+    #[test]
+    fn expired_sessions_are_not_current_or_listed() {
+        let _guard = seams::lock();
+        SessionStoreOps::reset_for_tests();
+        open(seams::p(1), 50, 10);
+        open(seams::p(2), 500, 10);
+
+        assert!(SessionWorkflow::current(seams::p(1), 60).is_none());
+        assert_eq!(
+            SessionWorkflow::current(seams::p(2), 60).map(|entry| entry.created_at_ns),
+            Some(10)
+        );
+
+        let page = SessionWorkflow::list(
+            PageRequest {
+                limit: 10,
+                offset: 0,
+            },
+            60,
+        );
+        assert_eq!(page.total, 1);
+        assert_eq!(page.entries[0].principal, seams::p(2));
+        SessionStoreOps::reset_for_tests();
+    }
+}
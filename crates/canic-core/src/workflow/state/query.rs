@@ -4,7 +4,10 @@
 //! Does not own: state storage mutation, endpoint authorization, or DTO schemas.
 //! Boundary: workflow query facade over state storage ops.
 
-use crate::{dto::state::FleetStateResponse, ops::storage::state::fleet::FleetStateOps};
+use crate::{
+    dto::state::{FleetMode, FleetStateResponse},
+    ops::storage::state::fleet::FleetStateOps,
+};
 
 ///
 /// FleetStateQuery
@@ -17,4 +20,10 @@ impl FleetStateQuery {
     pub fn snapshot() -> FleetStateResponse {
         FleetStateOps::snapshot_response()
     }
+
+    /// Current local Fleet mode, as last cascaded from root.
+    #[must_use]
+    pub fn mode() -> FleetMode {
+        FleetStateOps::get_mode()
+    }
 }
@@ -5,7 +5,12 @@
 //! Boundary: workflow query facade over registry storage ops.
 
 use crate::{
-    dto::topology::SubnetRegistryResponse, ops::storage::registry::subnet::SubnetRegistryOps,
+    dto::{
+        page::{Page, PageRequest},
+        topology::{CanisterFilter, CanisterSummary, SubnetRegistryResponse},
+    },
+    ops::storage::registry::subnet::SubnetRegistryOps,
+    workflow::view::paginate::paginate_vec,
 };
 
 ///
@@ -18,4 +23,8 @@ impl SubnetRegistryQuery {
     pub fn registry() -> SubnetRegistryResponse {
         SubnetRegistryOps::response()
     }
+
+    pub fn list(filter: &CanisterFilter, page: PageRequest) -> Page<CanisterSummary> {
+        paginate_vec(SubnetRegistryOps::summaries(filter), page)
+    }
 }
@@ -86,15 +86,15 @@ fn direct_ic_timer_access_has_one_production_owner() {
 
 fn expected_scheduling_inventory() -> BTreeMap<String, usize> {
     BTreeMap::from([
-        ("crates/canic/src/macros/start.rs".to_string(), 7),
+        ("crates/canic/src/macros/start.rs".to_string(), 5),
         ("crates/canic/src/api/mod.rs".to_string(), 1),
-        ("crates/canic/src/macros/timer.rs".to_string(), 2),
+        ("crates/canic/src/macros/timer.rs".to_string(), 3),
         (
             "crates/canic-control-plane/src/api/lifecycle.rs".to_string(),
             2,
         ),
         ("crates/canic-core/src/api/runtime/mod.rs".to_string(), 1),
-        ("crates/canic-core/src/api/timer.rs".to_string(), 3),
+        ("crates/canic-core/src/api/timer.rs".to_string(), 4),
         (
             "crates/canic-core/src/lifecycle/init/nonroot.rs".to_string(),
             1,
@@ -104,6 +104,12 @@ fn expected_scheduling_inventory() -> BTreeMap<String, usize> {
             1,
         ),
         ("crates/canic-core/src/ops/runtime/timer.rs".to_string(), 2),
+        ("crates/canic-core/src/workflow/event_bus.rs".to_string(), 1),
+        ("crates/canic-core/src/workflow/fsm.rs".to_string(), 1),
+        (
+            "crates/canic-core/src/workflow/memory/migrate.rs".to_string(),
+            1,
+        ),
         (
             "crates/canic-core/src/workflow/placement/acknowledgement.rs".to_string(),
             2,
@@ -120,6 +126,10 @@ fn expected_scheduling_inventory() -> BTreeMap<String, usize> {
             "crates/canic-core/src/workflow/runtime/cycles/mod.rs".to_string(),
             3,
         ),
+        (
+            "crates/canic-core/src/workflow/runtime/health.rs".to_string(),
+            1,
+        ),
         (
             "crates/canic-core/src/workflow/runtime/intent.rs".to_string(),
             2,
@@ -128,6 +138,10 @@ fn expected_scheduling_inventory() -> BTreeMap<String, usize> {
             "crates/canic-core/src/workflow/runtime/log.rs".to_string(),
             1,
         ),
+        (
+            "crates/canic-core/src/workflow/runtime/outbox.rs".to_string(),
+            1,
+        ),
         (
             "crates/canic-core/src/workflow/runtime/timer/mod.rs".to_string(),
             3,
@@ -146,10 +160,6 @@ fn expected_wait_inventory() -> BTreeMap<String, usize> {
             2,
         ),
         ("crates/canic-host/src/icp/command.rs".to_string(), 1),
-        (
-            "crates/canic-host/src/install_root/readiness/mod.rs".to_string(),
-            1,
-        ),
     ])
 }
 
@@ -246,23 +246,27 @@ fn expr_from_builtin(pred: &BuiltinPredicate) -> TokenStream2 {
         BuiltinPredicate::CallerIsWhitelisted => {
             quote!(::canic::__internal::core::access::expr::caller::is_whitelisted())
         }
-        BuiltinPredicate::Authenticated { required_scope } => match required_scope {
-            Some(AuthScopeArg::Literal(required_scope)) => quote!(
-                ::canic::__internal::core::access::expr::auth::authenticated_with_scope(
-                    #required_scope
+        BuiltinPredicate::Authenticated { required_scope } => {
+            if let Some(AuthScopeArg(required_scope)) = required_scope {
+                quote!(
+                    ::canic::__internal::core::access::expr::auth::authenticated_with_scope(
+                        #required_scope
+                    )
                 )
-            ),
-            Some(AuthScopeArg::Expr(required_scope)) => quote!(
-                ::canic::__internal::core::access::expr::auth::authenticated_with_scope(
-                    #required_scope
+            } else {
+                quote!(
+                    ::canic::__internal::core::access::expr::auth::authenticated(
+                        ::core::option::Option::None
+                    )
                 )
-            ),
-            None => quote!(
-                ::canic::__internal::core::access::expr::auth::authenticated(
-                    ::core::option::Option::None
-                )
-            ),
-        },
+            }
+        }
+        BuiltinPredicate::HasRole { role } => {
+            quote!(::canic::__internal::core::access::expr::auth::has_role(#role))
+        }
+        BuiltinPredicate::SessionActive { idle_timeout_secs } => {
+            quote!(::canic::__internal::core::access::expr::auth::session_active(#idle_timeout_secs))
+        }
         BuiltinPredicate::BuildIcOnly => {
             quote!(::canic::__internal::core::access::expr::env::build_ic_only())
         }
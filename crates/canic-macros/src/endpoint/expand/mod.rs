@@ -1,6 +1,10 @@
 mod access;
 
-use crate::endpoint::{EndpointKind, parse::QueryMode, validate::ValidatedArgs};
+use crate::endpoint::{
+    EndpointKind,
+    parse::{CacheKey, QueryMode},
+    validate::ValidatedArgs,
+};
 use access::{AccessPlan, access_stage, build_access_plan, requires_authenticated};
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
@@ -47,7 +51,12 @@ pub fn expand(kind: EndpointKind, args: ValidatedArgs, mut func: ItemFn) -> Toke
 
     let cdk_attr = cdk_attr(kind, &args.forwarded);
     let payload_registration = payload_registration(kind, &args, &orig_name);
-    let dispatch_fn = dispatch(kind, wrapper_async);
+    let dispatch_fn = dispatch(
+        kind,
+        wrapper_async,
+        args.idempotency.is_some(),
+        args.cache.is_some(),
+    );
 
     let wrapper_sig = syn::Signature {
         ident: orig_name.clone(),
@@ -72,17 +81,34 @@ pub fn expand(kind: EndpointKind, args: ValidatedArgs, mut func: ItemFn) -> Toke
         Err(e) => return e.to_compile_error(),
     };
 
+    let (idempotency_key, dispatch_args) = if args.cache.is_some() {
+        cache_stage(&args, &call_ident, &call_args)
+    } else {
+        idempotency_stage(&args, &call_ident)
+    };
+    let cache_registration = match cache_registration(
+        &args,
+        &orig_sig,
+        &exported_method,
+        &impl_name,
+        &call_args,
+        returns_fallible,
+    ) {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error(),
+    };
     let dispatch_call = dispatch_call(
         wrapper_async,
         impl_async,
         dispatch_fn,
-        &call_ident,
+        &dispatch_args,
         impl_name,
         &call_args,
     );
 
     quote! {
         #payload_registration
+        #cache_registration
 
         #(#attrs)*
         #[expect(clippy::missing_const_for_fn, clippy::unnecessary_wraps)]
@@ -91,6 +117,7 @@ pub fn expand(kind: EndpointKind, args: ValidatedArgs, mut func: ItemFn) -> Toke
             #call_decl
             ::canic::__internal::core::dispatch::preflight_endpoint(#call_ident);
             #access_stage
+            #idempotency_key
             #dispatch_call
         }
 
@@ -119,7 +146,25 @@ fn returns_fallible(sig: &syn::Signature) -> bool {
         .is_some_and(|seg| seg.ident == "Result")
 }
 
-fn dispatch(kind: EndpointKind, asyncness: bool) -> TokenStream2 {
+fn dispatch(kind: EndpointKind, asyncness: bool, idempotent: bool, cached: bool) -> TokenStream2 {
+    if cached {
+        return if asyncness {
+            quote!(::canic::__internal::core::dispatch::query_cache::dispatch_cached_query_async)
+        } else {
+            quote!(::canic::__internal::core::dispatch::query_cache::dispatch_cached_query)
+        };
+    }
+
+    if idempotent {
+        return if asyncness {
+            quote!(
+                ::canic::__internal::core::dispatch::idempotency::dispatch_idempotent_update_async
+            )
+        } else {
+            quote!(::canic::__internal::core::dispatch::idempotency::dispatch_idempotent_update)
+        };
+    }
+
     match (kind, asyncness) {
         (EndpointKind::Query, false) => {
             quote!(::canic::__internal::core::dispatch::dispatch_query)
@@ -228,31 +273,169 @@ fn first_typed_arg_ident(sig: &Signature) -> Option<syn::Ident> {
 // ============================================================================
 //
 
+// Own the explicit idempotency key before the handler arguments move into dispatch.
+fn idempotency_stage(args: &ValidatedArgs, call: &syn::Ident) -> (TokenStream2, TokenStream2) {
+    let Some(idempotency) = &args.idempotency else {
+        return (quote!(), quote!(#call));
+    };
+
+    let key_ident = format_ident!("__canic_idempotency_key");
+    let key = idempotency.key.as_ref().map_or_else(
+        || quote!(::core::option::Option::None),
+        |key| {
+            quote!(::core::option::Option::Some(
+                ::core::convert::AsRef::<[u8]>::as_ref(&#key).to_vec()
+            ))
+        },
+    );
+    let ttl_secs = idempotency.ttl_secs;
+
+    (
+        quote! {
+            let #key_ident: ::core::option::Option<::std::vec::Vec<u8>> = #key;
+        },
+        quote!(#call, #ttl_secs, #key_ident.as_deref()),
+    )
+}
+
+// Encode the keyed arguments before they move into dispatch.
+fn cache_stage(
+    args: &ValidatedArgs,
+    call: &syn::Ident,
+    call_args: &[TokenStream2],
+) -> (TokenStream2, TokenStream2) {
+    let Some(cache) = &args.cache else {
+        return (quote!(), quote!(#call));
+    };
+
+    let key_ident = format_ident!("__canic_cache_key");
+    let keyed = cache_key_args(cache.key, call_args);
+
+    (
+        quote! {
+            let #key_ident: ::core::option::Option<::std::vec::Vec<u8>> =
+                ::canic::__internal::core::dispatch::query_cache::cache_key((#(&#keyed,)*));
+        },
+        quote!(#call, #key_ident.as_deref()),
+    )
+}
+
+const fn cache_key_args(key: CacheKey, call_args: &[TokenStream2]) -> &[TokenStream2] {
+    match key {
+        CacheKey::Args => call_args,
+        CacheKey::None => &[],
+    }
+}
+
+// Register the cache settings and a compute entry point that `CacheApi::warm`
+// runs outside query context to fill the cache.
+fn cache_registration(
+    args: &ValidatedArgs,
+    sig: &Signature,
+    method_name: &TokenStream2,
+    impl_name: &syn::Ident,
+    call_args: &[TokenStream2],
+    fallible: bool,
+) -> syn::Result<TokenStream2> {
+    let Some(cache) = &args.cache else {
+        return Ok(quote!());
+    };
+
+    let name = &sig.ident;
+    let compute_name = format_ident!("__canic_cache_compute_{}", name);
+    let ctor_name = format_ident!("__canic_ctor_cache_{}", name);
+    let arg_types = sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            syn::FnArg::Typed(pat) => Ok(&*pat.ty),
+            syn::FnArg::Receiver(r) => Err(syn::Error::new_spanned(
+                r,
+                "`self` not supported in canic endpoints",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let keyed = cache_key_args(cache.key, call_args);
+    let ttl_secs = cache.ttl_secs;
+    let stable = cache.stable;
+    let await_impl = sig.asyncness.map(|_| quote!(.await));
+    let refuse_error = fallible.then(|| {
+        quote! {
+            if ::core::result::Result::is_err(&__canic_response) {
+                return ::core::result::Result::Err(
+                    ::canic::__internal::core::dto::error::Error::conflict(
+                        "cached query handler returned an error; response not cached",
+                    ),
+                );
+            }
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            fn #compute_name(
+                __canic_args: ::std::vec::Vec<u8>,
+            ) -> ::canic::__internal::core::dispatch::query_cache::CachedQueryFuture {
+                ::std::boxed::Box::pin(async move {
+                    let (#(#call_args,)*): (#(#arg_types,)*) =
+                        ::canic::__internal::core::dispatch::query_cache::decode_warm_args(
+                            &__canic_args,
+                        )?;
+                    let __canic_key =
+                        ::canic::__internal::core::dispatch::query_cache::cache_key((#(&#keyed,)*));
+                    let __canic_response = #impl_name(#(#call_args),*)#await_impl;
+                    #refuse_error
+                    ::canic::__internal::core::dispatch::query_cache::cached_response(
+                        __canic_key,
+                        &__canic_response,
+                    )
+                })
+            }
+
+            #[ ::canic::__internal::core::__reexports::ctor::ctor(
+                unsafe,
+                anonymous,
+                crate_path = ::canic::__internal::core::__reexports::ctor
+            ) ]
+            fn #ctor_name() {
+                ::canic::__internal::core::dispatch::query_cache::register_cached_query(
+                    ::canic::__internal::core::dispatch::query_cache::CachedQuery {
+                        endpoint: #method_name,
+                        ttl_secs: #ttl_secs,
+                        stable: #stable,
+                        compute: #compute_name,
+                    },
+                );
+            }
+        };
+    })
+}
+
 fn dispatch_call(
     wrapper_async: bool,
     impl_async: bool,
     dispatch: TokenStream2,
-    call: &syn::Ident,
+    dispatch_args: &TokenStream2,
     impl_name: syn::Ident,
     args: &[TokenStream2],
 ) -> TokenStream2 {
     if wrapper_async {
         if impl_async {
             quote! {
-                #dispatch(#call, || async move {
+                #dispatch(#dispatch_args, || async move {
                     #impl_name(#(#args),*).await
                 }).await
             }
         } else {
             quote! {
-                #dispatch(#call, || async move {
+                #dispatch(#dispatch_args, || async move {
                     #impl_name(#(#args),*)
                 }).await
             }
         }
     } else {
         quote! {
-            #dispatch(#call, || {
+            #dispatch(#dispatch_args, || {
                 #impl_name(#(#args),*)
             })
         }
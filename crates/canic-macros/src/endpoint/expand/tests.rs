@@ -6,6 +6,8 @@ fn make_args(requires: Vec<AccessExprAst>) -> ValidatedArgs {
         forwarded: Vec::new(),
         export_name: None,
         payload_max_bytes: None,
+        idempotency: None,
+        cache: None,
         requires,
         internal: false,
         query_mode: QueryMode::Plain,
@@ -33,6 +35,77 @@ fn update_expansion_registers_payload_limit_for_exported_name() {
     assert!(expanded.contains("64 * 1024"));
 }
 
+#[test]
+fn idempotent_expansion_dispatches_through_replay_cache_with_owned_key() {
+    let mut args = make_args(Vec::new());
+    args.idempotency = Some(crate::endpoint::parse::IdempotencyArgs {
+        ttl_secs: 600,
+        key: Some(format_ident!("request_id")),
+    });
+    let func: ItemFn = syn::parse_quote!(
+        fn pay(request_id: String, amount: u64) -> Result<u64, ::canic::Error> {
+            Ok(amount)
+        }
+    );
+
+    let expanded = expand(EndpointKind::Update, args, func).to_string();
+    let compact = expanded.split_whitespace().collect::<String>();
+
+    assert!(compact.contains("idempotency::dispatch_idempotent_update(__canic_call,600u64,"));
+    assert!(compact.contains("AsRef::<[u8]>::as_ref(&request_id).to_vec()"));
+    assert!(compact.find("__canic_idempotency_key") < compact.find("dispatch_idempotent_update"));
+}
+
+#[test]
+fn cached_query_expansion_keys_by_args_and_registers_compute() {
+    let mut args = make_args(Vec::new());
+    args.cache = Some(crate::endpoint::parse::CacheArgs {
+        ttl_secs: 30,
+        key: CacheKey::Args,
+        stable: true,
+    });
+    let func: ItemFn = syn::parse_quote!(
+        fn dashboard(page: u64) -> Result<Vec<u64>, ::canic::Error> {
+            Ok(vec![page])
+        }
+    );
+
+    let expanded = expand(EndpointKind::Query, args, func).to_string();
+    let compact = expanded.split_whitespace().collect::<String>();
+
+    assert!(compact.contains("query_cache::cache_key((&page,))"));
+    assert!(
+        compact.contains(
+            "query_cache::dispatch_cached_query(__canic_call,__canic_cache_key.as_deref(),"
+        )
+    );
+    assert!(compact.contains("register_cached_query"));
+    assert!(compact.contains("ttl_secs:30u64,stable:true,compute:__canic_cache_compute_dashboard"));
+    assert!(compact.contains("let(page,):(u64,)="));
+    assert!(expanded.contains("response not cached"));
+}
+
+#[test]
+fn cached_query_without_key_shares_one_entry() {
+    let mut args = make_args(Vec::new());
+    args.cache = Some(crate::endpoint::parse::CacheArgs {
+        ttl_secs: 60,
+        key: CacheKey::None,
+        stable: false,
+    });
+    let func: ItemFn = syn::parse_quote!(
+        fn totals(window: u64) -> Result<u64, ::canic::Error> {
+            Ok(window)
+        }
+    );
+
+    let expanded = expand(EndpointKind::Query, args, func).to_string();
+    let compact = expanded.split_whitespace().collect::<String>();
+
+    assert!(compact.contains("query_cache::cache_key(())"));
+    assert!(compact.contains("let(window,):(u64,)="));
+}
+
 #[test]
 fn composite_query_expansion_forwards_cdk_attr_and_call_kind() {
     let mut args = make_args(Vec::new());
@@ -115,6 +188,44 @@ fn access_stage_expr_builds_context_from_resolved_identity() {
     assert!(compact.contains("identity_source:__canic_authenticated_identity.identity_source"));
 }
 
+#[test]
+fn has_role_expands_to_the_runtime_acl_predicate() {
+    let sig: Signature = syn::parse_quote!(fn ping() -> Result<(), ::canic::Error>);
+    let args = make_args(vec![AccessExprAst::Pred(AccessPredicateAst::Builtin(
+        BuiltinPredicate::HasRole {
+            role: syn::parse_quote!("admin"),
+        },
+    ))]);
+    let plan = build_access_plan(EndpointKind::Update, &args, &sig).expect("access plan");
+    let call = format_ident!("__canic_call");
+    let compact = access_stage(&plan, &call)
+        .to_string()
+        .split_whitespace()
+        .collect::<String>();
+
+    assert!(compact.contains("::canic::__internal::core::access::expr::auth::has_role(\"admin\")"));
+}
+
+#[test]
+fn session_active_expands_with_idle_timeout_seconds() {
+    let sig: Signature = syn::parse_quote!(fn ping() -> Result<(), ::canic::Error>);
+    let args = make_args(vec![AccessExprAst::Pred(AccessPredicateAst::Builtin(
+        BuiltinPredicate::SessionActive {
+            idle_timeout_secs: 900,
+        },
+    ))]);
+    let plan = build_access_plan(EndpointKind::Update, &args, &sig).expect("access plan");
+    let call = format_ident!("__canic_call");
+    let compact = access_stage(&plan, &call)
+        .to_string()
+        .split_whitespace()
+        .collect::<String>();
+
+    assert!(
+        compact.contains("::canic::__internal::core::access::expr::auth::session_active(900u64)")
+    );
+}
+
 #[test]
 fn access_stage_default_guard_marks_identity_source_raw_caller() {
     let sig: Signature = syn::parse_quote!(fn ping() -> Result<(), ::canic::Error>);
@@ -134,7 +245,7 @@ fn access_stage_default_guard_marks_identity_source_raw_caller() {
 fn authenticated_endpoint_expansion_fences_before_access_and_dispatch() {
     let args = make_args(vec![AccessExprAst::Pred(AccessPredicateAst::Builtin(
         BuiltinPredicate::Authenticated {
-            required_scope: Some(AuthScopeArg::Literal(String::from("write"))),
+            required_scope: Some(AuthScopeArg(quote!(cap::WRITE))),
         },
     ))]);
     let func: ItemFn = syn::parse_quote!(
@@ -1,10 +1,11 @@
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{
-    Expr, Ident, LitStr, Meta, MetaNameValue, Path, Token, parse::Parser, punctuated::Punctuated,
+    Expr, ExprLit, Ident, Lit, LitStr, Meta, MetaNameValue, Path, Token, parse::Parser,
+    punctuated::Punctuated,
 };
 
-const ENDPOINT_ATTR_HELP: &str = "endpoint attributes must be expressed via requires(...), public, payload(...), internal, composite, or name = \"...\"";
+const ENDPOINT_ATTR_HELP: &str = "endpoint attributes must be expressed via requires(...), public, payload(...), idempotent(...), cache(...), internal, composite, or name = \"...\"";
 
 //
 // ============================================================================
@@ -22,12 +23,11 @@ const ENDPOINT_ATTR_HELP: &str = "endpoint attributes must be expressed via requ
 ///
 /// AuthScopeArg
 ///
+/// Path to a typed `Capability` constant (`cap::VERIFY` or a `canic::caps!` item).
+///
 
 #[derive(Clone, Debug)]
-pub enum AuthScopeArg {
-    Literal(String),
-    Expr(TokenStream2),
-}
+pub struct AuthScopeArg(pub TokenStream2);
 
 ///
 /// BuiltinPredicate
@@ -49,6 +49,12 @@ pub enum BuiltinPredicate {
     Authenticated {
         required_scope: Option<AuthScopeArg>,
     },
+    HasRole {
+        role: LitStr,
+    },
+    SessionActive {
+        idle_timeout_secs: u64,
+    },
     BuildIcOnly,
     BuildLocalOnly,
 }
@@ -91,6 +97,43 @@ impl QueryMode {
     }
 }
 
+///
+/// IdempotencyArgs
+///
+/// Parsed `idempotent(ttl = "...", key = arg)` clause; `ttl` is resolved to seconds.
+///
+
+#[derive(Clone, Debug)]
+pub struct IdempotencyArgs {
+    pub ttl_secs: u64,
+    pub key: Option<Ident>,
+}
+
+///
+/// CacheKey
+///
+/// Arguments that select a cached query response.
+///
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CacheKey {
+    Args,
+    None,
+}
+
+///
+/// CacheArgs
+///
+/// Parsed `cache(ttl = "...", key = "args" | "none", stable)` clause; `ttl` is resolved to seconds.
+///
+
+#[derive(Clone, Debug)]
+pub struct CacheArgs {
+    pub ttl_secs: u64,
+    pub key: CacheKey,
+    pub stable: bool,
+}
+
 ///
 /// ParsedArgs
 ///
@@ -100,6 +143,8 @@ pub struct ParsedArgs {
     pub forwarded: Vec<TokenStream2>,
     pub export_name: Option<LitStr>,
     pub payload_max_bytes: Option<TokenStream2>,
+    pub idempotency: Option<IdempotencyArgs>,
+    pub cache: Option<CacheArgs>,
     pub requires: Vec<AccessExprAst>,
     pub internal: bool,
     pub public: bool,
@@ -124,6 +169,8 @@ pub fn parse_args(attr: TokenStream2) -> syn::Result<ParsedArgs> {
     let mut query_mode = QueryMode::Plain;
     let mut export_name = None;
     let mut payload_max_bytes = None;
+    let mut idempotency = None;
+    let mut cache = None;
 
     for meta in metas {
         match meta {
@@ -139,6 +186,24 @@ pub fn parse_args(attr: TokenStream2) -> syn::Result<ParsedArgs> {
                 }
                 payload_max_bytes = Some(parse_payload_max_bytes(&list)?);
             }
+            Meta::List(list) if list.path.is_ident("idempotent") => {
+                if idempotency.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        list,
+                        "idempotent(...) must appear only once",
+                    ));
+                }
+                idempotency = Some(parse_idempotency(&list)?);
+            }
+            Meta::List(list) if list.path.is_ident("cache") => {
+                if cache.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        list,
+                        "cache(...) must appear only once",
+                    ));
+                }
+                cache = Some(parse_cache(&list)?);
+            }
             Meta::Path(path) if path.is_ident("internal") => {
                 if internal {
                     return Err(syn::Error::new_spanned(
@@ -215,7 +280,7 @@ pub fn parse_args(attr: TokenStream2) -> syn::Result<ParsedArgs> {
             Meta::List(list) => {
                 return Err(syn::Error::new_spanned(
                     list,
-                    "unsupported endpoint clause; use requires(...) or payload(...)",
+                    "unsupported endpoint clause; use requires(...), payload(...), idempotent(...), or cache(...)",
                 ));
             }
             Meta::Path(path) => {
@@ -232,10 +297,12 @@ pub fn parse_args(attr: TokenStream2) -> syn::Result<ParsedArgs> {
         && !public
         && forwarded.is_empty()
         && payload_max_bytes.is_none()
+        && idempotency.is_none()
+        && cache.is_none()
     {
         return Err(syn::Error::new_spanned(
             attr,
-            "expected requires(...), public, internal, composite, name = \"...\", or payload(...)",
+            "expected requires(...), public, internal, composite, name = \"...\", payload(...), idempotent(...), or cache(...)",
         ));
     }
 
@@ -243,6 +310,8 @@ pub fn parse_args(attr: TokenStream2) -> syn::Result<ParsedArgs> {
         forwarded,
         export_name,
         payload_max_bytes,
+        idempotency,
+        cache,
         requires,
         internal,
         public,
@@ -297,6 +366,8 @@ const fn empty() -> ParsedArgs {
         forwarded: Vec::new(),
         export_name: None,
         payload_max_bytes: None,
+        idempotency: None,
+        cache: None,
         requires: Vec::new(),
         internal: false,
         public: false,
@@ -349,6 +420,112 @@ fn parse_payload_max_bytes(list: &syn::MetaList) -> syn::Result<TokenStream2> {
     })
 }
 
+fn parse_idempotency(list: &syn::MetaList) -> syn::Result<IdempotencyArgs> {
+    const HELP: &str = "expected idempotent(ttl = \"<n>s|m|h|d\", key = <argument>)";
+
+    let metas = Punctuated::<Meta, Token![,]>::parse_terminated
+        .parse2(list.tokens.clone())
+        .map_err(|_| syn::Error::new_spanned(list, HELP))?;
+
+    let mut ttl_secs = None;
+    let mut key = None;
+
+    for meta in metas {
+        match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("ttl") && ttl_secs.is_none() => {
+                let ttl = parse_string_literal(&nv, "idempotent ttl")?;
+                ttl_secs = Some(parse_ttl_secs(&ttl.value()).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        ttl,
+                        "idempotent ttl must be a positive duration like \"30s\", \"10m\", \"2h\", or \"1d\"",
+                    )
+                })?);
+            }
+            Meta::NameValue(nv) if nv.path.is_ident("key") && key.is_none() => {
+                let Expr::Path(expr) = &nv.value else {
+                    return Err(syn::Error::new_spanned(
+                        nv,
+                        "idempotent key must name an endpoint argument",
+                    ));
+                };
+                key = Some(expr.path.require_ident()?.clone());
+            }
+            other => return Err(syn::Error::new_spanned(other, HELP)),
+        }
+    }
+
+    let ttl_secs = ttl_secs
+        .ok_or_else(|| syn::Error::new_spanned(list, "idempotent(...) requires ttl = \"...\""))?;
+
+    Ok(IdempotencyArgs { ttl_secs, key })
+}
+
+fn parse_cache(list: &syn::MetaList) -> syn::Result<CacheArgs> {
+    const HELP: &str = "expected cache(ttl = \"<n>s|m|h|d\", key = \"args\" | \"none\", stable)";
+
+    let metas = Punctuated::<Meta, Token![,]>::parse_terminated
+        .parse2(list.tokens.clone())
+        .map_err(|_| syn::Error::new_spanned(list, HELP))?;
+
+    let mut ttl_secs = None;
+    let mut key = None;
+    let mut stable = false;
+
+    for meta in metas {
+        match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("ttl") && ttl_secs.is_none() => {
+                let ttl = parse_string_literal(&nv, "cache ttl")?;
+                ttl_secs = Some(parse_ttl_secs(&ttl.value()).ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        ttl,
+                        "cache ttl must be a positive duration like \"30s\", \"10m\", \"2h\", or \"1d\"",
+                    )
+                })?);
+            }
+            Meta::NameValue(nv) if nv.path.is_ident("key") && key.is_none() => {
+                let value = parse_string_literal(&nv, "cache key")?;
+                key = Some(match value.value().as_str() {
+                    "args" => CacheKey::Args,
+                    "none" => CacheKey::None,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "cache key must be \"args\" or \"none\"",
+                        ));
+                    }
+                });
+            }
+            Meta::Path(path) if path.is_ident("stable") && !stable => stable = true,
+            other => return Err(syn::Error::new_spanned(other, HELP)),
+        }
+    }
+
+    let ttl_secs = ttl_secs
+        .ok_or_else(|| syn::Error::new_spanned(list, "cache(...) requires ttl = \"...\""))?;
+
+    Ok(CacheArgs {
+        ttl_secs,
+        key: key.unwrap_or(CacheKey::Args),
+        stable,
+    })
+}
+
+// Parse `<n><unit>` with unit s/m/h/d into whole seconds.
+fn parse_ttl_secs(value: &str) -> Option<u64> {
+    let split = value.len().checked_sub(1)?;
+    let (amount, unit) = value.split_at_checked(split)?;
+    let amount: u64 = amount.parse().ok()?;
+    let scale = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        _ => return None,
+    };
+
+    amount.checked_mul(scale).filter(|secs| *secs > 0)
+}
+
 fn parse_expr_list(tokens: &TokenStream2) -> syn::Result<Vec<AccessExprAst>> {
     let exprs = Punctuated::<Expr, Token![,]>::parse_terminated
         .parse2(tokens.clone())
@@ -381,7 +558,6 @@ fn parse_expr(expr: Expr) -> syn::Result<AccessExprAst> {
     }
 }
 
-#[expect(clippy::too_many_lines)]
 fn parse_call_expr(call: syn::ExprCall) -> syn::Result<AccessExprAst> {
     let path = match *call.func {
         Expr::Path(expr) => expr.path,
@@ -433,48 +609,15 @@ fn parse_call_expr(call: syn::ExprCall) -> syn::Result<AccessExprAst> {
         }
         _ => {
             if is_authenticated_path(&path) {
-                let required_scope = match args.next() {
-                    None => None,
-                    Some(scope_expr) => {
-                        if args.next().is_some() {
-                            return Err(syn::Error::new_spanned(
-                                &path,
-                                "authenticated(...) accepts zero arguments or one string literal/path scope",
-                            ));
-                        }
-                        let scope = match scope_expr {
-                            Expr::Lit(expr_lit) => match &expr_lit.lit {
-                                syn::Lit::Str(scope_lit) => {
-                                    let value = scope_lit.value();
-                                    if value.trim().is_empty() {
-                                        return Err(syn::Error::new_spanned(
-                                            &path,
-                                            "authenticated(...) scope must not be empty",
-                                        ));
-                                    }
-                                    AuthScopeArg::Literal(value)
-                                }
-                                _ => {
-                                    return Err(syn::Error::new_spanned(
-                                        expr_lit,
-                                        "authenticated(...) scope must be a string literal or path constant",
-                                    ));
-                                }
-                            },
-                            Expr::Path(expr_path) => AuthScopeArg::Expr(quote::quote!(#expr_path)),
-                            other => {
-                                return Err(syn::Error::new_spanned(
-                                    other,
-                                    "authenticated(...) scope must be a string literal or path constant",
-                                ));
-                            }
-                        };
-                        Some(scope)
-                    }
-                };
-                return Ok(AccessExprAst::Pred(AccessPredicateAst::Builtin(
-                    BuiltinPredicate::Authenticated { required_scope },
-                )));
+                return parse_authenticated(&path, args);
+            }
+
+            if is_has_role_path(&path) {
+                return parse_has_role(&path, args);
+            }
+
+            if is_session_active_path(&path) {
+                return parse_session_active(&path, args);
             }
 
             if args.next().is_some() {
@@ -503,6 +646,84 @@ fn parse_call_expr(call: syn::ExprCall) -> syn::Result<AccessExprAst> {
     }
 }
 
+fn parse_authenticated(
+    path: &Path,
+    mut args: impl Iterator<Item = Expr>,
+) -> syn::Result<AccessExprAst> {
+    let required_scope = match args.next() {
+        None => None,
+        Some(scope_expr) => {
+            if args.next().is_some() {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    "authenticated(...) accepts zero arguments or one capability constant",
+                ));
+            }
+            let scope = match scope_expr {
+                Expr::Path(expr_path) => AuthScopeArg(quote::quote!(#expr_path)),
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "authenticated(...) scope must be a capability constant such as cap::VERIFY; declare app scopes with canic::caps!",
+                    ));
+                }
+            };
+            Some(scope)
+        }
+    };
+
+    Ok(AccessExprAst::Pred(AccessPredicateAst::Builtin(
+        BuiltinPredicate::Authenticated { required_scope },
+    )))
+}
+
+fn parse_has_role(path: &Path, mut args: impl Iterator<Item = Expr>) -> syn::Result<AccessExprAst> {
+    match (args.next(), args.next()) {
+        (
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(role),
+                ..
+            })),
+            None,
+        ) if !role.value().is_empty() => Ok(AccessExprAst::Pred(AccessPredicateAst::Builtin(
+            BuiltinPredicate::HasRole { role },
+        ))),
+        _ => Err(syn::Error::new_spanned(
+            path,
+            "has_role(...) requires one non-empty role name string such as \"admin\"",
+        )),
+    }
+}
+
+fn parse_session_active(
+    path: &Path,
+    mut args: impl Iterator<Item = Expr>,
+) -> syn::Result<AccessExprAst> {
+    let idle_timeout_secs = match (args.next(), args.next()) {
+        (
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(timeout),
+                ..
+            })),
+            None,
+        ) => parse_ttl_secs(&timeout.value()),
+        _ => None,
+    };
+
+    idle_timeout_secs
+        .map(|idle_timeout_secs| {
+            AccessExprAst::Pred(AccessPredicateAst::Builtin(
+                BuiltinPredicate::SessionActive { idle_timeout_secs },
+            ))
+        })
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                path,
+                "session_active(...) requires one idle timeout string such as \"15m\" (units s, m, h, d)",
+            )
+        })
+}
+
 fn parse_expr_args<I>(args: I) -> syn::Result<Vec<AccessExprAst>>
 where
     I: IntoIterator<Item = Expr>,
@@ -558,6 +779,14 @@ fn is_authenticated_path(path: &Path) -> bool {
     short_path_is(path, "auth", "authenticated")
 }
 
+fn is_has_role_path(path: &Path) -> bool {
+    short_path_is(path, "auth", "has_role")
+}
+
+fn is_session_active_path(path: &Path) -> bool {
+    short_path_is(path, "auth", "session_active")
+}
+
 fn is_bare_authenticated_path(path: &Path) -> bool {
     if path.leading_colon.is_some() {
         return false;
@@ -133,6 +133,50 @@ fn payload_max_bytes_is_parsed() {
     );
 }
 
+#[test]
+fn idempotent_ttl_and_key_are_parsed() {
+    let parsed = parse_args(quote!(public, idempotent(ttl = "10m", key = request_id)))
+        .expect("idempotent args should parse");
+    let idempotency = parsed.idempotency.expect("idempotency");
+
+    assert_eq!(idempotency.ttl_secs, 600);
+    assert_eq!(idempotency.key.expect("key"), "request_id");
+}
+
+#[test]
+fn idempotent_ttl_requires_a_unit_and_positive_amount() {
+    for ttl in ["10", "0s", "5w", ""] {
+        let err = parse_args(quote!(public, idempotent(ttl = #ttl))).expect_err("bad ttl");
+        assert!(err.to_string().contains("positive duration"), "{ttl}");
+    }
+}
+
+#[test]
+fn cache_ttl_key_and_stable_marker_are_parsed() {
+    let parsed = parse_args(quote!(public, cache(ttl = "30s", key = "none", stable)))
+        .expect("cache args should parse");
+    let cache = parsed.cache.expect("cache");
+
+    assert_eq!(cache.ttl_secs, 30);
+    assert_eq!(cache.key, CacheKey::None);
+    assert!(cache.stable);
+
+    let cache = parse_args(quote!(public, cache(ttl = "1m")))
+        .expect("cache args should parse")
+        .cache
+        .expect("cache");
+    assert_eq!((cache.key, cache.stable), (CacheKey::Args, false));
+}
+
+#[test]
+fn cache_rejects_unknown_keys_and_missing_ttl() {
+    let err = parse_args(quote!(public, cache(ttl = "30s", key = "caller"))).expect_err("bad key");
+    assert!(err.to_string().contains("\"args\" or \"none\""));
+
+    let err = parse_args(quote!(public, cache(stable))).expect_err("missing ttl");
+    assert!(err.to_string().contains("requires ttl"));
+}
+
 #[test]
 fn duplicate_name_is_rejected() {
     let err = parse_args(quote!(name = "a", name = "b")).expect_err("duplicate name");
@@ -162,35 +206,99 @@ fn authenticated_allows_no_scope_argument() {
 }
 
 #[test]
-fn grouped_access_expression_is_unwrapped() {
-    let parsed = parse_args(quote!(requires((caller::is_controller())))).expect("parse args");
+fn has_role_takes_one_role_name_inside_any() {
+    let parsed = parse_args(quote!(requires(any(
+        caller::is_controller(),
+        auth::has_role("admin")
+    ))))
+    .expect("parse args");
     let AccessExprAst::All(exprs) = &parsed.requires[0] else {
         panic!("expected requires(all)");
     };
-    let AccessExprAst::Pred(AccessPredicateAst::Builtin(BuiltinPredicate::CallerIsController)) =
-        &exprs[0]
+    let AccessExprAst::Any(any) = &exprs[0] else {
+        panic!("expected any(...)");
+    };
+    let AccessExprAst::Pred(AccessPredicateAst::Builtin(BuiltinPredicate::HasRole { role })) =
+        &any[1]
     else {
-        panic!("expected caller::is_controller predicate");
+        panic!("expected has_role predicate");
     };
+    assert_eq!(role.value(), "admin");
 }
 
 #[test]
-fn authenticated_allows_string_scope_argument() {
-    let parsed =
-        parse_args(quote!(requires(auth::authenticated("scope:test")))).expect("parse args");
+fn has_role_rejects_missing_or_non_string_roles() {
+    for args in [
+        quote!(requires(auth::has_role())),
+        quote!(requires(auth::has_role(""))),
+        quote!(requires(auth::has_role(ADMIN))),
+        quote!(requires(auth::has_role("admin", "support"))),
+    ] {
+        let err = parse_args(args).expect_err("invalid has_role must fail");
+        assert!(
+            err.to_string()
+                .contains("has_role(...) requires one non-empty role name string")
+        );
+    }
+}
+
+#[test]
+fn session_active_parses_idle_timeout_into_seconds() {
+    let parsed = parse_args(quote!(requires(
+        auth::authenticated(),
+        auth::session_active("15m")
+    )))
+    .expect("parse args");
     let AccessExprAst::All(exprs) = &parsed.requires[0] else {
         panic!("expected requires(all)");
     };
-    let AccessExprAst::Pred(AccessPredicateAst::Builtin(BuiltinPredicate::Authenticated {
-        required_scope,
-    })) = &exprs[0]
+    let AccessExprAst::Pred(AccessPredicateAst::Builtin(BuiltinPredicate::SessionActive {
+        idle_timeout_secs,
+    })) = &exprs[1]
     else {
-        panic!("expected authenticated predicate");
+        panic!("expected session_active predicate");
     };
-    let Some(AuthScopeArg::Literal(required_scope)) = required_scope else {
-        panic!("expected literal scope");
+    assert_eq!(*idle_timeout_secs, 15 * 60);
+}
+
+#[test]
+fn session_active_rejects_missing_or_malformed_timeouts() {
+    for args in [
+        quote!(requires(auth::session_active())),
+        quote!(requires(auth::session_active("0s"))),
+        quote!(requires(auth::session_active("15"))),
+        quote!(requires(auth::session_active(900))),
+        quote!(requires(auth::session_active("15m", "1h"))),
+    ] {
+        let err = parse_args(args).expect_err("invalid session_active must fail");
+        assert!(
+            err.to_string()
+                .contains("session_active(...) requires one idle timeout string")
+        );
+    }
+}
+
+#[test]
+fn grouped_access_expression_is_unwrapped() {
+    let parsed = parse_args(quote!(requires((caller::is_controller())))).expect("parse args");
+    let AccessExprAst::All(exprs) = &parsed.requires[0] else {
+        panic!("expected requires(all)");
+    };
+    let AccessExprAst::Pred(AccessPredicateAst::Builtin(BuiltinPredicate::CallerIsController)) =
+        &exprs[0]
+    else {
+        panic!("expected caller::is_controller predicate");
     };
-    assert_eq!(required_scope, "scope:test");
+}
+
+#[test]
+fn authenticated_rejects_string_scope_argument() {
+    let err = parse_args(quote!(requires(auth::authenticated("scope:test"))))
+        .expect_err("string scopes must fail");
+    assert!(
+        err.to_string()
+            .contains("authenticated(...) scope must be a capability constant")
+    );
 }
 
 #[test]
@@ -206,8 +314,8 @@ fn authenticated_allows_path_scope_argument() {
     else {
         panic!("expected authenticated predicate");
     };
-    let Some(AuthScopeArg::Expr(required_scope)) = required_scope else {
-        panic!("expected expr scope");
+    let Some(AuthScopeArg(required_scope)) = required_scope else {
+        panic!("expected capability scope");
     };
     assert_eq!(required_scope.to_string(), "cap :: VERIFY");
 }
@@ -218,7 +326,7 @@ fn authenticated_rejects_multiple_arguments() {
         .expect_err("authenticated with two args must fail");
     assert!(
         err.to_string()
-            .contains("authenticated(...) accepts zero arguments or one string literal/path scope")
+            .contains("authenticated(...) accepts zero arguments or one capability constant")
     );
 }
 
@@ -1,6 +1,9 @@
 use crate::endpoint::{
     EndpointKind,
-    parse::{AccessExprAst, AccessPredicateAst, BuiltinPredicate, ParsedArgs, QueryMode},
+    parse::{
+        AccessExprAst, AccessPredicateAst, BuiltinPredicate, CacheArgs, IdempotencyArgs,
+        ParsedArgs, QueryMode,
+    },
 };
 use proc_macro2::TokenStream as TokenStream2;
 use syn::{FnArg, LitStr, Signature, Type};
@@ -25,6 +28,8 @@ pub struct ValidatedArgs {
     pub forwarded: Vec<TokenStream2>,
     pub export_name: Option<LitStr>,
     pub payload_max_bytes: Option<TokenStream2>,
+    pub idempotency: Option<IdempotencyArgs>,
+    pub cache: Option<CacheArgs>,
     pub requires: Vec<AccessExprAst>,
     pub internal: bool,
     pub query_mode: QueryMode,
@@ -45,6 +50,17 @@ pub fn validate(
         ));
     }
 
+    if let Some(idempotency) = &parsed.idempotency {
+        validate_idempotency(kind, idempotency, sig)?;
+    }
+
+    if parsed.cache.is_some() && matches!(kind, EndpointKind::Update) {
+        return Err(syn::Error::new_spanned(
+            &sig.ident,
+            "cache(...) is supported only on canic_query endpoints",
+        ));
+    }
+
     if parsed.query_mode.is_composite() && matches!(kind, EndpointKind::Update) {
         return Err(syn::Error::new_spanned(
             &sig.ident,
@@ -102,6 +118,8 @@ pub fn validate(
         forwarded: parsed.forwarded,
         export_name: parsed.export_name,
         payload_max_bytes: parsed.payload_max_bytes,
+        idempotency: parsed.idempotency,
+        cache: parsed.cache,
         requires: parsed.requires,
         internal: parsed.internal,
         query_mode: parsed.query_mode,
@@ -123,6 +141,41 @@ fn returns_fallible(sig: &Signature) -> bool {
         .is_some_and(|seg| seg.ident == "Result")
 }
 
+fn validate_idempotency(
+    kind: EndpointKind,
+    idempotency: &IdempotencyArgs,
+    sig: &Signature,
+) -> syn::Result<()> {
+    if matches!(kind, EndpointKind::Query) {
+        return Err(syn::Error::new_spanned(
+            &sig.ident,
+            "idempotent(...) is supported only on canic_update endpoints",
+        ));
+    }
+
+    if !returns_fallible(sig) {
+        return Err(syn::Error::new_spanned(
+            &sig.output,
+            "idempotent endpoints must return `Result<T, E>` where `E: From<canic::Error>`",
+        ));
+    }
+
+    if let Some(key) = &idempotency.key {
+        let names_arg = sig.inputs.iter().any(|input| {
+            matches!(input, FnArg::Typed(pat)
+                if matches!(&*pat.pat, syn::Pat::Ident(id) if id.ident == *key))
+        });
+        if !names_arg {
+            return Err(syn::Error::new_spanned(
+                key,
+                "idempotent key must name an endpoint argument",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn requires_authenticated(requires: &[AccessExprAst]) -> bool {
     requires.iter().any(access_expr_contains_authenticated)
 }
@@ -173,6 +226,8 @@ fn access_expr_contains_identity_predicate(expr: &AccessExprAst) -> bool {
                     | BuiltinPredicate::CallerIsRegisteredToSubnet
                     | BuiltinPredicate::CallerIsWhitelisted
                     | BuiltinPredicate::Authenticated { .. }
+                    | BuiltinPredicate::HasRole { .. }
+                    | BuiltinPredicate::SessionActive { .. }
             )
         }
         AccessExprAst::Pred(AccessPredicateAst::Custom(_)) => false,
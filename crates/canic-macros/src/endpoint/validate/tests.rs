@@ -6,6 +6,8 @@ fn parsed_authenticated() -> ParsedArgs {
         forwarded: Vec::new(),
         export_name: None,
         payload_max_bytes: None,
+        idempotency: None,
+        cache: None,
         requires: vec![AccessExprAst::Pred(AccessPredicateAst::Builtin(
             BuiltinPredicate::Authenticated {
                 required_scope: None,
@@ -22,6 +24,8 @@ fn parsed_registered_to_subnet(internal: bool) -> ParsedArgs {
         forwarded: Vec::new(),
         export_name: None,
         payload_max_bytes: None,
+        idempotency: None,
+        cache: None,
         requires: vec![AccessExprAst::Pred(AccessPredicateAst::Builtin(
             BuiltinPredicate::CallerIsRegisteredToSubnet,
         ))],
@@ -97,6 +101,8 @@ fn negated_caller_predicate_is_rejected() {
         forwarded: Vec::new(),
         export_name: None,
         payload_max_bytes: None,
+        idempotency: None,
+        cache: None,
         requires: vec![AccessExprAst::Not(Box::new(AccessExprAst::Pred(
             AccessPredicateAst::Builtin(BuiltinPredicate::CallerIsController),
         )))],
@@ -119,6 +125,8 @@ fn ungated_endpoint_without_public_marker_is_rejected() {
         forwarded: Vec::new(),
         export_name: None,
         payload_max_bytes: None,
+        idempotency: None,
+        cache: None,
         requires: Vec::new(),
         internal: false,
         public: false,
@@ -136,6 +144,8 @@ fn payload_limit_is_update_only() {
         forwarded: Vec::new(),
         export_name: None,
         payload_max_bytes: Some(quote::quote!(1024)),
+        idempotency: None,
+        cache: None,
         requires: Vec::new(),
         internal: false,
         public: true,
@@ -149,6 +159,82 @@ fn payload_limit_is_update_only() {
     );
 }
 
+fn idempotent_args(key: Option<&str>) -> ParsedArgs {
+    ParsedArgs {
+        forwarded: Vec::new(),
+        export_name: None,
+        payload_max_bytes: None,
+        idempotency: Some(IdempotencyArgs {
+            ttl_secs: 60,
+            key: key.map(|key| syn::Ident::new(key, proc_macro2::Span::call_site())),
+        }),
+        cache: None,
+        requires: Vec::new(),
+        internal: false,
+        public: true,
+        query_mode: QueryMode::Plain,
+    }
+}
+
+#[test]
+fn idempotent_is_update_only() {
+    let sig: Signature = syn::parse_quote!(fn hello() -> Result<u64, Error>);
+
+    let err = validate(EndpointKind::Query, idempotent_args(None), &sig, false).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("idempotent(...) is supported only on canic_update")
+    );
+}
+
+#[test]
+fn idempotent_key_must_name_an_argument() {
+    let sig: Signature = syn::parse_quote!(fn pay(request_id: String) -> Result<u64, Error>);
+
+    validate(
+        EndpointKind::Update,
+        idempotent_args(Some("request_id")),
+        &sig,
+        false,
+    )
+    .expect("key names an argument");
+    let err = validate(
+        EndpointKind::Update,
+        idempotent_args(Some("other")),
+        &sig,
+        false,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("must name an endpoint argument"));
+}
+
+#[test]
+fn cache_is_query_only() {
+    let sig: Signature = syn::parse_quote!(fn hello() -> u64);
+    let parsed = || ParsedArgs {
+        forwarded: Vec::new(),
+        export_name: None,
+        payload_max_bytes: None,
+        idempotency: None,
+        cache: Some(crate::endpoint::parse::CacheArgs {
+            ttl_secs: 30,
+            key: crate::endpoint::parse::CacheKey::Args,
+            stable: false,
+        }),
+        requires: Vec::new(),
+        internal: false,
+        public: true,
+        query_mode: QueryMode::Plain,
+    };
+
+    validate(EndpointKind::Query, parsed(), &sig, false).expect("cached query");
+    let err = validate(EndpointKind::Update, parsed(), &sig, false).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cache(...) is supported only on canic_query")
+    );
+}
+
 #[test]
 fn composite_query_marker_is_query_only() {
     let sig: Signature = syn::parse_quote!(fn hello() -> bool);
@@ -156,6 +242,8 @@ fn composite_query_marker_is_query_only() {
         forwarded: vec![quote::quote!(composite = true)],
         export_name: None,
         payload_max_bytes: None,
+        idempotency: None,
+        cache: None,
         requires: Vec::new(),
         internal: false,
         public: true,
@@ -1,7 +1,9 @@
 mod endpoint;
+mod model;
 
 use crate::endpoint::{EndpointKind, expand_entry};
 use proc_macro::TokenStream;
+use syn::parse_macro_input;
 
 /// Define a Canic query endpoint.
 ///
@@ -18,3 +20,25 @@ pub fn canic_query(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn canic_update(attr: TokenStream, item: TokenStream) -> TokenStream {
     expand_entry(EndpointKind::Update, attr, item)
 }
+
+/// Declare application stable maps, optionally with read-only endpoints.
+///
+/// Each model registers its memory through `ic_memory_key!` and becomes an
+/// eager `RefCell<BTreeMap<K, V, _>>` thread-local. An `endpoints(name, ...)`
+/// attribute emits `<name>_get`, `<name>_list`, and `<name>_count` queries;
+/// the remaining arguments are passed to `canic_query` unchanged, so the
+/// endpoints go through the same guard and auth pipeline.
+///
+/// ```ignore
+/// canic::canic_model! {
+///     /// Player profiles keyed by account id.
+///     #[endpoints(profiles, requires(caller::is_controller()))]
+///     pub static PROFILES: BTreeMap<u64, Profile> =
+///         memory(authority = "demo", key = "demo.profiles.v1", id = 120);
+/// }
+/// ```
+#[proc_macro]
+pub fn canic_model(input: TokenStream) -> TokenStream {
+    let defs = parse_macro_input!(input as model::ModelDefs);
+    model::expand(defs).into()
+}
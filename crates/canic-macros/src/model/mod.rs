@@ -0,0 +1,300 @@
+//! `canic_model!` declarations.
+//!
+//! Declares application stable maps through Canic's memory registration and,
+//! when asked, emits read-only endpoints routed through `canic_query`.
+//!
+//! The pipeline mirrors the endpoint macros: parse → expand. Access control is
+//! not interpreted here; endpoint arguments are forwarded verbatim so
+//! `canic_query` validates them exactly as it would a hand-written endpoint.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Expr, Ident, LitStr, MetaNameValue, Token, Type, TypePath, Visibility,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+///
+/// ModelDefs
+///
+/// Every model declared in one `canic_model!` invocation.
+///
+
+#[derive(Debug)]
+pub struct ModelDefs {
+    pub models: Vec<ModelDef>,
+}
+
+///
+/// ModelDef
+///
+/// One stable map declaration plus its optional endpoint surface.
+///
+
+#[derive(Debug)]
+pub struct ModelDef {
+    pub attrs: Vec<Attribute>,
+    pub endpoints: Option<EndpointsArgs>,
+    pub vis: Visibility,
+    pub ident: Ident,
+    pub key_ty: Type,
+    pub value_ty: TypePath,
+    pub memory: MemoryArgs,
+}
+
+///
+/// EndpointsArgs
+///
+/// Endpoint name prefix and the `canic_query` arguments applied to each endpoint.
+///
+
+#[derive(Debug)]
+pub struct EndpointsArgs {
+    pub prefix: Ident,
+    pub query_args: TokenStream2,
+}
+
+///
+/// MemoryArgs
+///
+/// Stable-memory slot forwarded to `ic_memory_key!`.
+///
+
+#[derive(Debug)]
+pub struct MemoryArgs {
+    pub authority: Expr,
+    pub stable_key: LitStr,
+    pub id: Expr,
+}
+
+impl Parse for ModelDefs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut models = Vec::new();
+        while !input.is_empty() {
+            models.push(input.parse()?);
+        }
+
+        Ok(Self { models })
+    }
+}
+
+impl Parse for ModelDef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = Vec::new();
+        let mut endpoints = None;
+        for attr in input.call(Attribute::parse_outer)? {
+            if !attr.path().is_ident("endpoints") {
+                attrs.push(attr);
+                continue;
+            }
+            if endpoints.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "endpoints(...) may be declared once per model",
+                ));
+            }
+            endpoints = Some(attr.parse_args::<EndpointsArgs>()?);
+        }
+
+        let vis = input.parse()?;
+        input.parse::<Token![static]>()?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+
+        let map_ident: Ident = input.parse()?;
+        if map_ident != "BTreeMap" {
+            return Err(syn::Error::new_spanned(
+                map_ident,
+                "canic_model! supports `BTreeMap<K, V>` models",
+            ));
+        }
+        input.parse::<Token![<]>()?;
+        let key_ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let value_ty = match input.parse::<Type>()? {
+            Type::Path(path) if path.qself.is_none() => path,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "model value type must be a named type",
+                ));
+            }
+        };
+        input.parse::<Token![>]>()?;
+        input.parse::<Token![=]>()?;
+
+        let memory_ident: Ident = input.parse()?;
+        if memory_ident != "memory" {
+            return Err(syn::Error::new_spanned(
+                memory_ident,
+                "expected memory(authority = ..., key = \"...\", id = ...)",
+            ));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let memory = parse_memory_args(&memory_ident, &content)?;
+        input.parse::<Token![;]>()?;
+
+        Ok(Self {
+            attrs,
+            endpoints,
+            vis,
+            ident,
+            key_ty,
+            value_ty,
+            memory,
+        })
+    }
+}
+
+impl Parse for EndpointsArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let prefix = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            prefix,
+            query_args: input.parse()?,
+        })
+    }
+}
+
+fn parse_memory_args(span: &Ident, input: ParseStream) -> syn::Result<MemoryArgs> {
+    let mut authority = None;
+    let mut stable_key = None;
+    let mut id = None;
+
+    for arg in Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)? {
+        let slot = if arg.path.is_ident("authority") {
+            &mut authority
+        } else if arg.path.is_ident("key") {
+            &mut stable_key
+        } else if arg.path.is_ident("id") {
+            &mut id
+        } else {
+            return Err(syn::Error::new_spanned(
+                arg.path,
+                "expected one of: authority, key, id",
+            ));
+        };
+        if slot.is_some() {
+            return Err(syn::Error::new_spanned(
+                arg.path,
+                "duplicate memory argument",
+            ));
+        }
+        *slot = Some(arg.value);
+    }
+
+    let missing =
+        |name: &str| syn::Error::new_spanned(span, format!("memory(...) requires `{name}`"));
+    let stable_key = match stable_key.ok_or_else(|| missing("key"))? {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => lit,
+        other => {
+            return Err(syn::Error::new_spanned(
+                other,
+                "memory key must be a string literal",
+            ));
+        }
+    };
+
+    Ok(MemoryArgs {
+        authority: authority.ok_or_else(|| missing("authority"))?,
+        stable_key,
+        id: id.ok_or_else(|| missing("id"))?,
+    })
+}
+
+/// Expand every model in declaration order.
+pub fn expand(defs: ModelDefs) -> TokenStream2 {
+    defs.models.into_iter().map(expand_model).collect()
+}
+
+fn expand_model(def: ModelDef) -> TokenStream2 {
+    let ModelDef {
+        attrs,
+        endpoints,
+        vis,
+        ident,
+        key_ty,
+        value_ty,
+        memory,
+    } = def;
+    let MemoryArgs {
+        authority,
+        stable_key,
+        id,
+    } = memory;
+    let structures = quote!(::canic::__internal::core::cdk::structures);
+
+    let endpoints =
+        endpoints.map(|endpoints| expand_endpoints(&ident, &key_ty, &value_ty, endpoints));
+
+    quote! {
+        ::canic::memory::eager_static! {
+            #(#attrs)*
+            #vis static #ident: ::std::cell::RefCell<
+                #structures::BTreeMap<
+                    #key_ty,
+                    #value_ty,
+                    #structures::memory::VirtualMemory<#structures::DefaultMemoryImpl>,
+                >
+            > = ::std::cell::RefCell::new(#structures::BTreeMap::init(
+                ::canic::memory::ic_memory_key!(
+                    authority = #authority,
+                    key = #stable_key,
+                    ty = #value_ty,
+                    id = #id,
+                ),
+            ));
+        }
+
+        #endpoints
+    }
+}
+
+fn expand_endpoints(
+    map: &Ident,
+    key_ty: &Type,
+    value_ty: &TypePath,
+    endpoints: EndpointsArgs,
+) -> TokenStream2 {
+    let EndpointsArgs { prefix, query_args } = endpoints;
+    let get = format_ident!("{prefix}_get");
+    let list = format_ident!("{prefix}_list");
+    let count = format_ident!("{prefix}_count");
+    let query = quote!(::canic::__internal::core::api::model::ModelQuery);
+
+    quote! {
+        #[::canic::canic_query(#query_args)]
+        async fn #get(
+            key: #key_ty,
+        ) -> ::std::result::Result<::std::option::Option<#value_ty>, ::canic::Error> {
+            ::std::result::Result::Ok(#map.with_borrow(|map| #query::get(map, &key)))
+        }
+
+        #[::canic::canic_query(#query_args)]
+        async fn #list(
+            page: ::canic::dto::page::PageRequest,
+        ) -> ::std::result::Result<
+            ::canic::dto::page::Page<::canic::dto::model::ModelEntry<#key_ty, #value_ty>>,
+            ::canic::Error,
+        > {
+            ::std::result::Result::Ok(#map.with_borrow(|map| #query::list(map, page)))
+        }
+
+        #[::canic::canic_query(#query_args)]
+        async fn #count() -> ::std::result::Result<u64, ::canic::Error> {
+            ::std::result::Result::Ok(#map.with_borrow(|map| #query::count(map)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
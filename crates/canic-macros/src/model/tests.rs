@@ -0,0 +1,87 @@
+use super::*;
+use quote::quote;
+
+fn parse(tokens: TokenStream2) -> syn::Result<ModelDefs> {
+    syn::parse2(tokens)
+}
+
+#[test]
+fn model_without_endpoints_declares_only_the_map() {
+    let defs = parse(quote! {
+        /// Profiles by account.
+        pub static PROFILES: BTreeMap<u64, Profile> =
+            memory(authority = "demo", key = "demo.profiles.v1", id = 120);
+    })
+    .expect("model should parse");
+
+    assert_eq!(defs.models.len(), 1);
+    let model = &defs.models[0];
+    assert_eq!(model.ident, "PROFILES");
+    assert_eq!(model.attrs.len(), 1);
+    assert!(model.endpoints.is_none());
+    assert_eq!(model.memory.stable_key.value(), "demo.profiles.v1");
+
+    let expanded = expand(defs).to_string();
+    assert!(expanded.contains("ic_memory_key"));
+    assert!(!expanded.contains("canic_query"));
+}
+
+#[test]
+fn endpoints_forward_query_args_to_each_generated_endpoint() {
+    let defs = parse(quote! {
+        #[endpoints(profiles, requires(caller::is_controller()))]
+        static PROFILES: BTreeMap<u64, Profile> =
+            memory(authority = "demo", key = "demo.profiles.v1", id = 120);
+
+        static NOTES: BTreeMap<u64, Note> =
+            memory(authority = "demo", key = "demo.notes.v1", id = 121);
+    })
+    .expect("models should parse");
+
+    assert_eq!(defs.models.len(), 2);
+    let endpoints = defs.models[0].endpoints.as_ref().expect("endpoints");
+    assert_eq!(endpoints.prefix, "profiles");
+    assert_eq!(
+        endpoints.query_args.to_string(),
+        quote!(requires(caller::is_controller())).to_string()
+    );
+
+    let expanded = expand(defs).to_string();
+    for name in ["profiles_get", "profiles_list", "profiles_count"] {
+        assert!(expanded.contains(name), "missing {name}");
+    }
+    assert_eq!(expanded.matches("canic_query").count(), 3);
+}
+
+#[test]
+fn memory_arguments_are_required_and_unique() {
+    let err = parse(quote! {
+        static PROFILES: BTreeMap<u64, Profile> = memory(authority = "demo", id = 120);
+    })
+    .expect_err("missing key must reject");
+    assert!(err.to_string().contains("requires `key`"));
+
+    let err = parse(quote! {
+        static PROFILES: BTreeMap<u64, Profile> =
+            memory(authority = "demo", key = "demo.profiles.v1", id = 120, id = 121);
+    })
+    .expect_err("duplicate id must reject");
+    assert!(err.to_string().contains("duplicate"));
+}
+
+#[test]
+fn non_map_and_unnamed_value_types_are_rejected() {
+    let err = parse(quote! {
+        static PROFILES: Cell<u64, Profile> =
+            memory(authority = "demo", key = "demo.profiles.v1", id = 120);
+    })
+    .expect_err("non-map model must reject");
+    assert!(err.to_string().contains("BTreeMap"));
+
+    let err = parse(quote! {
+        static PAIRS: BTreeMap<u64, (u64, u64)> =
+            memory(authority = "demo", key = "demo.pairs.v1", id = 120);
+    })
+    .expect_err("tuple value must reject");
+    assert!(err.to_string().contains("named type"));
+}
@@ -0,0 +1,217 @@
+//! Root + children PocketIC cluster builder for internal integration suites.
+
+use candid::{CandidType, Principal, utils::ArgumentEncoder};
+use canic::ids::{BuildNetwork, CanisterRole};
+use ic_testkit::pic::{Pic, PicCallError};
+use serde::de::DeserializeOwned;
+use std::{collections::HashMap, path::PathBuf};
+
+use super::{
+    CanicWasmBuildProfile, RootBaselineSpec, ensure_root_release_artifacts_built, load_root_wasm,
+    setup_root_topology,
+};
+
+const DEFAULT_BOOTSTRAP_TICK_LIMIT: usize = 120;
+const DEFAULT_ROOT_SETUP_MAX_ATTEMPTS: usize = 2;
+const DEFAULT_POCKET_IC_WASM_CHUNK_STORE_LIMIT_BYTES: usize = 100 * 1024 * 1024;
+const DEFAULT_ARTIFACT_WATCH_PATHS: &[&str] = &[
+    "Cargo.toml",
+    "Cargo.lock",
+    "canisters",
+    "apps/test",
+    "icp.yaml",
+    "crates",
+];
+
+///
+/// TestClusterBuilder
+///
+/// Describes one root + child release set; `build` compiles the Wasms, installs
+/// root, stages the child releases, and drives the bootstrap handshake.
+///
+
+pub struct TestClusterBuilder {
+    workspace_root: PathBuf,
+    config_path: PathBuf,
+    release_roles: &'static [&'static str],
+    build_profile: CanicWasmBuildProfile,
+    build_extra_env: Vec<(String, String)>,
+    progress_prefix: &'static str,
+    bootstrap_tick_limit: usize,
+}
+
+impl TestClusterBuilder {
+    /// Start a cluster for the workspace at `workspace_root` using `config_path` as `canic.toml`.
+    #[must_use]
+    pub fn new(workspace_root: impl Into<PathBuf>, config_path: impl Into<PathBuf>) -> Self {
+        Self {
+            workspace_root: workspace_root.into(),
+            config_path: config_path.into(),
+            release_roles: &[],
+            build_profile: CanicWasmBuildProfile::Fast,
+            build_extra_env: Vec::new(),
+            progress_prefix: "test_cluster",
+            bootstrap_tick_limit: DEFAULT_BOOTSTRAP_TICK_LIMIT,
+        }
+    }
+
+    /// Child roles built and staged alongside root.
+    #[must_use]
+    pub const fn roles(mut self, release_roles: &'static [&'static str]) -> Self {
+        self.release_roles = release_roles;
+        self
+    }
+
+    #[must_use]
+    pub const fn build_profile(mut self, build_profile: CanicWasmBuildProfile) -> Self {
+        self.build_profile = build_profile;
+        self
+    }
+
+    /// Extra environment passed to the Wasm build (for example `RUSTFLAGS`).
+    #[must_use]
+    pub fn build_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.build_extra_env.push((key.into(), value.into()));
+        self
+    }
+
+    #[must_use]
+    pub const fn progress_prefix(mut self, progress_prefix: &'static str) -> Self {
+        self.progress_prefix = progress_prefix;
+        self
+    }
+
+    #[must_use]
+    pub const fn bootstrap_tick_limit(mut self, bootstrap_tick_limit: usize) -> Self {
+        self.bootstrap_tick_limit = bootstrap_tick_limit;
+        self
+    }
+
+    /// Project this cluster onto the root baseline spec used by the cached harnesses.
+    #[must_use]
+    pub fn spec(&self) -> RootBaselineSpec<'static> {
+        let mut build_extra_env = self.build_extra_env.clone();
+        if build_extra_env
+            .iter()
+            .all(|(key, _)| key != "CANIC_REFERENCE_CANISTERS")
+        {
+            let mut build_canisters = self
+                .release_roles
+                .iter()
+                .map(|role| (*role).to_string())
+                .collect::<Vec<_>>();
+            build_canisters.push("root".to_string());
+            build_extra_env.push((
+                "CANIC_REFERENCE_CANISTERS".to_string(),
+                build_canisters.join(" "),
+            ));
+        }
+
+        let artifacts_dir = self
+            .workspace_root
+            .join(".icp")
+            .join("local")
+            .join("canisters");
+        let root_wasm_path = artifacts_dir.join("root").join("root.wasm.gz");
+
+        RootBaselineSpec {
+            progress_prefix: self.progress_prefix,
+            root_wasm_path: root_wasm_path.clone(),
+            root_wasm_artifact_path: root_wasm_path,
+            root_release_artifacts_dir: artifacts_dir,
+            artifact_watch_paths: DEFAULT_ARTIFACT_WATCH_PATHS,
+            release_roles: self.release_roles,
+            icp_build_lock_path: self
+                .workspace_root
+                .join(".icp")
+                .join("canic-tests-build.lock"),
+            workspace_root: self.workspace_root.clone(),
+            build_network: BuildNetwork::Local,
+            build_profile: self.build_profile,
+            build_config_path: self.config_path.clone(),
+            build_extra_env,
+            bootstrap_tick_limit: self.bootstrap_tick_limit,
+            root_setup_max_attempts: DEFAULT_ROOT_SETUP_MAX_ATTEMPTS,
+            pocket_ic_wasm_chunk_store_limit_bytes: DEFAULT_POCKET_IC_WASM_CHUNK_STORE_LIMIT_BYTES,
+            root_release_chunk_bytes: canic::CANIC_WASM_CHUNK_BYTES,
+            package_version: env!("CARGO_PKG_VERSION"),
+        }
+    }
+
+    /// Build the Wasms, install root, and wait until root and every child report ready.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Wasms cannot be built or loaded, or if root setup fails;
+    /// see [`setup_root_topology`].
+    #[must_use]
+    pub fn build(self) -> TestCluster {
+        let spec = self.spec();
+        ensure_root_release_artifacts_built(&spec);
+        let root_wasm = load_root_wasm(&spec).expect("load root wasm");
+        let topology = setup_root_topology(&spec, root_wasm);
+
+        TestCluster {
+            pic: topology.pic,
+            root_id: topology.metadata.root_id,
+            subnet_index: topology.metadata.subnet_index,
+        }
+    }
+}
+
+///
+/// TestCluster
+///
+/// A bootstrapped root and its children, with typed call helpers.
+///
+
+pub struct TestCluster {
+    pub pic: Pic,
+    pub root_id: Principal,
+    pub subnet_index: HashMap<CanisterRole, Principal>,
+}
+
+impl TestCluster {
+    /// Resolve the principal of one child role created during bootstrap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if root did not register a canister for `role`.
+    #[must_use]
+    pub fn pid(&self, role: &'static str) -> Principal {
+        self.subnet_index
+            .get(&CanisterRole::new(role))
+            .copied()
+            .unwrap_or_else(|| panic!("{role} canister must be registered"))
+    }
+
+    /// Update call as `caller`, decoding the Candid reply as `T`.
+    pub fn call_as<T, A>(
+        &self,
+        pid: Principal,
+        caller: Principal,
+        method: &str,
+        args: A,
+    ) -> Result<T, PicCallError>
+    where
+        T: CandidType + DeserializeOwned,
+        A: ArgumentEncoder,
+    {
+        self.pic.update_call_as(pid, caller, method, args)
+    }
+
+    /// Query call as `caller`, decoding the Candid reply as `T`.
+    pub fn query_as<T, A>(
+        &self,
+        pid: Principal,
+        caller: Principal,
+        method: &str,
+        args: A,
+    ) -> Result<T, PicCallError>
+    where
+        T: CandidType + DeserializeOwned,
+        A: ArgumentEncoder,
+    {
+        self.pic.query_call_as(pid, caller, method, args)
+    }
+}
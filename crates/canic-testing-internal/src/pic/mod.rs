@@ -4,9 +4,11 @@ mod artifacts;
 mod attestation;
 mod audit;
 mod canic;
+mod cluster;
 mod delegation;
 mod lifecycle;
 mod root;
+mod time;
 
 pub use artifacts::{CanicWasmBuildProfile, build_internal_test_wasm_canisters};
 pub use attestation::{BaselinePicGuard, CachedInstalledRoot, install_test_root_cached};
@@ -18,6 +20,7 @@ pub use canic::{
     CanicPicExt, install_standalone_canister, install_standalone_canister_on_pic,
     managed_test_init_identity, role_pid, wait_until_ready,
 };
+pub use cluster::{TestCluster, TestClusterBuilder};
 pub use delegation::{
     create_user_shard, issue_delegated_token_from_active_proof,
     issue_delegated_token_from_active_proof_with_request_nonce, role_grant,
@@ -30,3 +33,4 @@ pub use root::{
     ensure_root_release_artifacts_built, load_root_wasm, restore_root_cached_baseline,
     setup_root_topology,
 };
+pub use time::TimeController;
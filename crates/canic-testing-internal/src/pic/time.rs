@@ -0,0 +1,91 @@
+//! Deterministic PocketIC time control for TTL- and timer-driven Canic tests.
+
+use candid::Principal;
+use canic::{
+    Error,
+    dto::{auth::DelegatedTokenClaims, runtime::CanicRuntimeStatus},
+    protocol,
+};
+use ic_testkit::pic::Pic;
+use std::time::Duration;
+
+const DEFAULT_SETTLE_TICKS: usize = 4;
+
+///
+/// TimeController
+///
+/// Moves PocketIC time to Canic-visible deadlines and then ticks enough rounds
+/// for due timers (and any calls they issue) to run.
+///
+
+pub struct TimeController<'a> {
+    pic: &'a Pic,
+    settle_ticks: usize,
+}
+
+impl<'a> TimeController<'a> {
+    #[must_use]
+    pub const fn new(pic: &'a Pic) -> Self {
+        Self {
+            pic,
+            settle_ticks: DEFAULT_SETTLE_TICKS,
+        }
+    }
+
+    /// Rounds executed after every time jump.
+    #[must_use]
+    pub const fn settle_ticks(mut self, settle_ticks: usize) -> Self {
+        self.settle_ticks = settle_ticks;
+        self
+    }
+
+    #[must_use]
+    pub fn now_ns(&self) -> u64 {
+        self.pic.current_time_nanos()
+    }
+
+    /// Advance by `duration`, then settle.
+    pub fn advance(&self, duration: Duration) {
+        self.pic.advance_time(duration);
+        self.pic.tick_n(self.settle_ticks);
+    }
+
+    /// Advance to `deadline_ns` (no-op jump when already past it), then settle.
+    pub fn advance_to_ns(&self, deadline_ns: u64) {
+        let remaining_ns = deadline_ns.saturating_sub(self.now_ns());
+        self.advance(Duration::from_nanos(remaining_ns));
+    }
+
+    /// Advance until the named Canic timer is due and let it run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if runtime status cannot be queried, or the timer is unknown or
+    /// has no scheduled deadline.
+    pub fn advance_until_next_timer(&self, canister_id: Principal, subsystem: &str, name: &str) {
+        let status: Result<CanicRuntimeStatus, Error> = self
+            .pic
+            .query_call(canister_id, protocol::CANIC_RUNTIME_STATUS, ())
+            .expect("query runtime status");
+        let due_at_ns = status
+            .expect("runtime status application result")
+            .timers
+            .into_iter()
+            .find(|timer| timer.subsystem == subsystem && timer.name == name)
+            .unwrap_or_else(|| panic!("timer {subsystem}:{name} must be registered"))
+            .next_due_at_ns
+            .unwrap_or_else(|| panic!("timer {subsystem}:{name} has no scheduled deadline"));
+
+        self.advance_to_ns(due_at_ns);
+    }
+
+    /// Advance to the first nanosecond at which `claims` is expired, then settle.
+    pub fn advance_past_token_expiry(&self, claims: &DelegatedTokenClaims) {
+        self.advance_to_ns(claims.expires_at_ns.saturating_add(1));
+    }
+
+    /// Run `count` rounds without moving time; PocketIC executes heartbeats once per round.
+    pub fn advance_heartbeats(&self, count: usize) {
+        self.pic.tick_n(count);
+    }
+}
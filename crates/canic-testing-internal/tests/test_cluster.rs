@@ -0,0 +1,32 @@
+use canic_testing_internal::pic::{CanicWasmBuildProfile, TestClusterBuilder};
+
+// Verify the cluster builder stages root alongside the requested child roles.
+#[test]
+fn test_cluster_spec_builds_root_with_requested_roles() {
+    let spec = TestClusterBuilder::new("/workspace", "/workspace/apps/test/canic.toml")
+        .roles(&["app", "user_hub"])
+        .build_profile(CanicWasmBuildProfile::Debug)
+        .spec();
+
+    assert_eq!(spec.release_roles, &["app", "user_hub"]);
+    assert_eq!(spec.build_profile, CanicWasmBuildProfile::Debug);
+    assert!(spec.root_wasm_path.ends_with("root/root.wasm.gz"));
+    assert!(spec.build_extra_env.contains(&(
+        "CANIC_REFERENCE_CANISTERS".to_string(),
+        "app user_hub root".to_string()
+    )));
+}
+
+// Verify an explicit reference-canister override is left untouched.
+#[test]
+fn test_cluster_spec_keeps_explicit_reference_canisters() {
+    let spec = TestClusterBuilder::new("/workspace", "/workspace/canic.toml")
+        .roles(&["app"])
+        .build_env("CANIC_REFERENCE_CANISTERS", "root")
+        .spec();
+
+    assert_eq!(
+        spec.build_extra_env,
+        vec![("CANIC_REFERENCE_CANISTERS".to_string(), "root".to_string())]
+    );
+}
@@ -327,6 +327,13 @@ fn assert_root_observability_queries(pic: &Pic, root_id: Principal) {
         panic!("default root memory ledger endpoint should be absent")
     };
     assert_missing_method(&err, protocol::CANIC_MEMORY_LEDGER);
+
+    let stores: Result<Result<(), canic::Error>, _> =
+        pic.query_call(root_id, protocol::CANIC_STORES, ());
+    let Err(err) = stores else {
+        panic!("default root store catalog endpoint should be absent")
+    };
+    assert_missing_method(&err, protocol::CANIC_STORES);
 }
 
 fn assert_root_runtime_introspection_rejects_non_controller(pic: &Pic, root_id: Principal) {
@@ -1,5 +1,6 @@
-use canic::ids::BuildNetwork;
-use canic_testing_internal::pic::{CanicWasmBuildProfile, RootBaselineMetadata, RootBaselineSpec};
+use canic_testing_internal::pic::{
+    CanicWasmBuildProfile, RootBaselineMetadata, RootBaselineSpec, TestClusterBuilder,
+};
 use ic_testkit::{artifacts::workspace_root_for, pic::CachedPicBaseline};
 use std::{
     path::{Path, PathBuf},
@@ -24,16 +25,6 @@ const ROOT_RECONCILE_RELEASE_ROLES: &[&str] = &[
     "user_shard",
 ];
 const TEST_SMALL_STORE_RUSTFLAGS: &str = "--cfg canic_test_small_wasm_store";
-const BOOTSTRAP_TICK_LIMIT: usize = 120;
-const ROOT_SETUP_MAX_ATTEMPTS: usize = 2;
-const ROOT_WASM_WATCH_PATHS: &[&str] = &[
-    "Cargo.toml",
-    "Cargo.lock",
-    "canisters",
-    "apps/test",
-    "icp.yaml",
-    "crates",
-];
 
 static ROOT_TOPOLOGY_BASELINE: Mutex<Option<CachedPicBaseline<RootBaselineMetadata>>> =
     Mutex::new(None);
@@ -142,71 +133,25 @@ fn profile_build_config_path(profile: RootSetupProfile, workspace_root: &Path) -
 // Build one reusable baseline spec for a named root harness profile.
 fn baseline_spec_for_profile(profile: RootSetupProfile) -> RootBaselineSpec<'static> {
     let workspace_root = workspace_root();
-    let build_extra_env = profile_build_extra_env(profile);
     let build_config_path = profile_build_config_path(profile, &workspace_root);
-    baseline_spec_for_roles_owned_env(
-        workspace_root,
-        profile.release_roles(),
-        profile.build_profile(),
-        build_config_path,
-        build_extra_env,
-    )
-}
-
-// Build one reusable baseline spec from static release roles plus owned env overrides.
-fn baseline_spec_for_roles_owned_env(
-    workspace_root: PathBuf,
-    release_roles: &'static [&'static str],
-    build_profile: CanicWasmBuildProfile,
-    build_config_path: PathBuf,
-    mut build_extra_env: Vec<(String, String)>,
-) -> RootBaselineSpec<'static> {
-    if build_extra_env
-        .iter()
-        .all(|(key, _)| key != "CANIC_REFERENCE_CANISTERS")
-    {
-        let mut build_canisters = release_roles
-            .iter()
-            .map(|role| (*role).to_string())
-            .collect::<Vec<_>>();
-        build_canisters.push("root".to_string());
-        build_extra_env.push((
-            "CANIC_REFERENCE_CANISTERS".to_string(),
-            build_canisters.join(" "),
-        ));
-    }
-
-    RootBaselineSpec {
-        progress_prefix: "root_setup",
-        root_wasm_path: root_wasm_path(&workspace_root),
-        root_wasm_artifact_path: root_wasm_path(&workspace_root),
-        root_release_artifacts_dir: root_release_artifacts_dir(&workspace_root),
-        artifact_watch_paths: ROOT_WASM_WATCH_PATHS,
-        release_roles,
-        icp_build_lock_path: icp_build_lock_path(&workspace_root),
-        workspace_root,
-        build_network: BuildNetwork::Local,
-        build_profile,
-        build_config_path,
-        build_extra_env,
-        bootstrap_tick_limit: BOOTSTRAP_TICK_LIMIT,
-        root_setup_max_attempts: ROOT_SETUP_MAX_ATTEMPTS,
-        pocket_ic_wasm_chunk_store_limit_bytes: 100 * 1024 * 1024,
-        root_release_chunk_bytes: canic::CANIC_WASM_CHUNK_BYTES,
-        package_version: env!("CARGO_PKG_VERSION"),
-    }
+    let builder = TestClusterBuilder::new(workspace_root, build_config_path)
+        .roles(profile.release_roles())
+        .build_profile(profile.build_profile())
+        .progress_prefix("root_setup");
+
+    profile_build_extra_env(profile)
+        .into_iter()
+        .fold(builder, |builder, (key, value)| {
+            builder.build_env(key, value)
+        })
+        .spec()
 }
 
 fn root_wasm_path(workspace_root: &Path) -> PathBuf {
-    root_release_artifacts_dir(workspace_root)
+    workspace_root
+        .join(".icp")
+        .join("local")
+        .join("canisters")
         .join("root")
         .join("root.wasm.gz")
 }
-
-fn root_release_artifacts_dir(workspace_root: &Path) -> PathBuf {
-    workspace_root.join(".icp").join("local").join("canisters")
-}
-
-fn icp_build_lock_path(workspace_root: &Path) -> PathBuf {
-    workspace_root.join(".icp").join("canic-tests-build.lock")
-}
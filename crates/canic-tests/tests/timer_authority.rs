@@ -14,7 +14,9 @@ use canic::{
     },
     protocol,
 };
-use canic_testing_internal::pic::{CanicPicExt, install_lifecycle_boundary_fixture, upgrade_args};
+use canic_testing_internal::pic::{
+    CanicPicExt, TimeController, install_lifecycle_boundary_fixture, upgrade_args,
+};
 use std::time::Duration;
 
 const READY_TICK_LIMIT: usize = 120;
@@ -28,20 +30,18 @@ fn application_timers_cancel_and_recur_only_after_completion() {
     fixture
         .pic
         .wait_for_ready(canister_id, READY_TICK_LIMIT, "install");
+    let time = TimeController::new(&fixture.pic);
 
-    fixture.pic.advance_time(Duration::from_secs(6));
-    tick(&fixture.pic, 4);
+    time.advance(Duration::from_secs(6));
     let first = counts(&fixture.pic, canister_id);
     assert_eq!(first.0, 1, "one-shot should execute exactly once");
     assert_eq!(first.2, 0, "cancelled one-shot must not execute");
 
-    fixture.pic.advance_time(Duration::from_secs(10));
-    tick(&fixture.pic, 4);
+    time.advance(Duration::from_secs(10));
     let second = counts(&fixture.pic, canister_id);
     assert_eq!(second.1, first.1.saturating_add(1));
 
-    fixture.pic.advance_time(Duration::from_secs(30));
-    tick(&fixture.pic, 4);
+    time.advance(Duration::from_secs(30));
     let third = counts(&fixture.pic, canister_id);
     assert_eq!(
         third.1,
@@ -150,26 +150,24 @@ fn finite_intent_expiry_is_rebuilt_after_upgrade_without_arming_ttl_free_work()
     fixture
         .pic
         .wait_for_ready(canister_id, READY_TICK_LIMIT, "post_upgrade");
+    let time = TimeController::new(&fixture.pic).settle_ticks(8);
 
     let rebuilt = intent_cleanup_status(&fixture.pic, canister_id);
     assert_eq!(rebuilt.registration, TimerRegistrationStatus::Scheduled);
     assert_eq!(rebuilt.condition, TimerProcessCondition::Active);
     assert_eq!(rebuilt.scheduling_mode, TimerSchedulingMode::Deadline);
 
-    fixture.pic.advance_time(Duration::from_secs(302));
-    tick(&fixture.pic, 8);
+    time.advance(Duration::from_secs(302));
     begin_intent(&fixture.pic, canister_id, 1, Some(600))
         .expect("expired reservation should release capacity after lifecycle rebuild");
-    fixture.pic.advance_time(Duration::from_secs(602));
-    tick(&fixture.pic, 8);
+    time.advance(Duration::from_secs(602));
     let idle = intent_cleanup_status(&fixture.pic, canister_id);
     assert_eq!(idle.registration, TimerRegistrationStatus::Unregistered);
     assert_eq!(idle.condition, TimerProcessCondition::Idle);
 
     begin_intent(&fixture.pic, canister_id, 2, None).expect("TTL-free reservation should succeed");
     let idle_timer_metrics = timer_metrics(&fixture.pic, canister_id);
-    fixture.pic.advance_time(Duration::from_hours(24));
-    tick(&fixture.pic, 8);
+    time.advance(Duration::from_hours(24));
     assert!(
         begin_intent(&fixture.pic, canister_id, 2, None).is_err(),
         "TTL-free reservation must not be treated as expirable work"
@@ -260,9 +258,3 @@ fn timer_metric(entries: &[MetricEntry], label: &str) -> (u64, u64) {
         })
         .unwrap_or_default()
 }
-
-fn tick(pic: &ic_testkit::pic::Pic, count: usize) {
-    for _ in 0..count {
-        pic.tick();
-    }
-}
@@ -13,6 +13,7 @@ fn main() {
     println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_observability_env)");
     println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_observability_log)");
     println!("cargo:rustc-check-cfg=cfg(canic_memory_ledger_enabled)");
+    println!("cargo:rustc-check-cfg=cfg(canic_store_catalog_enabled)");
     println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_metrics)");
     println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_cycle_tracker)");
     println!("cargo:rustc-check-cfg=cfg(canic_metrics_core)");
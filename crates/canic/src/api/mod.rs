@@ -6,6 +6,11 @@
 /// core layout.
 ///
 
+/// Application roles checked by `auth::has_role(...)`.
+pub mod acl {
+    pub use crate::__internal::core::api::acl::AclApi;
+}
+
 /// Authentication workflow helpers
 pub mod auth {
     pub use crate::__internal::core::api::auth::AuthApi;
@@ -17,6 +22,26 @@ pub mod blob_storage {
     pub use crate::__internal::core::api::blob_storage::BlobStorageApi;
 }
 
+/// Response cache for `cache(...)` query endpoints.
+pub mod cache {
+    pub use crate::__internal::core::api::cache::CacheApi;
+}
+
+/// Root-brokered publish/subscribe events.
+pub mod event_bus {
+    pub use crate::__internal::core::api::event_bus::EventBus;
+}
+
+/// Declarative workflow state machines persisted across upgrades.
+pub mod fsm {
+    pub use crate::__internal::core::api::fsm::{Fsm, StateMachine, Transition};
+}
+
+/// Child-to-root health reports and application gauges.
+pub mod health {
+    pub use crate::__internal::core::api::health::Health;
+}
+
 /// Local and receipt-backed reservation helpers.
 pub mod intent {
     pub use crate::__internal::core::api::intent::{
@@ -28,6 +53,14 @@ pub mod intent {
     };
 }
 
+/// Durable outbox for inter-canister calls issued after local state commits.
+pub mod outbox {
+    pub use crate::__internal::core::{
+        api::outbox::Outbox,
+        dto::outbox::{OutboxAdminCommand, OutboxFailedEntry},
+    };
+}
+
 /// Instrumented inter-canister call construction and response decoding.
 pub mod call {
     pub use crate::__internal::core::api::call::{Call, CallBuilder, CallResult};
@@ -42,6 +75,10 @@ pub mod env {
 pub mod canister {
     pub use crate::__internal::core::ids::CanisterRole;
 
+    pub mod blue_green {
+        pub use crate::__internal::core::api::blue_green::BlueGreenApi;
+    }
+
     pub mod children {
         pub use crate::__internal::core::api::topology::children::CanisterChildrenApi;
     }
@@ -51,7 +88,9 @@ pub mod canister {
     }
 
     pub mod registry {
-        pub use crate::__internal::core::api::topology::registry::SubnetRegistryApi;
+        pub use crate::__internal::core::api::topology::registry::{
+            CanisterRegistryApi, SubnetRegistryApi,
+        };
     }
 
     pub mod placement {
@@ -59,7 +98,9 @@ pub mod canister {
         pub use crate::__internal::core::api::placement::scaling::ScalingApi;
 
         #[cfg(feature = "sharding")]
-        pub use crate::__internal::core::api::placement::sharding::ShardingApi;
+        pub use crate::__internal::core::api::placement::sharding::{
+            PlacementCandidate, PlacementInput, ShardingApi,
+        };
     }
 
     #[cfg(any(feature = "control-plane", feature = "wasm-store-canister"))]
@@ -100,10 +141,15 @@ pub mod ops {
     pub use crate::__internal::core::{log, perf};
 }
 
+/// Sessions opened by verified delegated tokens.
+pub mod session {
+    pub use crate::__internal::core::api::session::SessionApi;
+}
+
 /// Timers and scheduling helpers
 pub mod timer {
     pub use crate::__internal::core::api::timer::TimerHandle;
-    pub use crate::{timer, timer_interval};
+    pub use crate::{schedule, timer, timer_interval};
 
     /// Consume a timer handle and suppress any future invocation.
     #[must_use]
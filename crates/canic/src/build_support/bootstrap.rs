@@ -114,7 +114,7 @@ pub fn emit_root_wasm_store_bootstrap_release_set(config_path: &Path) -> bool {
     true
 }
 
-fn discover_workspace_root(manifest_dir: &Path) -> PathBuf {
+pub(super) fn discover_workspace_root(manifest_dir: &Path) -> PathBuf {
     for candidate in manifest_dir.ancestors() {
         let cargo_toml = candidate.join("Cargo.toml");
         if !cargo_toml.is_file() {
@@ -143,7 +143,7 @@ pub fn manifest_declares_workspace(source: &str) -> bool {
         .is_some_and(|manifest| manifest.get("workspace").is_some())
 }
 
-fn discover_release_artifact_root(workspace_root: &Path) -> PathBuf {
+pub(super) fn discover_release_artifact_root(workspace_root: &Path) -> PathBuf {
     let canonical_icp_root =
         env::var_os(canic_core::role_contract::CANONICAL_BUILD_ICP_ROOT_ENV).map(PathBuf::from);
     canonical_release_artifact_root(workspace_root, canonical_icp_root.as_deref())
@@ -0,0 +1,115 @@
+use super::{
+    bootstrap::{discover_release_artifact_root, discover_workspace_root},
+    config::required_package_role,
+};
+use candid_parser::utils::{CandidSource, service_compatible};
+use std::{env, fs, path::PathBuf};
+
+/// Compare the role's extracted Candid interface against a committed baseline.
+///
+/// The extracted interface is the `<role>.did` written by the last canonical
+/// Canic build of this role; the check is skipped with a warning until one
+/// exists. Breaking changes fail the build unless `allow_breaking` is set, in
+/// which case they are reported as warnings.
+///
+/// # Panics
+///
+/// Panics when Cargo does not provide `CARGO_MANIFEST_DIR`, when the baseline
+/// cannot be read, or when the interface breaks the baseline and
+/// `allow_breaking` is false.
+pub fn check_candid_baseline(baseline: &str, allow_breaking: bool) {
+    let manifest_dir = PathBuf::from(
+        env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR must be set for Candid check"),
+    );
+    let role = required_package_role(&manifest_dir);
+    let baseline_path = manifest_dir.join(baseline);
+    let generated_path = discover_release_artifact_root(&discover_workspace_root(&manifest_dir))
+        .join(&role)
+        .join(format!("{role}.did"));
+
+    println!("cargo:rerun-if-changed={}", baseline_path.display());
+    println!("cargo:rerun-if-changed={}", generated_path.display());
+
+    let baseline_did = fs::read_to_string(&baseline_path).unwrap_or_else(|err| {
+        panic!(
+            "read Candid baseline {} failed: {err}",
+            baseline_path.display()
+        )
+    });
+    let Ok(generated_did) = fs::read_to_string(&generated_path) else {
+        println!(
+            "cargo:warning=skipping Candid baseline check for '{role}': missing build-produced interface at {}",
+            generated_path.display()
+        );
+        return;
+    };
+
+    let Err(reason) = candid_breaking_change(&generated_did, &baseline_did) else {
+        return;
+    };
+    let message = format!(
+        "Candid interface of '{role}' at {} breaks baseline {}: {reason}",
+        generated_path.display(),
+        baseline_path.display()
+    );
+    assert!(
+        allow_breaking,
+        "{message}; update the baseline or pass `allow_breaking` to canic::build!"
+    );
+    println!("cargo:warning={message}");
+}
+
+// Return why `generated` cannot serve callers written against `baseline`.
+fn candid_breaking_change(generated: &str, baseline: &str) -> Result<(), String> {
+    service_compatible(CandidSource::Text(generated), CandidSource::Text(baseline))
+        .map_err(|err| err.to_string())
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASELINE: &str = r"
+        type Entry = record { id : nat64; label : text };
+        service : {
+            get : (nat64) -> (opt Entry) query;
+            put : (Entry) -> ();
+        }
+    ";
+
+    #[test]
+    fn added_methods_and_optional_fields_stay_compatible() {
+        let generated = r"
+            type Entry = record { id : nat64; label : text; note : opt text };
+            service : {
+                get : (nat64) -> (opt Entry) query;
+                put : (Entry) -> ();
+                list : () -> (vec Entry) query;
+            }
+        ";
+
+        assert!(candid_breaking_change(generated, BASELINE).is_ok());
+    }
+
+    #[test]
+    fn removed_methods_and_changed_types_break_the_baseline() {
+        let removed = r"
+            type Entry = record { id : nat64; label : text };
+            service : { get : (nat64) -> (opt Entry) query }
+        ";
+        let changed = r"
+            type Entry = record { id : text; label : text };
+            service : {
+                get : (nat64) -> (opt Entry) query;
+                put : (Entry) -> ();
+            }
+        ";
+
+        assert!(candid_breaking_change(removed, BASELINE).is_err());
+        assert!(candid_breaking_change(changed, BASELINE).is_err());
+    }
+}
@@ -1,7 +1,11 @@
 use std::{fs, path::Path};
 
 use canic_core::{
-    bootstrap::compiled::{ConfigModel, validate_canister_role_name},
+    bootstrap::{
+        ConfigError,
+        compiled::{ConfigModel, validate_canister_role_name},
+        parse_config_model,
+    },
     ids::CanisterRole,
 };
 use toml::Value as TomlValue;
@@ -60,6 +64,35 @@ pub fn read_config_source_or_default(
     }
 }
 
+/// Parse and validate a Canic config source for the build script.
+///
+/// # Panics
+///
+/// Panics with a `path:line:column` diagnostic and the offending key path when
+/// the source does not parse, or with the config path and the violated rule
+/// when cross-field validation fails.
+#[must_use]
+pub fn parse_config_source(config_path: &Path, source: &str) -> ConfigModel {
+    parse_config_model(source)
+        .unwrap_or_else(|err| panic!("{}", config_diagnostic(config_path, &err)))
+}
+
+fn config_diagnostic(config_path: &Path, err: &ConfigError) -> String {
+    match err {
+        ConfigError::CannotParseToml {
+            issue,
+            location: Some(location),
+            detail,
+        } => format!(
+            "invalid Canic config {}:{}:{}: {issue}: {detail}",
+            config_path.display(),
+            location.line,
+            location.column
+        ),
+        _ => format!("invalid Canic config {}: {err}", config_path.display()),
+    }
+}
+
 /// Read optional Canic metadata declared in the package manifest.
 #[must_use]
 pub fn declared_package_metadata(manifest_dir: &Path) -> Option<PackageCanicMetadata> {
@@ -175,7 +208,6 @@ enabled = false
 #[cfg(test)]
 mod tests {
     use super::*;
-    use canic_core::bootstrap::parse_config_model;
 
     #[test]
     fn canonical_role_contract_marker_is_required_only_for_wasm_builds() {
@@ -357,4 +389,37 @@ kind = "service"
         assert!(!config_contains_role(&cfg, "roots"));
         assert!(!config_contains_role(&cfg, "missing"));
     }
+
+    #[test]
+    fn config_diagnostic_reports_path_line_and_key_for_unknown_fields() {
+        let source = format!(
+            "{}\n[subnets.default.canisters.sandbox_blank.randomness]\nenabled = true\n",
+            standalone_config_source("sandbox_blank")
+        );
+        let err = parse_config_model(&source).expect_err("unknown field must reject");
+        let diagnostic = config_diagnostic(Path::new("canic.toml"), &err);
+
+        assert!(
+            diagnostic.starts_with("invalid Canic config canic.toml:19:42: "),
+            "{diagnostic}"
+        );
+        assert!(
+            diagnostic.contains("subnets.default.canisters.sandbox_blank.randomness"),
+            "{diagnostic}"
+        );
+    }
+
+    #[test]
+    fn config_diagnostic_reports_path_for_cross_field_violations() {
+        let source = standalone_config_source("sandbox_blank")
+            .replace("roles = []", "roles = [\"missing\"]");
+        let err = parse_config_model(&source).expect_err("unknown fleet role must reject");
+        let diagnostic = config_diagnostic(Path::new("canic.toml"), &err);
+
+        assert!(
+            diagnostic.starts_with("invalid Canic config canic.toml: "),
+            "{diagnostic}"
+        );
+        assert!(diagnostic.contains("services.fleet.roles"), "{diagnostic}");
+    }
 }
@@ -1,12 +1,15 @@
 mod bootstrap;
+mod candid;
 mod config;
 mod metrics;
 
 pub use bootstrap::{emit_root_wasm_store_bootstrap_release_set, manifest_declares_workspace};
+pub use candid::check_candid_baseline;
 pub use config::{
     assert_canonical_role_contract_build, config_app_id, config_attaches_role,
     config_contains_role, config_declares_role, declared_package_metadata, declared_package_role,
-    read_config_source_or_default, required_package_metadata, required_package_role,
+    parse_config_source, read_config_source_or_default, required_package_metadata,
+    required_package_role,
 };
 pub use metrics::{
     METRICS_TIER_CORE, METRICS_TIER_PLACEMENT, METRICS_TIER_PLATFORM, METRICS_TIER_RUNTIME,
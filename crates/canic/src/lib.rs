@@ -54,11 +54,11 @@ pub mod __build {
     pub use crate::build_support::{
         METRICS_TIER_CORE, METRICS_TIER_PLACEMENT, METRICS_TIER_PLATFORM, METRICS_TIER_RUNTIME,
         METRICS_TIER_SECURITY, METRICS_TIER_STORAGE, assert_canonical_role_contract_build,
-        config_app_id, config_attaches_role, config_contains_role, config_declares_role,
-        declared_package_metadata, declared_package_role,
+        check_candid_baseline, config_app_id, config_attaches_role, config_contains_role,
+        config_declares_role, declared_package_metadata, declared_package_role,
         emit_root_wasm_store_bootstrap_release_set, manifest_declares_workspace,
-        metrics_profile_tier_mask, read_config_source_or_default, required_package_metadata,
-        required_package_role, role_normal_dependency_metrics_enabled,
+        metrics_profile_tier_mask, parse_config_source, read_config_source_or_default,
+        required_package_metadata, required_package_role, role_normal_dependency_metrics_enabled,
     };
 }
 
@@ -72,7 +72,7 @@ pub use canic_core::memory;
 // -----------------------------------------------------------------------------
 pub use canic_core::dto::error::Error;
 pub use canic_core::{impl_storable_bounded, impl_storable_unbounded};
-pub use canic_macros::{canic_query, canic_update};
+pub use canic_macros::{canic_model, canic_query, canic_update};
 
 // -----------------------------------------------------------------------------
 // Constants
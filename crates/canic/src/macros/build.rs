@@ -8,6 +8,11 @@
 /// using the shared config schema, and emits both a compact source copy and a
 /// generated Rust config model for runtime bootstrap. Canister crates typically
 /// invoke this from `build.rs`.
+///
+/// `candid_baseline = "<path>"` (relative to the crate manifest dir) also fails
+/// the build when the role's extracted Candid interface removes methods or
+/// changes types relative to that committed `.did`. Add `allow_breaking` to
+/// downgrade breaking changes to build warnings for an intentional break.
 #[macro_export]
 macro_rules! build {
     ($file:expr) => {{
@@ -18,6 +23,14 @@ macro_rules! build {
             }
         }
     }};
+    ($file:expr, candid_baseline = $baseline:expr $(,)?) => {{
+        $crate::build!($file);
+        $crate::__build::check_candid_baseline($baseline, false);
+    }};
+    ($file:expr, candid_baseline = $baseline:expr, allow_breaking $(,)?) => {{
+        $crate::build!($file);
+        $crate::__build::check_candid_baseline($baseline, true);
+    }};
 }
 
 /// Internal helper shared by Canic build macros.
@@ -95,8 +108,7 @@ macro_rules! __canic_build_internal {
 
         // Validate once on the host, then emit a precompiled runtime model.
         let $cfg = ::std::sync::Arc::new(
-            $crate::__internal::core::bootstrap::parse_config_model(&$cfg_str)
-                .expect("invalid canic config")
+            $crate::__build::parse_config_source(&$cfg_path, &$cfg_str)
         );
         let compact_cfg = $crate::__internal::core::bootstrap::compact_config_source(&$cfg_str);
         let compiled_cfg =
@@ -121,6 +133,7 @@ macro_rules! __canic_build_internal {
         println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_observability_env)");
         println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_observability_log)");
         println!("cargo:rustc-check-cfg=cfg(canic_memory_ledger_enabled)");
+        println!("cargo:rustc-check-cfg=cfg(canic_store_catalog_enabled)");
         println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_metrics)");
         println!("cargo:rustc-check-cfg=cfg(canic_disable_bundle_cycle_tracker)");
         println!("cargo:rustc-check-cfg=cfg(canic_metrics_core)");
@@ -152,6 +165,7 @@ macro_rules! __canic_build_internal {
 
         let role_name = __canic_role_name.as_str();
         let mut memory_ledger = false;
+        let mut store_catalog = false;
         let mut metrics_core = false;
         let mut metrics_placement = false;
         let mut metrics_platform = false;
@@ -189,6 +203,7 @@ macro_rules! __canic_build_internal {
         for subnet in $cfg.subnets.values() {
             if let Some(canister_cfg) = subnet.get_canister(&role_id) {
                 memory_ledger |= canister_cfg.diagnostics.memory_ledger;
+                store_catalog |= canister_cfg.diagnostics.store_catalog;
                 let profile = canister_cfg.resolved_metrics_profile(&role_id);
                 let tier_mask = $crate::__build::metrics_profile_tier_mask(profile);
                 metrics_core |= tier_mask & $crate::__build::METRICS_TIER_CORE != 0;
@@ -242,6 +257,10 @@ macro_rules! __canic_build_internal {
             println!("cargo:rustc-cfg=canic_memory_ledger_enabled");
         }
 
+        if store_catalog {
+            println!("cargo:rustc-cfg=canic_store_catalog_enabled");
+        }
+
         if has_scaling {
             println!("cargo:rustc-cfg=canic_has_scaling");
         }
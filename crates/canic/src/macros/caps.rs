@@ -0,0 +1,54 @@
+// -----------------------------------------------------------------------------
+// Capability macros
+// -----------------------------------------------------------------------------
+
+///
+/// caps
+/// Declare an app capability namespace once, generating typed scope constants
+/// plus an `ALL` registry for `auth::authenticated(...)` guards and grants.
+///
+/// Each constant expands to `"<namespace>:<name>"`; duplicate scopes inside one
+/// namespace fail the build.
+///
+/// # Examples
+/// ```ignore
+/// canic::caps! {
+///     pub mod billing = "billing" {
+///         CHARGE = "charge",
+///         REFUND = "refund",
+///     }
+/// }
+///
+/// #[canic_update(requires(auth::authenticated(billing::CHARGE)))]
+/// async fn charge(token: DelegatedToken) -> Result<(), Error> { Ok(()) }
+/// ```
+///
+#[macro_export]
+macro_rules! caps {
+    ($(
+        $(#[$mod_meta:meta])*
+        $vis:vis mod $module:ident = $namespace:literal {
+            $(
+                $(#[$cap_meta:meta])*
+                $name:ident = $scope:literal
+            ),* $(,)?
+        }
+    )*) => {$(
+        $(#[$mod_meta])*
+        $vis mod $module {
+            /// Namespace prefix shared by every scope in this module.
+            pub const NAMESPACE: &str = $namespace;
+
+            $(
+                $(#[$cap_meta])*
+                pub const $name: $crate::ids::cap::Capability =
+                    $crate::ids::cap::Capability::new(concat!($namespace, ":", $scope));
+            )*
+
+            /// Every capability declared in this namespace.
+            pub const ALL: &[$crate::ids::cap::Capability] = &[$($name),*];
+
+            const _: () = $crate::ids::cap::assert_unique(ALL);
+        }
+    )*};
+}
@@ -11,8 +11,12 @@ macro_rules! canic_bundle_shared_runtime_endpoints {
         $crate::canic_emit_lifecycle_core_endpoints!();
         #[cfg(canic_memory_ledger_enabled)]
         $crate::canic_emit_memory_ledger_diagnostic_endpoint!();
+        #[cfg(canic_store_catalog_enabled)]
+        $crate::canic_emit_store_catalog_diagnostic_endpoint!();
         $crate::canic_bundle_discovery_endpoints!();
         $crate::canic_bundle_observability_endpoints!();
+        $crate::canic_emit_acl_endpoints!();
+        $crate::canic_emit_session_endpoints!();
         #[cfg(not(canic_disable_bundle_metrics))]
         $crate::canic_emit_metrics_endpoints!();
         #[cfg(not(canic_disable_bundle_cycle_tracker))]
@@ -29,6 +33,8 @@ macro_rules! canic_bundle_root_only_endpoints {
     () => {
         $crate::canic_emit_root_admin_endpoints!();
         $crate::canic_emit_root_auth_attestation_endpoints!();
+        $crate::canic_emit_root_event_bus_endpoints!();
+        $crate::canic_emit_root_health_endpoints!();
         $crate::canic_emit_root_wasm_store_endpoints!();
     };
 }
@@ -51,6 +57,8 @@ macro_rules! canic_bundle_wasm_store_runtime_endpoints {
         $crate::canic_emit_lifecycle_core_endpoints!();
         #[cfg(canic_memory_ledger_enabled)]
         $crate::canic_emit_memory_ledger_diagnostic_endpoint!();
+        #[cfg(canic_store_catalog_enabled)]
+        $crate::canic_emit_store_catalog_diagnostic_endpoint!();
         $crate::canic_bundle_discovery_endpoints!();
         #[cfg(not(canic_disable_bundle_cycle_tracker))]
         $crate::canic_emit_cycle_tracker_endpoints!();
@@ -34,6 +34,13 @@ macro_rules! canic_emit_root_admin_endpoints {
             $crate::__internal::core::api::config::ConfigApi::export_toml()
         }
 
+        #[$crate::canic_update(requires(caller::is_controller()))]
+        async fn canic_config_patch(
+            patch: ::canic::dto::config::ConfigPatch,
+        ) -> Result<(), ::canic::Error> {
+            $crate::__internal::core::api::config::ConfigApi::apply_patch(patch).await
+        }
+
         #[$crate::canic_update(requires(caller::is_controller()))]
         async fn canic_icp_refill(
             request: ::canic::dto::icp_refill::IcpRefillRequest,
@@ -47,6 +54,17 @@ macro_rules! canic_emit_root_admin_endpoints {
             Ok($crate::__internal::core::api::topology::registry::SubnetRegistryApi::registry())
         }
 
+        #[$crate::canic_query(public)]
+        fn canic_canisters(
+            filter: ::canic::dto::topology::CanisterFilter,
+            page: ::canic::dto::page::PageRequest,
+        ) -> Result<::canic::dto::page::Page<::canic::dto::topology::CanisterSummary>, ::canic::Error>
+        {
+            Ok($crate::__internal::core::api::topology::registry::CanisterRegistryApi::list(
+                filter, page,
+            ))
+        }
+
         #[$crate::canic_query(public)]
         async fn canic_pool_list()
         -> Result<::canic::dto::pool::CanisterPoolResponse, ::canic::Error> {
@@ -59,6 +77,82 @@ macro_rules! canic_emit_root_admin_endpoints {
         ) -> Result<::canic::dto::pool::PoolAdminResponse, ::canic::Error> {
             $crate::__internal::core::api::pool::CanisterPoolApi::admin(cmd).await
         }
+
+        #[$crate::canic_update(requires(caller::is_controller()))]
+        async fn canic_blue_green_deploy(
+            request: ::canic::dto::blue_green::BlueGreenDeployRequest,
+        ) -> Result<::canic::dto::blue_green::BlueGreenStatus, ::canic::Error> {
+            $crate::__internal::core::api::blue_green::BlueGreenApi::deploy(request)
+        }
+
+        #[$crate::canic_query(requires(caller::is_controller()))]
+        async fn canic_blue_green_status()
+        -> Result<Vec<::canic::dto::blue_green::BlueGreenStatus>, ::canic::Error> {
+            Ok($crate::__internal::core::api::blue_green::BlueGreenApi::statuses())
+        }
+    };
+}
+
+/// Emit root-only event-bus broker endpoints.
+#[macro_export]
+macro_rules! canic_emit_root_event_bus_endpoints {
+    () => {
+        #[$crate::canic_update(internal, requires(caller::is_registered_to_subnet()))]
+        async fn canic_event_publish(
+            request: ::canic::dto::event_bus::EventPublishRequest,
+        ) -> Result<::canic::dto::event_bus::EventPublishResponse, ::canic::Error> {
+            $crate::__internal::core::api::event_bus::EventBusApi::publish_root(request)
+        }
+
+        #[$crate::canic_update(internal, requires(caller::is_registered_to_subnet()))]
+        async fn canic_event_subscribe(
+            request: ::canic::dto::event_bus::EventSubscribeRequest,
+        ) -> Result<(), ::canic::Error> {
+            $crate::__internal::core::api::event_bus::EventBusApi::subscribe_root(request)
+        }
+
+        #[$crate::canic_update(internal, requires(caller::is_registered_to_subnet()))]
+        async fn canic_event_unsubscribe(
+            request: ::canic::dto::event_bus::EventUnsubscribeRequest,
+        ) -> Result<(), ::canic::Error> {
+            $crate::__internal::core::api::event_bus::EventBusApi::unsubscribe_root(request)
+        }
+
+        #[$crate::canic_query(requires(caller::is_controller()))]
+        async fn canic_event_dead_letters(
+            page: ::canic::dto::page::PageRequest,
+        ) -> Result<
+            ::canic::dto::page::Page<::canic::dto::event_bus::EventDeadLetter>,
+            ::canic::Error,
+        > {
+            Ok($crate::__internal::core::api::event_bus::EventBusApi::dead_letters(page))
+        }
+
+        #[$crate::canic_update(requires(caller::is_controller()))]
+        async fn canic_event_bus_admin(
+            command: ::canic::dto::event_bus::EventBusAdminCommand,
+        ) -> Result<(), ::canic::Error> {
+            $crate::__internal::core::api::event_bus::EventBusApi::admin(command)
+        }
+    };
+}
+
+/// Emit root-only fleet health endpoints.
+#[macro_export]
+macro_rules! canic_emit_root_health_endpoints {
+    () => {
+        #[$crate::canic_update(internal, requires(caller::is_registered_to_subnet()))]
+        async fn canic_health_report(
+            report: ::canic::dto::health::HealthReport,
+        ) -> Result<(), ::canic::Error> {
+            $crate::__internal::core::api::health::HealthApi::report_root(report)
+        }
+
+        #[$crate::canic_query(requires(caller::is_controller()))]
+        async fn canic_fleet_health()
+        -> Result<::canic::dto::health::FleetHealthResponse, ::canic::Error> {
+            $crate::__internal::core::api::health::HealthApi::fleet_health()
+        }
     };
 }
 
@@ -23,6 +23,12 @@ macro_rules! canic_emit_lifecycle_core_endpoints {
             $crate::__internal::core::api::ready::ReadyApi::bootstrap_status()
         }
 
+        // Stays reachable while the Fleet is Disabled so status pages can report maintenance.
+        #[$crate::canic_query(internal, public)]
+        fn canic_fleet_mode() -> ::canic::dto::state::FleetMode {
+            $crate::__internal::core::api::state::FleetStateQuery::mode()
+        }
+
         #[$crate::canic_query(requires(caller::is_controller()))]
         async fn canic_fleet_activation_status()
         -> Result<::canic::dto::fleet_activation::FleetActivationStatusResponse, ::canic::Error> {
@@ -68,6 +74,13 @@ macro_rules! canic_emit_runtime_introspection_endpoints {
                 ),
             )
         }
+
+        #[$crate::canic_query(requires(caller::is_controller()))]
+        async fn canic_workflow_status(
+            instance_id: u64,
+        ) -> Result<Option<::canic::dto::fsm::WorkflowStatusResponse>, ::canic::Error> {
+            Ok($crate::__internal::core::api::fsm::Fsm::status(instance_id))
+        }
     };
 }
 
@@ -104,6 +117,11 @@ macro_rules! canic_emit_canic_metadata_endpoints {
                 $crate::__internal::cdk::api::canister_version(),
             )
         }
+
+        #[$crate::canic_query(internal, public)]
+        fn canic_interface_version() -> String {
+            $crate::VERSION.to_string()
+        }
     };
 }
 
@@ -137,6 +155,24 @@ macro_rules! canic_emit_memory_ledger_diagnostic_endpoint {
     };
 }
 
+/// Emit the controller-only stable store catalog diagnostic endpoint.
+#[macro_export]
+macro_rules! canic_emit_store_catalog_diagnostic_endpoint {
+    () => {
+        #[$crate::__internal::cdk::query]
+        fn canic_stores() -> Result<::canic::dto::memory::StoreCatalogResponse, ::canic::Error> {
+            let caller = $crate::__internal::cdk::api::msg_caller();
+            if !$crate::__internal::cdk::api::is_controller(&caller) {
+                return Err(::canic::Error::unauthorized(format!(
+                    "caller '{caller}' is not a controller of this canister"
+                )));
+            }
+
+            $crate::__internal::core::api::memory::MemoryQuery::stores()
+        }
+    };
+}
+
 /// Emit the environment snapshot diagnostic endpoint shared by all Canic canisters.
 #[macro_export]
 macro_rules! canic_emit_env_observability_endpoints {
@@ -178,6 +214,48 @@ macro_rules! canic_bundle_observability_endpoints {
     };
 }
 
+/// Emit the controller-gated access control list endpoints.
+#[macro_export]
+macro_rules! canic_emit_acl_endpoints {
+    () => {
+        #[$crate::canic_update(requires(caller::is_controller()))]
+        async fn canic_acl_grant(
+            request: ::canic::dto::acl::AclRoleRequest,
+        ) -> Result<(), ::canic::Error> {
+            $crate::__internal::core::api::acl::AclApi::grant(request.principal, &request.role)
+        }
+
+        #[$crate::canic_update(requires(caller::is_controller()))]
+        async fn canic_acl_revoke(
+            request: ::canic::dto::acl::AclRoleRequest,
+        ) -> Result<(), ::canic::Error> {
+            $crate::__internal::core::api::acl::AclApi::revoke(request.principal, &request.role)
+        }
+
+        #[$crate::canic_query(requires(caller::is_controller()))]
+        async fn canic_acl_list(
+            page: ::canic::dto::page::PageRequest,
+        ) -> Result<::canic::dto::page::Page<::canic::dto::acl::AclEntry>, ::canic::Error> {
+            Ok($crate::__internal::core::api::acl::AclApi::list(page))
+        }
+    };
+}
+
+/// Emit the controller-only session listing shared by all Canic canisters.
+#[macro_export]
+macro_rules! canic_emit_session_endpoints {
+    () => {
+        #[$crate::canic_query(requires(caller::is_controller()))]
+        async fn canic_sessions_list(
+            page: ::canic::dto::page::PageRequest,
+        ) -> Result<::canic::dto::page::Page<::canic::dto::session::SessionEntry>, ::canic::Error> {
+            Ok($crate::__internal::core::api::session::SessionApi::list(
+                page,
+            ))
+        }
+    };
+}
+
 /// Emit the metrics query surface shared by all Canic canisters.
 #[macro_export]
 macro_rules! canic_emit_metrics_endpoints {
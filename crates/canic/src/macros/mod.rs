@@ -1,5 +1,6 @@
 //! Facade macros for downstream canister crates.
 mod build;
+mod caps;
 mod endpoints;
 mod start;
 mod timer;
@@ -46,3 +46,27 @@ macro_rules! timer_interval {
         )
     }};
 }
+
+///
+/// schedule
+/// Schedule a recurring task from a five-field UTC cron expression.
+///
+/// Returns `Result<TimerHandle, Error>`; invalid or never-firing expressions
+/// are rejected. Call from `canic_setup` so the schedule is re-armed after
+/// every upgrade.
+///
+/// # Examples
+/// - `schedule!("0 3 * * *", nightly_compaction)?;`
+/// - `schedule!("*/15 * * * *", refresh, state.clone())?;`
+///
+#[macro_export]
+macro_rules! schedule {
+    ($expr:expr, $func:path $(, $($args:tt)*)? ) => {{
+        let label = concat!(module_path!(), "::", stringify!($func));
+        $crate::__internal::core::api::timer::TimerApi::set_schedule(
+            $expr,
+            label,
+            move || $func($($($args)*)?),
+        )
+    }};
+}
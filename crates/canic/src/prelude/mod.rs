@@ -10,7 +10,7 @@ pub use crate::{
         call::Call,
         canister::CanisterRole,
         ops::{log, perf},
-        timer::{timer, timer_interval},
+        timer::{schedule, timer, timer_interval},
     },
     dto::auth::DelegatedToken,
 };
@@ -9,13 +9,15 @@ pub use canic_core::protocol::{
     BLOB_STORAGE_CASHIER_ACCOUNT_BALANCE_GET_V1, BLOB_STORAGE_CASHIER_ACCOUNT_TOP_UP_V1,
     BLOB_STORAGE_CASHIER_STORAGE_GATEWAY_PRINCIPAL_LIST_V1, BLOB_STORAGE_CONFIRM_BLOB_DELETION,
     BLOB_STORAGE_CREATE_CERTIFICATE, BLOB_STORAGE_FUND_FROM_PROJECT_CYCLES, BLOB_STORAGE_STATUS,
-    BLOB_STORAGE_UPDATE_GATEWAY_PRINCIPALS, CANIC_ACTIVE_DELEGATION_PROOF_STATUS,
-    CANIC_CYCLE_BALANCE, CANIC_CYCLE_TRACKER, CANIC_FLEET_ACTIVATION_STATUS,
-    CANIC_GET_DELEGATED_TOKEN, CANIC_GET_OR_CREATE_CHAIN_KEY_DELEGATION_PROOF,
-    CANIC_GET_ROLE_ATTESTATION, CANIC_HEALTH, CANIC_INSTALL_ACTIVE_DELEGATION_PROOF,
-    CANIC_METADATA, CANIC_PREPARE_DELEGATED_TOKEN, CANIC_PREPARE_ROLE_ATTESTATION, CANIC_READINESS,
+    BLOB_STORAGE_UPDATE_GATEWAY_PRINCIPALS, CANIC_ACL_GRANT, CANIC_ACL_LIST, CANIC_ACL_REVOKE,
+    CANIC_ACTIVE_DELEGATION_PROOF_STATUS, CANIC_CYCLE_BALANCE, CANIC_CYCLE_TRACKER,
+    CANIC_EVENT_PUBLISH, CANIC_EVENT_SUBSCRIBE, CANIC_EVENT_UNSUBSCRIBE,
+    CANIC_FLEET_ACTIVATION_STATUS, CANIC_GET_DELEGATED_TOKEN,
+    CANIC_GET_OR_CREATE_CHAIN_KEY_DELEGATION_PROOF, CANIC_GET_ROLE_ATTESTATION, CANIC_HEALTH,
+    CANIC_HEALTH_REPORT, CANIC_INSTALL_ACTIVE_DELEGATION_PROOF, CANIC_METADATA,
+    CANIC_PREPARE_DELEGATED_TOKEN, CANIC_PREPARE_ROLE_ATTESTATION, CANIC_READINESS,
     CANIC_RESPONSE_CAPABILITY_V1, CANIC_ROOT_ISSUER_RENEWAL_STATUS, CANIC_RUNTIME_STATUS,
-    CANIC_SYNC_STATE, CANIC_SYNC_TOPOLOGY, CANIC_TEMPLATE_PREPARE_ADMIN,
+    CANIC_SESSIONS_LIST, CANIC_SYNC_STATE, CANIC_SYNC_TOPOLOGY, CANIC_TEMPLATE_PREPARE_ADMIN,
     CANIC_TEMPLATE_PUBLISH_CHUNK_ADMIN, CANIC_TEMPLATE_STAGE_MANIFEST_ADMIN,
     CANIC_UPSERT_ROOT_ISSUER_POLICY, CANIC_UPSERT_ROOT_ISSUER_RENEWAL_TEMPLATE,
     CANIC_WASM_STORE_BEGIN_GC, CANIC_WASM_STORE_BOOTSTRAP_DEBUG,
@@ -23,25 +25,35 @@ pub use canic_core::protocol::{
     CANIC_WASM_STORE_COMPLETE_GC, CANIC_WASM_STORE_INFO, CANIC_WASM_STORE_OVERVIEW,
     CANIC_WASM_STORE_PREPARE, CANIC_WASM_STORE_PREPARE_GC, CANIC_WASM_STORE_PUBLISH_CHUNK,
     CANIC_WASM_STORE_ROOT_UPDATE_METHODS, CANIC_WASM_STORE_STAGE_MANIFEST, CANIC_WASM_STORE_STATUS,
-    CANIC_WASM_STORE_STRUCTURAL_QUERY_METHODS,
+    CANIC_WASM_STORE_STRUCTURAL_QUERY_METHODS, CANIC_WORKFLOW_STATUS,
 };
 
 pub const CANIC_FLEET_ADMIN: &str = "canic_fleet_admin";
 pub const CANIC_CANISTER_UPGRADE: &str = "canic_canister_upgrade";
 pub const CANIC_CANISTER_STATUS: &str = "canic_canister_status";
 pub const CANIC_CONFIG: &str = "canic_config";
+pub const CANIC_CONFIG_PATCH: &str = "canic_config_patch";
+pub const CANIC_EVENT_DEAD_LETTERS: &str = "canic_event_dead_letters";
+pub const CANIC_EVENT_BUS_ADMIN: &str = "canic_event_bus_admin";
+pub const CANIC_FLEET_HEALTH: &str = "canic_fleet_health";
 pub const CANIC_SUBNET_REGISTRY: &str = "canic_subnet_registry";
+pub const CANIC_CANISTERS: &str = "canic_canisters";
 pub const CANIC_POOL_LIST: &str = "canic_pool_list";
 pub const CANIC_POOL_ADMIN: &str = "canic_pool_admin";
+pub const CANIC_BLUE_GREEN_DEPLOY: &str = "canic_blue_green_deploy";
+pub const CANIC_BLUE_GREEN_STATUS: &str = "canic_blue_green_status";
 pub const CANIC_WASM_STORE_ADMIN: &str = "canic_wasm_store_admin";
 pub const ICRC10_SUPPORTED_STANDARDS: &str = "icrc10_supported_standards";
 pub const ICRC21_CANISTER_CALL_CONSENT_MESSAGE: &str = "icrc21_canister_call_consent_message";
 pub const CANIC_MEMORY_LEDGER: &str = "canic_memory_ledger";
+pub const CANIC_STORES: &str = "canic_stores";
+pub const CANIC_INTERFACE_VERSION: &str = "canic_interface_version";
 pub const CANIC_ENV: &str = "canic_env";
 pub const CANIC_LOG: &str = "canic_log";
 pub const CANIC_METRICS: &str = "canic_metrics";
 pub const CANIC_READY: &str = "canic_ready";
 pub const CANIC_FLEET_STATE: &str = "canic_fleet_state";
+pub const CANIC_FLEET_MODE: &str = "canic_fleet_mode";
 pub const CANIC_APP_INDEX: &str = "canic_app_index";
 pub const CANIC_SUBNET_INDEX: &str = "canic_subnet_index";
 pub const CANIC_CANISTER_CHILDREN: &str = "canic_canister_children";
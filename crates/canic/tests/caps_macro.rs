@@ -0,0 +1,39 @@
+use canic::{Error, canic_update, dto::auth::DelegatedToken, ids::cap::Capability};
+
+canic::caps! {
+    /// Billing scopes for the test canister.
+    pub mod billing = "billing" {
+        CHARGE = "charge",
+        /// Refunds require a separate grant.
+        REFUND = "refund",
+    }
+
+    mod reports = "reports" {
+        EXPORT = "export"
+    }
+}
+
+#[canic_update(requires(auth::authenticated(billing::CHARGE)))]
+async fn charge(token: DelegatedToken) -> Result<(), Error> {
+    std::future::ready(token).await;
+    Ok(())
+}
+
+#[test]
+fn caps_prefixes_scopes_with_the_namespace() {
+    assert_eq!(billing::NAMESPACE, "billing");
+    assert_eq!(billing::CHARGE.as_str(), "billing:charge");
+    assert_eq!(billing::REFUND.to_string(), "billing:refund");
+    assert_eq!(reports::EXPORT, "reports:export");
+}
+
+#[test]
+fn caps_registry_lists_every_declared_scope() {
+    assert_eq!(billing::ALL, &[billing::CHARGE, billing::REFUND]);
+    assert_eq!(reports::ALL, &[Capability::new("reports:export")]);
+}
+
+#[test]
+fn caps_constants_are_accepted_by_authenticated_guards() {
+    std::hint::black_box(charge);
+}
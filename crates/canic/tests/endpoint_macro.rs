@@ -1,11 +1,34 @@
-use canic::{Error, canic_query};
+use canic::{Error, canic_query, canic_update};
 
 #[canic_query(public, composite)]
 fn composite_probe() -> Result<(), Error> {
     Ok(())
 }
 
+#[canic_query(public, cache(ttl = "30s", key = "args", stable))]
+fn cached_probe(page: u64, label: String) -> Result<Vec<String>, Error> {
+    Ok(vec![format!("{label}:{page}")])
+}
+
+#[canic_query(public, cache(ttl = "1m", key = "none"))]
+async fn cached_async_probe(window: u64) -> Result<u64, Error> {
+    Ok(std::future::ready(window).await)
+}
+
+#[canic_update(requires(any(caller::is_controller(), auth::has_role("admin"))))]
+async fn role_gated_probe() -> Result<(), Error> {
+    std::future::ready(Ok(())).await
+}
+
 canic::canic_emit_nonroot_auth_attestation_endpoints!();
+#[canic_update(requires(auth::authenticated(), auth::session_active("15m")))]
+async fn session_gated_probe(token: canic::dto::auth::DelegatedToken) -> Result<(), Error> {
+    std::future::ready(token).await;
+    Ok(())
+}
+
+canic::canic_emit_acl_endpoints!();
+canic::canic_emit_session_endpoints!();
 canic::canic_emit_lifecycle_core_endpoints!();
 
 #[test]
@@ -13,6 +36,35 @@ fn canic_query_accepts_composite_marker() {
     std::hint::black_box(composite_probe as fn() -> Result<(), Error>);
 }
 
+#[test]
+fn canic_query_accepts_cache_clause() {
+    std::hint::black_box(cached_probe as fn(u64, String) -> Result<Vec<String>, Error>);
+    std::hint::black_box(cached_async_probe);
+}
+
+#[test]
+fn canic_update_accepts_has_role_predicate() {
+    std::hint::black_box(role_gated_probe);
+}
+
+#[test]
+fn acl_emitter_exports_controller_admin_endpoints() {
+    std::hint::black_box(canic_acl_grant);
+    std::hint::black_box(canic_acl_revoke);
+    std::hint::black_box(canic_acl_list);
+}
+
+#[test]
+fn canic_update_accepts_session_active_predicate() {
+    std::hint::black_box(session_gated_probe);
+}
+
+#[test]
+fn session_emitter_exports_controller_session_list() {
+    std::hint::black_box(canic_sessions_list);
+    assert_eq!(canic::protocol::CANIC_SESSIONS_LIST, "canic_sessions_list");
+}
+
 #[test]
 fn nonroot_auth_emitter_exports_active_proof_installer() {
     std::hint::black_box(canic_install_active_delegation_proof);
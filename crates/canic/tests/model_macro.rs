@@ -0,0 +1,42 @@
+use canic::{
+    Error,
+    dto::{
+        model::ModelEntry,
+        page::{Page, PageRequest},
+    },
+};
+
+canic::canic_model! {
+    /// Display names keyed by account id.
+    #[endpoints(names, requires(caller::is_controller()))]
+    pub static NAMES: BTreeMap<u64, String> =
+        memory(authority = "model_test", key = "model_test.names.v1", id = 120);
+
+    static COUNTERS: BTreeMap<String, u64> =
+        memory(authority = "model_test", key = "model_test.counters.v1", id = 121);
+}
+
+#[test]
+fn model_endpoints_are_generated_with_the_declared_prefix() {
+    std::hint::black_box(names_get);
+    std::hint::black_box(names_list);
+    std::hint::black_box(names_count);
+}
+
+#[test]
+fn model_endpoints_return_page_dtos() {
+    fn assert_list<F, Fut>(_: F)
+    where
+        F: Fn(PageRequest) -> Fut,
+        Fut: Future<Output = Result<Page<ModelEntry<u64, String>>, Error>>,
+    {
+    }
+
+    assert_list(names_list);
+}
+
+#[test]
+fn models_without_endpoints_declare_only_the_map() {
+    std::hint::black_box(&NAMES);
+    std::hint::black_box(&COUNTERS);
+}
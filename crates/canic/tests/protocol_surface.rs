@@ -225,6 +225,11 @@ fn wasm_store_excludes_default_memory_diagnostics() {
         "unexpected `canic_memory_registry` method in {}",
         did_path.display()
     );
+    assert!(
+        !did.contains("type StoreCatalogResponse = record") && !did.contains("  canic_stores :"),
+        "unexpected default `canic_stores` method in {}",
+        did_path.display()
+    );
 }
 
 #[test]
@@ -278,6 +283,40 @@ fn fleet_activation_status_is_a_controller_query_on_the_shared_runtime_surface()
     );
 }
 
+#[test]
+fn fleet_mode_is_a_public_query_that_bypasses_the_fleet_guard() {
+    assert_eq!(canic::protocol::CANIC_FLEET_MODE, "canic_fleet_mode");
+
+    let macro_path = workspace_root().join("crates/canic/src/macros/endpoints/shared.rs");
+    let source = read_text(&macro_path);
+    let attribute = preceding_attribute(&source, "fn canic_fleet_mode()");
+
+    assert!(
+        attribute.contains("canic_query(internal, public)"),
+        "Fleet mode must stay readable while the Fleet is Disabled"
+    );
+
+    let did = read_text(&workspace_root().join("crates/canic-wasm-store/wasm_store.did"));
+    assert!(did.contains("  canic_fleet_mode : () -> (FleetMode) query;"));
+}
+
+#[test]
+fn interface_version_is_exported_alongside_metadata() {
+    assert_eq!(
+        canic::protocol::CANIC_INTERFACE_VERSION,
+        "canic_interface_version"
+    );
+
+    let macro_path = workspace_root().join("crates/canic/src/macros/endpoints/shared.rs");
+    let source = read_text(&macro_path);
+    let attribute = preceding_attribute(&source, "fn canic_interface_version()");
+
+    assert!(attribute.contains("canic_query(internal, public)"));
+
+    let did = read_text(&workspace_root().join("crates/canic-wasm-store/wasm_store.did"));
+    assert!(did.contains("  canic_interface_version : () -> (text) query;"));
+}
+
 #[test]
 fn public_protocol_reexports_wasm_store_root_update_manifest() {
     assert_eq!(
@@ -1421,6 +1460,32 @@ fn memory_ledger_is_config_gated() {
     );
 }
 
+#[test]
+fn store_catalog_is_config_gated_and_controller_only() {
+    let bundle_path = workspace_root().join("crates/canic/src/macros/endpoints/bundles.rs");
+    let bundles = read_text(&bundle_path);
+    let macro_path = workspace_root().join("crates/canic/src/macros/endpoints/shared.rs");
+    let source = read_text(&macro_path);
+    let endpoint = source
+        .split("fn canic_stores()")
+        .nth(1)
+        .expect("store catalog endpoint should exist");
+
+    assert_eq!(
+        bundles
+            .matches("#[cfg(canic_store_catalog_enabled)]\n        $crate::canic_emit_store_catalog_diagnostic_endpoint!();")
+            .count(),
+        2,
+        "shared and wasm_store runtime bundles must config-gate the store catalog endpoint"
+    );
+    assert!(
+        endpoint.contains("$crate::__internal::cdk::api::is_controller")
+            && endpoint.contains("MemoryQuery::stores()"),
+        "store catalog diagnostic must be controller-gated in {}",
+        macro_path.display()
+    );
+}
+
 #[test]
 fn missing_finish_marker_stays_actionable() {
     let macro_path = workspace_root().join("crates/canic/src/macros/start.rs");
@@ -0,0 +1,58 @@
+use canic_cli::{cli_error_exit_code, render_cli_error, run};
+use std::ffi::OsString;
+
+// Cargo passes the subcommand name as the first argument after the binary.
+const CARGO_SUBCOMMAND: &str = "canic";
+
+// Run the operator CLI as `cargo canic` and report errors like `canic` does.
+fn main() {
+    if let Err(err) = run(subcommand_args(std::env::args_os().skip(1))) {
+        let rendered = render_cli_error(&err);
+        if !rendered.is_empty() {
+            eprintln!("{rendered}");
+        }
+        std::process::exit(cli_error_exit_code(&err));
+    }
+}
+
+// Drop the `canic` token Cargo inserts so `cargo canic x` matches `canic x`.
+fn subcommand_args<I>(args: I) -> Vec<OsString>
+where
+    I: IntoIterator<Item = OsString>,
+{
+    let mut args = args.into_iter().peekable();
+    if args.peek().is_some_and(|arg| arg == CARGO_SUBCOMMAND) {
+        args.next();
+    }
+    args.collect()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<OsString> {
+        values.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn cargo_subcommand_token_is_stripped() {
+        assert_eq!(
+            subcommand_args(args(&["canic", "scaffold", "canister", "demo", "store"])),
+            args(&["scaffold", "canister", "demo", "store"])
+        );
+    }
+
+    #[test]
+    fn direct_invocation_args_are_preserved() {
+        assert_eq!(
+            subcommand_args(args(&["scaffold", "canister", "demo", "canic"])),
+            args(&["scaffold", "canister", "demo", "canic"])
+        );
+        assert!(subcommand_args(Vec::new()).is_empty());
+    }
+}
@@ -19,6 +19,7 @@ PUBLISH_ORDER=(
     canic
     canic-host
     canic-cli
+    cargo-canic
     canic-wasm-store
 )
 